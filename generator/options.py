@@ -0,0 +1,132 @@
+#!/usr/bin/env python3
+# (c) Copyright 2025 mrdkprj
+"""Generates src/options.rs from the doc comments of the `*_with_opts` methods in src/ops.rs.
+
+Run from the repository root whenever ops.rs is regenerated, then format the result:
+
+    python3 generator/options.py && cargo fmt
+"""
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+OPS = ROOT / "src" / "ops.rs"
+OPTIONS = ROOT / "src" / "options.rs"
+
+KEYWORDS = {
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do",
+    "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self",
+    "Self", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+}
+
+# documented type -> (field type, setter parameter type, conversion of the parameter)
+TYPES = {
+    "`bool`": ("bool", "bool", "{n}"),
+    "`i32`": ("i32", "i32", "{n}"),
+    "`f64`": ("f64", "f64", "{n}"),
+    "`u64`": ("u64", "u64", "{n}"),
+    "`&str`": ("String", "&str", "{n}.to_string()"),
+    "`&[f64]`": ("Vec<f64>", "&[f64]", "{n}.to_vec()"),
+    "`&[i32]`": ("Vec<i32>", "&[i32]", "{n}.to_vec()"),
+    "`&[VipsImage]`": ("Vec<VipsImage>", "&[VipsImage]", "{n}.to_vec()"),
+    "`&VipsInterpolate`": ("VipsInterpolate", "&VipsInterpolate", "{n}.clone()"),
+    "`&VipsImage`": ("VipsImage", "&VipsImage", "{n}.clone()"),
+    # a few image arguments are documented without a type
+    "``": ("VipsImage", "&VipsImage", "{n}.clone()"),
+}
+
+METHOD = re.compile(r"((?:    ///.*\n)+)    pub fn (\w+)_with_opts(?:<'a>)?\(")
+ARGUMENT = re.compile(r"/// (\w+): (`[^`]*`|\[`(\w+)`\]) -> (.*)")
+
+
+def field(name):
+    name = name.lower()
+    return "r#" + name if name in KEYWORDS else name
+
+
+def parameter(name):
+    name = name.lower()
+    return name + "_" if name in KEYWORDS else name
+
+
+def generate(ops):
+    structs = []
+    enums = set()
+    seen = set()
+    for method in METHOD.finditer(ops):
+        doc, function = method.group(1), method.group(2)
+        if "<ins>Optional arguments</ins>" not in doc:
+            continue
+        operation = re.search(r"\((\w+)\)", doc.split("\n")[0]).group(1)
+        arguments = []
+        for argument in ARGUMENT.finditer(doc.split("<ins>Optional arguments</ins>")[1]):
+            name, documented, enum, description = argument.groups()
+            description = description.strip()
+            if enum:
+                enums.add(enum)
+                arguments.append((name, enum, enum, "{n}", description))
+            elif documented in TYPES:
+                arguments.append((name, *TYPES[documented], description))
+            elif documented.startswith("`&mut"):
+                # optional outputs stay with VOption
+                continue
+            else:
+                sys.exit(f"{function}: unknown argument type {documented}")
+        if not arguments:
+            continue
+
+        name = "".join(part[:1].upper() + part[1:] for part in function.split("_")) + "Options"
+        assert name not in seen, name
+        seen.add(name)
+
+        lines = [
+            f"/// Optional arguments of {operation}, for [`VipsImage::{function}_with_opts`]. "
+            "Unset arguments keep their libvips defaults.",
+            "#[derive(Debug, Clone, Default)]",
+            f"pub struct {name} {{",
+        ]
+        for argument, field_type, _, _, description in arguments:
+            lines += [f"    /// {description}", f"    pub {field(argument)}: Option<{field_type}>,"]
+        lines += ["}", "", f"impl {name} {{", "    pub fn new() -> Self {", "        Self::default()", "    }"]
+        for argument, _, parameter_type, conversion, description in arguments:
+            lines += [
+                "",
+                f"    /// {description}",
+                f"    pub fn {field(argument)}(mut self, {parameter(argument)}: {parameter_type}) -> Self {{",
+                f"        self.{field(argument)} = Some({conversion.format(n=parameter(argument))});",
+                "        self",
+                "    }",
+            ]
+        lines += [
+            "}",
+            "",
+            f"impl From<{name}> for VOption<'_> {{",
+            f"    fn from(options: {name}) -> Self {{",
+            "        let mut option = VOption::new();",
+        ]
+        for argument, *_ in arguments:
+            lines += [
+                f"        if let Some(value) = options.{field(argument)} {{",
+                f'            option.add("{argument}", value);',
+                "        }",
+            ]
+        lines += ["        option", "    }", "}", ""]
+        structs.append("\n".join(lines))
+
+    header = (
+        "// (c) Copyright 2025 mrdkprj\n"
+        "// Generated by generator/options.py from the docs in ops.rs, do not edit by hand.\n"
+        "use crate::{\n"
+        f"    ops::{{{', '.join(sorted(enums))}}},\n"
+        "    voption::{Setter, VOption},\n"
+        "    VipsImage, VipsInterpolate,\n"
+        "};\n\n"
+    )
+    return header + "\n".join(structs)
+
+
+if __name__ == "__main__":
+    OPTIONS.write_text(generate(OPS.read_text()))
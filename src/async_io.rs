@@ -0,0 +1,386 @@
+// (c) Copyright 2025 mrdkprj
+use crate::{
+    bindings, connection::connect_signal_full, error::Error, Result, VipsImage, VipsSource,
+    VipsTarget,
+};
+use std::{
+    ffi::c_void,
+    io::{self, Read, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    runtime::Handle,
+    sync::{mpsc, oneshot},
+    task,
+};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl VipsSource {
+    /// Create a source that reads from an async reader. Must be called from within a tokio runtime.
+    ///
+    /// The reader is driven by a task on the runtime, and libvips waits for its data on a blocking thread.
+    /// Load from the source with `VipsImage::load_source_async()` or inside `spawn_blocking`, never directly from async code.
+    pub fn from_async_reader<R>(reader: R) -> Result<Self>
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        let handle = current_runtime()?;
+        let (sender, receiver) = mpsc::channel(4);
+        handle.spawn(
+            pump_reader(
+                reader,
+                sender,
+            ),
+        );
+        Self::from_reader(
+            ChannelReader {
+                receiver,
+                chunk: Vec::new(),
+                position: 0,
+            },
+        )
+    }
+}
+
+impl VipsTarget {
+    /// Create a target that writes to an async writer. Must be called from within a tokio runtime.
+    ///
+    /// The writer is driven by a task on the runtime, and is shut down once the target is dropped.
+    /// Save to the target with `VipsImage::save_target_async()` or inside `spawn_blocking`, never directly from async code.
+    pub fn from_async_writer<W>(writer: W) -> Result<Self>
+    where
+        W: AsyncWrite + Send + Unpin + 'static,
+    {
+        let handle = current_runtime()?;
+        let (sender, receiver) = mpsc::channel(4);
+        let error = Arc::new(Mutex::new(None));
+        handle.spawn(
+            pump_writer(
+                writer,
+                receiver,
+                Arc::clone(&error),
+            ),
+        );
+        Self::from_writer(
+            ChannelWriter {
+                sender,
+                error,
+            },
+        )
+    }
+}
+
+/// Async wrappers which run on tokio's blocking thread pool.
+///
+/// Dropping the returned future cancels the work: a save in progress is stopped, and work which has not started yet is skipped.
+/// Loads and thumbnails cannot be stopped once started, since libvips only checks for cancellation while it computes pixels,
+/// and these return a lazy image whose pixels are computed later, by whatever uses it.
+impl VipsImage {
+    /// Load an image from a file on a blocking thread. Dropping the future only skips the load if it has not started.
+    pub async fn load_async<P: AsRef<Path>>(filename: P) -> Result<VipsImage> {
+        let filename = filename
+            .as_ref()
+            .to_path_buf();
+        run_blocking(move |_| VipsImage::new_from_file(filename)).await
+    }
+
+    /// Load an image from a source on a blocking thread. Dropping the future only skips the load if it has not started.
+    pub async fn load_source_async(source: VipsSource, option_str: &str) -> Result<VipsImage> {
+        let option_str = option_str.to_string();
+        run_blocking(move |_| {
+            VipsImage::new_from_source(
+                &source,
+                &option_str,
+            )
+        })
+        .await
+    }
+
+    /// Make a thumbnail of a file on a blocking thread. Dropping the future only skips the work if it has not started.
+    pub async fn thumbnail_async<P: AsRef<Path>>(filename: P, width: i32) -> Result<VipsImage> {
+        let filename = filename
+            .as_ref()
+            .to_string_lossy()
+            .to_string();
+        run_blocking(move |_| {
+            VipsImage::thumbnail(
+                &filename,
+                width,
+            )
+        })
+        .await
+    }
+
+    /// Save the image to a file on a blocking thread.
+    pub async fn save_async<P: AsRef<Path>>(&self, filename: P) -> Result<()> {
+        let filename = filename
+            .as_ref()
+            .to_path_buf();
+        let image = self.clone();
+        run_blocking(
+            move |cancel| {
+                watch_cancel(
+                    &image,
+                    cancel,
+                )?
+                .write_to_file(filename)
+            },
+        )
+        .await
+    }
+
+    /// Save the image to a target on a blocking thread. suffix selects the saver, as in `write_to_target()`.
+    pub async fn save_target_async(&self, suffix: &str, target: VipsTarget) -> Result<()> {
+        let suffix = suffix.to_string();
+        let image = self.clone();
+        run_blocking(
+            move |cancel| {
+                watch_cancel(
+                    &image,
+                    cancel,
+                )?
+                .write_to_target(
+                    &suffix,
+                    &target,
+                )
+            },
+        )
+        .await
+    }
+}
+
+fn current_runtime() -> Result<Handle> {
+    Handle::try_current().map_err(|_| {
+        Error::InitializationError("Must be called from within a tokio runtime".into())
+    })
+}
+
+async fn pump_reader<R>(mut reader: R, sender: mpsc::Sender<io::Result<Vec<u8>>>)
+where
+    R: AsyncRead + Unpin,
+{
+    loop {
+        let mut chunk = vec![0; CHUNK_SIZE];
+        match reader
+            .read(&mut chunk)
+            .await
+        {
+            // dropping the sender signals end of file
+            Ok(0) => return,
+            Ok(n) => {
+                chunk.truncate(n);
+                if sender
+                    .send(Ok(chunk))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            Err(e) => {
+                let _ = sender
+                    .send(Err(e))
+                    .await;
+                return;
+            }
+        }
+    }
+}
+
+struct ChannelReader {
+    receiver: mpsc::Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position
+            == self
+                .chunk
+                .len()
+        {
+            match self
+                .receiver
+                .blocking_recv()
+            {
+                Some(chunk) => {
+                    self.chunk = chunk?;
+                    self.position = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+        let available = &self.chunk[self.position..];
+        let n = available
+            .len()
+            .min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+enum WriterMessage {
+    Data(Vec<u8>),
+    Flush(oneshot::Sender<io::Result<()>>),
+}
+
+async fn pump_writer<W>(
+    mut writer: W,
+    mut receiver: mpsc::Receiver<WriterMessage>,
+    error: Arc<Mutex<Option<io::Error>>>,
+) where
+    W: AsyncWrite + Unpin,
+{
+    while let Some(message) = receiver
+        .recv()
+        .await
+    {
+        match message {
+            WriterMessage::Data(data) => {
+                if let Err(e) = writer
+                    .write_all(&data)
+                    .await
+                {
+                    *error
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner()) = Some(e);
+                    return;
+                }
+            }
+            WriterMessage::Flush(reply) => {
+                let _ = reply.send(
+                    writer
+                        .flush()
+                        .await,
+                );
+            }
+        }
+    }
+    let _ = writer
+        .shutdown()
+        .await;
+}
+
+struct ChannelWriter {
+    sender: mpsc::Sender<WriterMessage>,
+    error: Arc<Mutex<Option<io::Error>>>,
+}
+
+impl ChannelWriter {
+    fn closed(&self) -> io::Error {
+        self.error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    "async writer closed",
+                )
+            })
+    }
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.sender
+            .blocking_send(WriterMessage::Data(buf.to_vec()))
+            .map_err(|_| self.closed())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let (reply, result) = oneshot::channel();
+        self.sender
+            .blocking_send(WriterMessage::Flush(reply))
+            .map_err(|_| self.closed())?;
+        result
+            .blocking_recv()
+            .map_err(|_| self.closed())?
+    }
+}
+
+/// Sets the flag when the future holding it is dropped.
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0
+            .store(
+                true,
+                Ordering::Release,
+            );
+    }
+}
+
+async fn run_blocking<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Arc<AtomicBool>) -> Result<T> + Send + 'static,
+{
+    let cancel = CancelOnDrop(Arc::new(
+        AtomicBool::new(false),
+    ));
+    let flag = Arc::clone(&cancel.0);
+    task::spawn_blocking(move || {
+        if flag.load(Ordering::Acquire) {
+            return Err(Error::OperationError("Operation cancelled".into()));
+        }
+        f(flag)
+    })
+    .await
+    .map_err(|e| {
+        Error::OperationError(
+            format!(
+                "Blocking task failed: {}",
+                e
+            )
+            .into(),
+        )
+    })?
+}
+
+/// Returns a private copy of image which stops evaluating once cancel is set.
+///
+/// The copy bypasses the operation cache, so killing it never affects other saves of the same image.
+fn watch_cancel(image: &VipsImage, cancel: Arc<AtomicBool>) -> Result<VipsImage> {
+    let copy = image.private_copy()?;
+    unsafe {
+        bindings::vips_image_set_progress(
+            copy.ctx,
+            1,
+        );
+        // the handler owns this call's flag, which is released when the copy is finalized
+        let data = Arc::into_raw(cancel) as *mut c_void;
+        connect_signal_full(
+            copy.ctx as _,
+            "eval",
+            eval_cancel as *const (),
+            data,
+            Some(free_cancel),
+        )?;
+    }
+    Ok(copy)
+}
+
+unsafe extern "C" fn eval_cancel(
+    image: *mut bindings::VipsImage,
+    _progress: *mut bindings::VipsProgress,
+    data: *mut c_void,
+) {
+    if (*(data as *const AtomicBool)).load(Ordering::Acquire) {
+        bindings::vips_image_set_kill(image, 1);
+    }
+}
+
+unsafe extern "C" fn free_cancel(data: *mut c_void, _closure: *mut bindings::GClosure) {
+    drop(Arc::from_raw(data as *const AtomicBool));
+}
@@ -0,0 +1,317 @@
+// (c) Copyright 2025 mrdkprj
+use crate::{bindings, error::Error, init_locked, lock_state, utils, Result, Vips, VipsGuard};
+use std::{
+    ffi::CStr,
+    path::{Path, PathBuf},
+};
+
+/// Global libvips settings, applied together when libvips starts.
+///
+/// Unset values keep the libvips defaults.
+///
+/// ```no_run
+/// use rs_vips::VipsConfig;
+///
+/// let _vips = VipsConfig::from_env("my-service")
+///     .concurrency(4)
+///     .cache_max_mem(100 * 1024 * 1024)
+///     .init()
+///     .unwrap();
+/// println!("{:?}", rs_vips::Vips::settings());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VipsConfig {
+    name: String,
+    concurrency: Option<i32>,
+    cache_max: Option<i32>,
+    cache_max_mem: Option<u64>,
+    cache_max_files: Option<i32>,
+    leak: Option<bool>,
+    progress: Option<bool>,
+    pipe_read_limit: Option<i64>,
+    disc_threshold: Option<u64>,
+    temp_dir: Option<PathBuf>,
+}
+
+/// The settings libvips is running with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VipsSettings {
+    pub concurrency: i32,
+    pub cache_max: i32,
+    pub cache_max_mem: u64,
+    pub cache_max_files: i32,
+    pub leak: bool,
+    pub progress: bool,
+    pub pipe_read_limit: i64,
+    pub disc_threshold: u64,
+    pub temp_dir: PathBuf,
+}
+
+impl VipsConfig {
+    /// A config with every value unset. name is the program name passed to vips_init().
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// A config initialised from the environment.
+    ///
+    /// Reads `VIPS_CONCURRENCY`, `VIPS_CACHE_MAX`, `VIPS_CACHE_MAX_MEM`, `VIPS_CACHE_MAX_FILES`, `VIPS_LEAK`,
+    /// `VIPS_PROGRESS` and `VIPS_PIPE_READ_LIMIT`. Sizes accept a k, m or g suffix, and values that don't parse
+    /// or overflow are ignored. libvips reads `VIPS_DISC_THRESHOLD` and `TMPDIR` itself, so they are left unset.
+    /// Builder calls made afterwards take precedence.
+    pub fn from_env(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            concurrency: env_parse("VIPS_CONCURRENCY"),
+            cache_max: env_parse("VIPS_CACHE_MAX"),
+            cache_max_mem: env_size("VIPS_CACHE_MAX_MEM"),
+            cache_max_files: env_parse("VIPS_CACHE_MAX_FILES"),
+            leak: env_flag("VIPS_LEAK"),
+            progress: env_flag("VIPS_PROGRESS"),
+            pipe_read_limit: env_size("VIPS_PIPE_READ_LIMIT")
+                .and_then(|size| i64::try_from(size).ok()),
+            ..Default::default()
+        }
+    }
+
+    /// Number of worker threads in the libvips threadpool.
+    pub fn concurrency(mut self, concurrency: i32) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Maximum number of operations kept in the operation cache.
+    pub fn cache_max(mut self, max: i32) -> Self {
+        self.cache_max = Some(max);
+        self
+    }
+
+    /// Maximum amount of tracked memory before cached operations are dropped.
+    pub fn cache_max_mem(mut self, max: u64) -> Self {
+        self.cache_max_mem = Some(max);
+        self
+    }
+
+    /// Maximum number of tracked files before cached operations are dropped.
+    pub fn cache_max_files(mut self, max: i32) -> Self {
+        self.cache_max_files = Some(max);
+        self
+    }
+
+    /// Turn on or off vips leak checking.
+    pub fn leak(mut self, leak: bool) -> Self {
+        self.leak = Some(leak);
+        self
+    }
+
+    /// Turn on or off progress reporting.
+    pub fn progress(mut self, progress: bool) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Maximum number of bytes read into memory from a source that cannot seek or mmap.
+    pub fn pipe_read_limit(mut self, limit: i64) -> Self {
+        self.pipe_read_limit = Some(limit);
+        self
+    }
+
+    /// Images larger than this many bytes are decompressed to a temporary file instead of memory.
+    pub fn disc_threshold(mut self, threshold: u64) -> Self {
+        self.disc_threshold = Some(threshold);
+        self
+    }
+
+    /// Directory for temporary files.
+    pub fn temp_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.temp_dir = Some(
+            dir.as_ref()
+                .to_path_buf(),
+        );
+        self
+    }
+
+    /// Starts up libvips with this config.
+    ///
+    /// If libvips is already running, the other settings are applied to it and a new guard is returned.
+    /// The disc threshold and temp directory are read by libvips only once, so setting them then is an error.
+    ///
+    /// libvips has no setter for those two, so they are passed through the process environment. Changing the
+    /// environment is not thread safe: set them before starting threads that read or write environment variables.
+    pub fn init(self) -> Result<VipsGuard> {
+        let mut state = lock_state();
+        if state.started
+            && (self
+                .disc_threshold
+                .is_some()
+                || self
+                    .temp_dir
+                    .is_some())
+        {
+            return Err(
+                Error::InitializationError(
+                    "The disc threshold and temp directory must be set before libvips is started"
+                        .into(),
+                ),
+            );
+        }
+
+        // libvips reads these two from the environment the first time it needs them
+        if let Some(threshold) = self.disc_threshold {
+            set_env(
+                "VIPS_DISC_THRESHOLD",
+                &threshold.to_string(),
+            )?;
+        }
+        if let Some(dir) = &self.temp_dir {
+            set_env(
+                "TMPDIR",
+                &dir.to_string_lossy(),
+            )?;
+        }
+
+        let guard = init_locked(
+            &mut state,
+            &self.name,
+        )?;
+        unsafe {
+            if let Some(concurrency) = self.concurrency {
+                bindings::vips_concurrency_set(concurrency);
+            }
+            if let Some(max) = self.cache_max {
+                bindings::vips_cache_set_max(max);
+            }
+            if let Some(max) = self.cache_max_mem {
+                bindings::vips_cache_set_max_mem(max);
+            }
+            if let Some(max) = self.cache_max_files {
+                bindings::vips_cache_set_max_files(max);
+            }
+            if let Some(leak) = self.leak {
+                bindings::vips_leak_set(if leak { 1 } else { 0 });
+                state.leak = leak;
+            }
+            if let Some(progress) = self.progress {
+                bindings::vips_progress_set(if progress { 1 } else { 0 });
+                state.progress = progress;
+            }
+            if let Some(limit) = self.pipe_read_limit {
+                bindings::vips_pipe_read_limit_set(limit);
+                state.pipe_read_limit = limit;
+            }
+        }
+        Ok(guard)
+    }
+}
+
+impl Vips {
+    /// The settings libvips is currently running with.
+    pub fn settings() -> VipsSettings {
+        let state = lock_state();
+        unsafe {
+            VipsSettings {
+                concurrency: bindings::vips_concurrency_get(),
+                cache_max: bindings::vips_cache_get_max(),
+                cache_max_mem: bindings::vips_cache_get_max_mem(),
+                cache_max_files: bindings::vips_cache_get_max_files(),
+                leak: state.leak,
+                progress: state.progress,
+                pipe_read_limit: state.pipe_read_limit,
+                disc_threshold: bindings::vips_get_disc_threshold(),
+                temp_dir: PathBuf::from(
+                    CStr::from_ptr(bindings::g_get_tmp_dir())
+                        .to_string_lossy()
+                        .to_string(),
+                ),
+            }
+        }
+    }
+}
+
+fn set_env(name: &str, value: &str) -> Result<()> {
+    let c_name = utils::new_c_string(name)?;
+    let c_value = utils::new_c_string(value)?;
+    let res = unsafe {
+        bindings::g_setenv(
+            c_name.as_ptr(),
+            c_value.as_ptr(),
+            1,
+        )
+    };
+    if res == 0 {
+        Err(
+            Error::InitializationError(
+                format!(
+                    "Cannot set {}",
+                    name
+                )
+                .into(),
+            ),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(name: &str) -> Option<T> {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .ok()
+        })
+}
+
+fn env_flag(name: &str) -> Option<bool> {
+    std::env::var(name)
+        .ok()
+        .map(|value| {
+            !matches!(
+                value
+                    .trim()
+                    .to_ascii_lowercase()
+                    .as_str(),
+                "0" | "false" | "no" | "off"
+            )
+        })
+}
+
+fn env_size(name: &str) -> Option<u64> {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| parse_size(&value))
+}
+
+// Same rules as vips__parse_size(): a number with an optional k, m or g suffix.
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, unit) = match value
+        .chars()
+        .last()?
+        .to_ascii_lowercase()
+    {
+        'k' => (
+            &value[..value.len() - 1],
+            1024,
+        ),
+        'm' => (
+            &value[..value.len() - 1],
+            1024 * 1024,
+        ),
+        'g' => (
+            &value[..value.len() - 1],
+            1024 * 1024 * 1024,
+        ),
+        _ => (value, 1),
+    };
+    number
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|number| number.checked_mul(unit))
+}
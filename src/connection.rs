@@ -2,28 +2,41 @@
 // (c) Copyright 2025 mrdkprj
 use crate::{
     bindings,
-    error::Error,
+    error::{self, Error},
     region::VipsBlob,
     utils::{self, vips_source_result, vips_target_result},
     Result,
 };
 use std::{
     borrow::Cow,
-    ffi::{c_void, CStr, CString},
-    ptr::null_mut,
+    ffi::{c_int, c_void, CStr, CString},
+    io::{Read, Seek, SeekFrom, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
 };
 
-#[derive(Debug, Clone)]
+/// The common part of sources and targets.
+///
+/// Cloning a connection takes a new reference to the same libvips object; the clones share its state.
+#[derive(Debug)]
 pub struct VipsConnection {
     pub(crate) ctx: *mut bindings::VipsConnection,
 }
 
-#[derive(Debug, Clone)]
+/// An input that libvips can load images from.
+///
+/// Cloning a source takes a new reference to the same libvips object, so the clones share one read
+/// position: reading or seeking through one moves the others too.
+#[derive(Debug)]
 pub struct VipsSource {
     pub(crate) ctx: *mut bindings::VipsSource,
 }
 
-#[derive(Debug, Clone)]
+/// An output that libvips can save images to.
+///
+/// Cloning a target takes a new reference to the same libvips object, so the clones share one write
+/// position and everything written through any of them ends up in the same output.
+#[derive(Debug)]
 pub struct VipsTarget {
     pub(crate) ctx: *mut bindings::VipsTarget,
 }
@@ -68,7 +81,7 @@ impl VipsSource {
             vips_source_result(
                 res,
                 Error::InitializationError(
-                    "Could not initialise VipsSource from descriptor".to_string(),
+                    "Could not initialise VipsSource from descriptor".into(),
                 ),
             )
         }
@@ -81,38 +94,36 @@ impl VipsSource {
             let res = bindings::vips_source_new_from_file(f.as_ptr());
             vips_source_result(
                 res,
-                Error::InitializationError("Could not initialise VipsSource from file".to_string()),
+                Error::InitializationError("Could not initialise VipsSource from file".into()),
             )
         }
     }
 
-    // Create a source attached to an area of memory.
-    // not sure if it this is safe
-    // should test before making it public
-    fn new_from_blob(blob: VipsBlob) -> Result<Self> {
+    /// Create a source attached to a blob. The source takes a new reference to blob; nothing is copied.
+    pub fn new_from_blob(blob: &VipsBlob) -> Result<Self> {
         unsafe {
             let res = bindings::vips_source_new_from_blob(blob.ctx);
             vips_source_result(
                 res,
-                Error::InitializationError("Could not initialise VipsSource from blob".to_string()),
+                Error::InitializationError("Could not initialise VipsSource from blob".into()),
             )
         }
     }
 
-    /// Create a source attached to an area of memory. You must not free data while the source is active.
+    /// Create a source attached to an area of memory. buffer is copied, so it can be dropped as soon as this returns.
     pub fn new_from_memory(buffer: &[u8]) -> Result<Self> {
-        unsafe {
-            let res = bindings::vips_source_new_from_memory(
-                buffer.as_ptr() as *const c_void,
-                buffer.len() as u64,
-            );
-            vips_source_result(
-                res,
-                Error::InitializationError(
-                    "Could not initialise VipsSource from memory".to_string(),
-                ),
-            )
-        }
+        let blob = VipsBlob::copy_from_slice(buffer);
+        Self::new_from_blob(&blob)
+    }
+
+    /// Create a source attached to an area of memory without copying it. The source takes ownership of buffer
+    /// and drops it once libvips no longer needs it.
+    pub fn new_from_memory_owned<T>(buffer: T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        let blob = VipsBlob::new(buffer);
+        Self::new_from_blob(&blob)
     }
 
     /// Create a source from an option string.
@@ -122,13 +133,54 @@ impl VipsSource {
             let res = bindings::vips_source_new_from_options(options.as_ptr());
             vips_source_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsSource from options".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsSource from options".into()),
             )
         }
     }
 
+    /// Create a source that reads from reader.
+    ///
+    /// The source cannot seek, so libvips reads it like a pipe: loaders that need the whole file read it into memory
+    /// first, up to the pipe read limit. I/O errors from reader are reported in the error of the load.
+    pub fn from_reader<R>(reader: R) -> Result<Self>
+    where
+        R: Read + Send + 'static,
+    {
+        unsafe {
+            let (source, data) = new_source_custom(reader)?;
+            connect_signal(
+                source as _,
+                "read",
+                read_source::<R> as *const (),
+                data,
+            )?;
+            Ok(source.into())
+        }
+    }
+
+    /// Create a source that reads from a seekable reader, so loaders can read headers and pixels without buffering.
+    pub fn from_read_seek<R>(reader: R) -> Result<Self>
+    where
+        R: Read + Seek + Send + 'static,
+    {
+        unsafe {
+            let (source, data) = new_source_custom(reader)?;
+            connect_signal(
+                source as _,
+                "read",
+                read_source::<R> as *const (),
+                data,
+            )?;
+            connect_signal(
+                source as _,
+                "seek",
+                seek_source::<R> as *const (),
+                data,
+            )?;
+            Ok(source.into())
+        }
+    }
+
     /// Minimise the source. As many resources as can be safely removed are removed.
     pub fn minimise(&mut self) {
         unsafe {
@@ -143,7 +195,7 @@ impl VipsSource {
             utils::result(
                 result,
                 (),
-                Error::OperationError("Error on vips unminimise".to_string()),
+                Error::OperationError("Error on vips unminimise".into()),
             )
         }
     }
@@ -155,29 +207,23 @@ impl VipsSource {
             utils::result(
                 result,
                 (),
-                Error::OperationError("Error on vips decode".to_string()),
+                Error::OperationError("Error on vips decode".into()),
             )
         }
     }
 
-    /// Read up to length bytes from source and store the bytes in buffer.
-    pub fn read(&mut self, length: u64) -> Result<Vec<u8>> {
+    /// Read up to buffer.len() bytes from source into buffer. Returns the number of bytes read, 0 at end of file.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
         unsafe {
-            let bytes: *mut c_void = null_mut();
             let result = bindings::vips_source_read(
                 self.ctx,
-                bytes,
-                length,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len() as _,
             );
-            if result != -1 {
-                let buffer = Vec::from_raw_parts(
-                    bytes as *mut u8,
-                    result as usize,
-                    result as usize,
-                );
-                Ok(buffer)
+            if result == -1 {
+                Err(Error::IOError("Error on vips read".into()).extend())
             } else {
-                Err(Error::OperationError("Error on vips read".to_string()))
+                Ok(result as usize)
             }
         }
     }
@@ -196,7 +242,7 @@ impl VipsSource {
                 whence,
             );
             if result == -1 {
-                Err(Error::OperationError("Error on vips seek".to_string()))
+                Err(Error::OperationError("Error on vips seek".into()))
             } else {
                 Ok(result)
             }
@@ -208,7 +254,7 @@ impl VipsSource {
         unsafe {
             let result = bindings::vips_source_rewind(self.ctx);
             if result == -1 {
-                Err(Error::OperationError("Error on vips rewind".to_string()))
+                Err(Error::OperationError("Error on vips rewind".into()))
             } else {
                 Ok(())
             }
@@ -220,7 +266,7 @@ impl VipsSource {
         unsafe {
             let result = bindings::vips_source_length(self.ctx);
             if result == -1 {
-                Err(Error::OperationError("Error on vips length".to_string()))
+                Err(Error::OperationError("Error on vips length".into()))
             } else {
                 Ok(result)
             }
@@ -229,24 +275,21 @@ impl VipsSource {
 }
 
 impl<'a> VipsSource {
-    /// Map the source entirely into memory and return a pointer to the start.
+    /// Map the source entirely into memory and return it. Sources which cannot be mapped are read into memory first.
     pub fn map(&'a self) -> Result<&'a [u8]> {
         unsafe {
-            let length: *mut u64 = null_mut();
+            let mut length: usize = 0;
             let result = bindings::vips_source_map(
                 self.ctx,
-                length,
+                &mut length as *mut usize as _,
             );
-            if length.is_null() {
-                Err(Error::OperationError("Error on vips map".to_string()))
+            if result.is_null() {
+                Err(Error::IOError("Error on vips map".into()).extend())
             } else {
-                let size = (*length)
-                    .try_into()
-                    .map_err(|_| Error::OperationError("Can't get size of array".to_string()))?;
                 Ok(
                     std::slice::from_raw_parts(
-                        result as *mut u8,
-                        size,
+                        result as *const u8,
+                        length,
                     ),
                 )
             }
@@ -254,6 +297,32 @@ impl<'a> VipsSource {
     }
 }
 
+impl Read for VipsSource {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        VipsSource::read(self, buf).map_err(std::io::Error::other)
+    }
+}
+
+impl Seek for VipsSource {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (
+                io_offset(offset)?,
+                0,
+            ),
+            SeekFrom::Current(offset) => (offset, 1),
+            SeekFrom::End(offset) => (offset, 2),
+        };
+        VipsSource::seek(
+            self,
+            offset,
+            whence,
+        )
+        .map_err(std::io::Error::other)
+        .and_then(io_offset)
+    }
+}
+
 impl VipsTarget {
     /// Create a target attached to a file descriptor. descriptor is kept open until the target is finalized.
     pub fn new_to_descriptor(descriptor: i32) -> Result<Self> {
@@ -262,7 +331,7 @@ impl VipsTarget {
             vips_target_result(
                 res,
                 Error::InitializationError(
-                    "Could not initialise VipsTarget from descriptor".to_string(),
+                    "Could not initialise VipsTarget from descriptor".into(),
                 ),
             )
         }
@@ -275,7 +344,7 @@ impl VipsTarget {
             let res = bindings::vips_target_new_to_file(f.as_ptr());
             vips_target_result(
                 res,
-                Error::InitializationError("Could not initialise VipsTarget from file".to_string()),
+                Error::InitializationError("Could not initialise VipsTarget from file".into()),
             )
         }
     }
@@ -286,13 +355,62 @@ impl VipsTarget {
             let res = bindings::vips_target_new_to_memory();
             vips_target_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsTarget from memory".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsTarget from memory".into()),
             )
         }
     }
 
+    /// Create a target that writes to writer. writer is flushed when the save finishes.
+    ///
+    /// I/O errors from writer make the save fail and are reported in its error.
+    pub fn from_writer<W>(writer: W) -> Result<Self>
+    where
+        W: Write + Send + 'static,
+    {
+        unsafe {
+            let (target, data) = new_target_custom(writer)?;
+            connect_signal(
+                target as _,
+                "write",
+                write_target::<W> as *const (),
+                data,
+            )?;
+            connect_signal(
+                target as _,
+                "end",
+                end_target::<W> as *const (),
+                data,
+            )?;
+            Ok(target.into())
+        }
+    }
+
+    /// Create a target that writes to a seekable writer which can also be read back.
+    ///
+    /// Savers such as tiffsave need to seek and re-read what they have written, so they only work with this kind of target.
+    pub fn from_write_seek<W>(writer: W) -> Result<Self>
+    where
+        W: Read + Write + Seek + Send + 'static,
+    {
+        unsafe {
+            let target = Self::from_writer(writer)?;
+            let data = get_io(target.ctx as _)?;
+            connect_signal(
+                target.ctx as _,
+                "read",
+                read_target::<W> as *const (),
+                data,
+            )?;
+            connect_signal(
+                target.ctx as _,
+                "seek",
+                seek_target::<W> as *const (),
+                data,
+            )?;
+            Ok(target)
+        }
+    }
+
     /// Call this at the end of write to make the target do any cleaning up.
     pub fn end(self) {
         unsafe {
@@ -308,7 +426,7 @@ impl VipsTarget {
                 ch as i32,
             );
             if res == -1 {
-                Err(Error::OperationError("Could not write to buffer".to_string()))
+                Err(Error::OperationError("Could not write to buffer".into()))
             } else {
                 Ok(())
             }
@@ -324,7 +442,7 @@ impl VipsTarget {
                 buffer.len() as u64,
             );
             if res == -1 {
-                Err(Error::OperationError("Could not write to buffer".to_string()))
+                Err(Error::OperationError("Could not write to buffer".into()))
             } else {
                 Ok(())
             }
@@ -335,13 +453,13 @@ impl VipsTarget {
     pub fn write_amp(&mut self, text: &str) -> Result<()> {
         unsafe {
             let cstr = CString::new(text)
-                .map_err(|_| Error::OperationError("Cannot initialize C string".to_string()))?;
+                .map_err(|_| Error::OperationError("Cannot initialize C string".into()))?;
             let res = bindings::vips_target_write_amp(
                 self.ctx,
                 cstr.as_ptr(),
             );
             if res == -1 {
-                Err(Error::OperationError("Could not write to buffer".to_string()))
+                Err(Error::OperationError("Could not write to buffer".into()))
             } else {
                 Ok(())
             }
@@ -352,13 +470,13 @@ impl VipsTarget {
     pub fn writes(&mut self, text: &str) -> Result<()> {
         unsafe {
             let cstr = CString::new(text)
-                .map_err(|_| Error::OperationError("Cannot initialize C string".to_string()))?;
+                .map_err(|_| Error::OperationError("Cannot initialize C string".into()))?;
             let res = bindings::vips_target_writes(
                 self.ctx,
                 cstr.as_ptr(),
             );
             if res == -1 {
-                Err(Error::OperationError("Could not write to buffer".to_string()))
+                Err(Error::OperationError("Could not write to buffer".into()))
             } else {
                 Ok(())
             }
@@ -367,10 +485,49 @@ impl VipsTarget {
 
     pub fn get_blob(&self) -> VipsBlob {
         unsafe {
+            let blob = (*self.ctx).blob;
+            if !blob.is_null() {
+                bindings::vips_area_copy(&mut (*blob).area);
+            }
             VipsBlob {
-                ctx: (*self.ctx).blob,
+                ctx: blob,
+            }
+        }
+    }
+}
+
+impl Write for VipsTarget {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        VipsTarget::write(self, buf)
+            .map(|_| buf.len())
+            .map_err(std::io::Error::other)
+    }
+
+    // libvips only empties its output buffer in vips_target_end(), see VipsTarget::end
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Sources and targets keep a read/write position, so they can be moved to another thread but not shared.
+// Clones share that position too; see the type docs.
+unsafe impl Send for VipsConnection {}
+unsafe impl Send for VipsSource {}
+unsafe impl Send for VipsTarget {}
+
+impl Clone for VipsConnection {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_ref(self.ctx as *mut c_void);
             }
         }
+        Self {
+            ctx: self.ctx,
+        }
     }
 }
 
@@ -387,6 +544,22 @@ impl Drop for VipsConnection {
     }
 }
 
+impl Clone for VipsSource {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        Self {
+            ctx: self.ctx,
+        }
+    }
+}
+
 impl Drop for VipsSource {
     fn drop(&mut self) {
         unsafe {
@@ -400,6 +573,22 @@ impl Drop for VipsSource {
     }
 }
 
+impl Clone for VipsTarget {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        Self {
+            ctx: self.ctx,
+        }
+    }
+}
+
 impl Drop for VipsTarget {
     fn drop(&mut self) {
         unsafe {
@@ -413,6 +602,319 @@ impl Drop for VipsTarget {
     }
 }
 
+const IO_DATA_KEY: &str = "rs-vips-io";
+const SOURCE_DOMAIN: &str = "VipsSourceCustom";
+const TARGET_DOMAIN: &str = "VipsTargetCustom";
+
+/// Create a custom source which owns io. Signal handlers get a pointer to the io, which lives as long as the source.
+unsafe fn new_source_custom<T: Send + 'static>(
+    io: T,
+) -> Result<(
+    *mut bindings::VipsSource,
+    *mut c_void,
+)> {
+    let source = bindings::vips_source_custom_new();
+    if source.is_null() {
+        return Err(
+            Error::InitializationError("Could not initialise VipsSourceCustom".into()).extend(),
+        );
+    }
+    let data = attach_io(
+        source as _,
+        io,
+    )?;
+    Ok((
+        source as _,
+        data,
+    ))
+}
+
+/// Create a custom target which owns io. Signal handlers get a pointer to the io, which lives as long as the target.
+unsafe fn new_target_custom<T: Send + 'static>(
+    io: T,
+) -> Result<(
+    *mut bindings::VipsTarget,
+    *mut c_void,
+)> {
+    let target = bindings::vips_target_custom_new();
+    if target.is_null() {
+        return Err(
+            Error::InitializationError("Could not initialise VipsTargetCustom".into()).extend(),
+        );
+    }
+    let data = attach_io(
+        target as _,
+        io,
+    )?;
+    Ok((
+        target as _,
+        data,
+    ))
+}
+
+unsafe fn get_io(object: *mut bindings::GObject) -> Result<*mut c_void> {
+    let key = utils::new_c_string(IO_DATA_KEY)?;
+    Ok(
+        bindings::g_object_get_data(
+            object,
+            key.as_ptr(),
+        ),
+    )
+}
+
+unsafe fn attach_io<T: Send + 'static>(
+    object: *mut bindings::GObject,
+    io: T,
+) -> Result<*mut c_void> {
+    let key = utils::new_c_string(IO_DATA_KEY)?;
+    let data = Box::into_raw(Box::new(
+        Mutex::new(io),
+    )) as *mut c_void;
+    bindings::g_object_set_data_full(
+        object,
+        key.as_ptr(),
+        data,
+        Some(free_io::<T>),
+    );
+    Ok(data)
+}
+
+pub(crate) unsafe fn connect_signal(
+    instance: *mut c_void,
+    signal: &str,
+    handler: *const (),
+    data: *mut c_void,
+) -> Result<()> {
+    connect_signal_full(
+        instance,
+        signal,
+        handler,
+        data,
+        None,
+    )
+}
+
+/// Like connect_signal(), but destroy is called with data once the handler is disconnected or the instance is finalized.
+pub(crate) unsafe fn connect_signal_full(
+    instance: *mut c_void,
+    signal: &str,
+    handler: *const (),
+    data: *mut c_void,
+    destroy: bindings::GClosureNotify,
+) -> Result<()> {
+    let signal = utils::new_c_string(signal)?;
+    bindings::g_signal_connect_data(
+        instance,
+        signal.as_ptr(),
+        Some(std::mem::transmute::<*const (), unsafe extern "C" fn()>(handler)),
+        data,
+        destroy,
+        0,
+    );
+    Ok(())
+}
+
+unsafe extern "C" fn free_io<T>(data: *mut c_void) {
+    drop(Box::from_raw(data as *mut Mutex<T>));
+}
+
+/// Run an I/O callback, reporting errors and panics to the libvips error buffer and returning -1 for them.
+fn io_callback<F>(domain: &str, callback: F) -> i64
+where
+    F: FnOnce() -> std::io::Result<i64>,
+{
+    let message = match panic::catch_unwind(AssertUnwindSafe(callback)) {
+        Ok(Ok(result)) => return result,
+        Ok(Err(e)) => {
+            let message = e.to_string();
+            error::set_pending_source(Some(
+                Box::new(e),
+            ));
+            message
+        }
+        Err(_) => "panic during I/O".to_string(),
+    };
+    if let (Ok(domain), Ok(format), Ok(message)) = (
+        utils::new_c_string(domain),
+        utils::new_c_string("%s"),
+        utils::new_c_string(message),
+    ) {
+        unsafe {
+            bindings::vips_error(
+                domain.as_ptr(),
+                format.as_ptr(),
+                message.as_ptr(),
+            );
+        }
+    }
+    -1
+}
+
+fn lock_io<'a, T>(data: *mut c_void) -> std::sync::MutexGuard<'a, T> {
+    unsafe { &*(data as *const Mutex<T>) }
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+unsafe fn read_io<R: Read>(
+    buffer: *mut c_void,
+    length: i64,
+    data: *mut c_void,
+) -> std::io::Result<i64> {
+    if buffer.is_null() || length <= 0 {
+        return Ok(0);
+    }
+    let buffer = std::slice::from_raw_parts_mut(
+        buffer as *mut u8,
+        length as usize,
+    );
+    let mut reader = lock_io::<R>(data);
+    loop {
+        match reader.read(buffer) {
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            result => return result.and_then(io_offset),
+        }
+    }
+}
+
+fn seek_io<S: Seek>(offset: i64, whence: c_int, data: *mut c_void) -> std::io::Result<i64> {
+    let position = match whence {
+        0 => SeekFrom::Start(io_offset(
+            offset,
+        )?),
+        1 => SeekFrom::Current(offset),
+        2 => SeekFrom::End(offset),
+        _ => {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "invalid whence",
+                ),
+            )
+        }
+    };
+    lock_io::<S>(data)
+        .seek(position)
+        .and_then(io_offset)
+}
+
+// Lengths and positions are i64 on the libvips side and u64 or usize on the Rust side.
+fn io_offset<T, U: TryFrom<T>>(value: T) -> std::io::Result<U> {
+    U::try_from(value).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "offset out of range",
+        )
+    })
+}
+
+unsafe extern "C" fn read_source<R: Read>(
+    _source: *mut bindings::VipsSourceCustom,
+    buffer: *mut c_void,
+    length: i64,
+    data: *mut c_void,
+) -> i64 {
+    io_callback(
+        SOURCE_DOMAIN,
+        || {
+            read_io::<R>(
+                buffer,
+                length,
+                data,
+            )
+        },
+    )
+}
+
+unsafe extern "C" fn seek_source<R: Seek>(
+    _source: *mut bindings::VipsSourceCustom,
+    offset: i64,
+    whence: c_int,
+    data: *mut c_void,
+) -> i64 {
+    io_callback(
+        SOURCE_DOMAIN,
+        || {
+            seek_io::<R>(
+                offset,
+                whence,
+                data,
+            )
+        },
+    )
+}
+
+unsafe extern "C" fn write_target<W: Write>(
+    _target: *mut bindings::VipsTargetCustom,
+    buffer: *const c_void,
+    length: i64,
+    data: *mut c_void,
+) -> i64 {
+    io_callback(
+        TARGET_DOMAIN,
+        || {
+            if buffer.is_null() || length <= 0 {
+                return Ok(0);
+            }
+            let buffer = std::slice::from_raw_parts(
+                buffer as *const u8,
+                length as usize,
+            );
+            lock_io::<W>(data).write_all(buffer)?;
+            Ok(length)
+        },
+    )
+}
+
+unsafe extern "C" fn end_target<W: Write>(
+    _target: *mut bindings::VipsTargetCustom,
+    data: *mut c_void,
+) -> c_int {
+    io_callback(
+        TARGET_DOMAIN,
+        || {
+            lock_io::<W>(data).flush()?;
+            Ok(0)
+        },
+    ) as c_int
+}
+
+unsafe extern "C" fn read_target<W: Read>(
+    _target: *mut bindings::VipsTargetCustom,
+    buffer: *mut c_void,
+    length: i64,
+    data: *mut c_void,
+) -> i64 {
+    io_callback(
+        TARGET_DOMAIN,
+        || {
+            read_io::<W>(
+                buffer,
+                length,
+                data,
+            )
+        },
+    )
+}
+
+unsafe extern "C" fn seek_target<W: Seek>(
+    _target: *mut bindings::VipsTargetCustom,
+    offset: i64,
+    whence: c_int,
+    data: *mut c_void,
+) -> i64 {
+    io_callback(
+        TARGET_DOMAIN,
+        || {
+            seek_io::<W>(
+                offset,
+                whence,
+                data,
+            )
+        },
+    )
+}
+
 impl From<*mut bindings::VipsSource> for VipsSource {
     fn from(value: *mut bindings::VipsSource) -> Self {
         Self {
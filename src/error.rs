@@ -1,64 +1,659 @@
 // (c) Copyright 2025 mrdkprj
-use crate::bindings::vips_error_buffer;
-use std::ffi::CStr;
+use crate::bindings::{g_free, vips_error_buffer_copy};
+use std::{
+    cell::{Cell, RefCell},
+    ffi::CStr,
+    sync::{Condvar, Mutex, PoisonError},
+    thread::ThreadId,
+};
 
 #[derive(Debug)]
 pub enum Error {
-    InitializationError(String),
-    IOError(String),
-    OperationError(String),
+    InitializationError(ErrorInfo),
+    IOError(ErrorInfo),
+    OperationError(ErrorInfo),
+    /// An option passed to an operation was rejected before the operation ran.
+    ArgumentError(ArgumentError),
+    /// The operation, or the only loader for the input, has been blocked. Holds the operation nickname.
+    BlockedError(String),
+}
+
+/// What kind of failure an error is, as far as can be told from what libvips reported.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// No loader or saver supports the input or the requested format.
+    UnsupportedFormat,
+    /// The input ended early or is damaged.
+    TruncatedInput,
+    OutOfMemory,
+    BlockedOperation,
+    BadArgument,
+    #[default]
+    Other,
+}
+
+/// Describes a failure: what this crate was doing, and the messages libvips reported for it.
+#[derive(Debug, Default)]
+pub struct ErrorInfo {
+    /// What failed, for example `Jpegsave (vips_jpegsave) failed`.
+    pub message: String,
+    pub kind: ErrorKind,
+    /// The nickname of the operation that failed, when the error came from one.
+    pub operation: Option<String>,
+    /// The libvips messages of the failed call, one per line, each starting with its domain.
+    pub lines: Vec<String>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+/// Describes an option an operation does not accept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentError {
+    /// The operation nickname, for example `jpegsave`.
+    pub operation: String,
+    /// The name of the rejected option.
+    pub argument: String,
+    /// Why the option was rejected, for example `expected gboolean, got gchararray`.
+    pub reason: String,
+    /// The names the operation accepts in that position.
+    pub valid_arguments: Vec<String>,
+}
+
+impl std::fmt::Display for ArgumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: argument {}: {}. Valid arguments: {}",
+            self.operation,
+            self.argument,
+            self.reason,
+            self.valid_arguments
+                .join(", ")
+        )
+    }
+}
+
+impl ErrorInfo {
+    /// Attaches the error which caused this one.
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.source = Some(Box::new(
+            source,
+        ));
+        self
+    }
+
+    /// The domains of the libvips messages, in the order they were reported, without duplicates.
+    pub fn domains(&self) -> Vec<&str> {
+        let mut domains: Vec<&str> = Vec::new();
+        for line in &self.lines {
+            if let Some((domain, _)) = line.split_once(": ") {
+                if !domains.contains(&domain) {
+                    domains.push(domain);
+                }
+            }
+        }
+        domains
+    }
+
+    fn attach(
+        mut self,
+        lines: Vec<String>,
+        operation: Option<String>,
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        if self.kind == ErrorKind::Other {
+            self.kind = classify(&lines);
+        }
+        self.lines
+            .extend(lines);
+        if self
+            .operation
+            .is_none()
+        {
+            self.operation = operation;
+        }
+        if self
+            .source
+            .is_none()
+        {
+            self.source = source;
+        }
+        self
+    }
+}
+
+impl From<String> for ErrorInfo {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for ErrorInfo {
+    fn from(message: &str) -> Self {
+        Self::from(message.to_string())
+    }
+}
+
+impl std::fmt::Display for ErrorInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self
+            .lines
+            .is_empty()
+        {
+            write!(
+                f,
+                "{}",
+                self.message
+            )
+        } else {
+            write!(
+                f,
+                "{}. {}",
+                self.message,
+                self.lines
+                    .join("\n")
+            )
+        }
+    }
+}
+
+type BoxedSource = Box<dyn std::error::Error + Send + Sync>;
+
+thread_local! {
+    static PENDING_ERROR: RefCell<Option<Error>> = const { RefCell::new(None) };
+    static FAILED_OPERATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    static PENDING_SOURCE: RefCell<Option<BoxedSource>> = const { RefCell::new(None) };
+    static PENDING_LINES: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+    static CURRENT_CAPTURE: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// The most lines kept for calls which have not claimed them yet.
+const MAX_UNCLAIMED_LINES: usize = 256;
+
+/// Domains every operation, or every loader and saver, has. Sharing them does not make two calls alike.
+const SHARED_DOMAINS: &[&str] = &["VipsOperation", "operation", "VipsForeign", "foreign"];
+
+/// The calls into libvips in flight on all threads, and the messages none of them has claimed yet.
+///
+/// libvips keeps a single error buffer for the whole process, so the messages of two calls failing at
+/// the same time end up in it together. Three things keep them apart:
+///
+/// - Calls whose messages would look alike, because they share a domain such as `VipsForeignLoad`, take
+///   turns: a call waits in `Capture::begin()` until the alike calls on other threads have finished.
+/// - The buffer is drained whenever a call begins or ends, and each line remembers the calls in flight when
+///   it was drained. A call only ever takes lines written while it was running.
+/// - Among those calls, a line goes to the call it mentions a marker of, such as the filename it loads, and
+///   otherwise to the call which owns its domain.
+struct Captures {
+    next_id: u64,
+    active: Vec<CaptureOwner>,
+    unclaimed: Vec<UnclaimedLine>,
+}
+
+struct CaptureOwner {
+    id: u64,
+    thread: ThreadId,
+    domains: Vec<String>,
+    markers: Vec<String>,
+}
+
+struct UnclaimedLine {
+    line: String,
+    /// The calls in flight when the line was drained from the libvips buffer.
+    candidates: Vec<u64>,
+}
+
+impl CaptureOwner {
+    fn owns_domain(&self, domain: &str) -> bool {
+        self.domains
+            .iter()
+            .any(|d| d == domain)
+    }
+
+    fn marks(&self, line: &str) -> bool {
+        self.markers
+            .iter()
+            .any(|marker| line.contains(marker.as_str()))
+    }
+
+    fn is_alike(&self, domains: &[String]) -> bool {
+        domains
+            .iter()
+            .filter(|domain| !SHARED_DOMAINS.contains(&domain.as_str()))
+            .any(|domain| self.owns_domain(domain))
+    }
+}
+
+impl Captures {
+    /// Moves the libvips error buffer into the unclaimed lines, tagged with the calls in flight.
+    fn drain(&mut self) {
+        let candidates: Vec<u64> = self
+            .active
+            .iter()
+            .map(|owner| owner.id)
+            .collect();
+        self.unclaimed
+            .extend(
+                take_error_buffer()
+                    .into_iter()
+                    .map(
+                        |line| UnclaimedLine {
+                            line,
+                            candidates: candidates.clone(),
+                        },
+                    ),
+            );
+        if self
+            .unclaimed
+            .len()
+            > MAX_UNCLAIMED_LINES
+        {
+            let excess = self
+                .unclaimed
+                .len()
+                - MAX_UNCLAIMED_LINES;
+            self.unclaimed
+                .drain(..excess);
+        }
+    }
+}
+
+static CAPTURES: Mutex<Captures> = Mutex::new(Captures {
+    next_id: 0,
+    active: Vec::new(),
+    unclaimed: Vec::new(),
+});
+
+/// Signalled whenever a call ends, for the alike calls waiting for their turn.
+static CAPTURE_ENDED: Condvar = Condvar::new();
+
+/// Brackets a call into libvips on this thread, so that its messages can be told apart from those of
+/// calls failing on other threads at the same time.
+pub(crate) struct Capture {
+    id: u64,
+    previous: Option<u64>,
+}
+
+impl Capture {
+    /// Waits until no alike call is in flight on another thread. Calls nested on the same thread don't wait.
+    pub(crate) fn begin(domains: Vec<String>, markers: Vec<String>) -> Self {
+        let thread = std::thread::current().id();
+        let mut captures = CAPTURES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        while captures
+            .active
+            .iter()
+            .any(|owner| owner.thread != thread && owner.is_alike(&domains))
+        {
+            captures = CAPTURE_ENDED
+                .wait(captures)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        // whatever is in the buffer now was written before this call started
+        captures.drain();
+        let id = captures.next_id;
+        captures.next_id += 1;
+        captures
+            .active
+            .push(
+                CaptureOwner {
+                    id,
+                    thread,
+                    domains,
+                    markers: markers
+                        .into_iter()
+                        .filter(|marker| !marker.is_empty())
+                        .collect(),
+                },
+            );
+        Self {
+            id,
+            previous: CURRENT_CAPTURE.with(|current| current.replace(Some(id))),
+        }
+    }
+
+    /// Keeps the messages of this call for the next extend() on this thread.
+    pub(crate) fn fail(&self, operation: Option<String>) {
+        let lines = claim_lines(Some(
+            self.id,
+        ));
+        PENDING_LINES.with(|pending| *pending.borrow_mut() = Some(lines));
+        if operation.is_some() {
+            set_failed_operation(operation);
+        }
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        CURRENT_CAPTURE.with(|current| current.set(self.previous));
+        let mut captures = CAPTURES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        captures.drain();
+        captures
+            .active
+            .retain(|owner| owner.id != self.id);
+        // this call did not fail, so it wrote nothing the others need; lines only it could have written are noise
+        let id = self.id;
+        captures
+            .unclaimed
+            .retain_mut(
+                |unclaimed| {
+                    let before = unclaimed
+                        .candidates
+                        .len();
+                    unclaimed
+                        .candidates
+                        .retain(|candidate| *candidate != id);
+                    !(before > 0
+                        && unclaimed
+                            .candidates
+                            .is_empty())
+                },
+            );
+        if captures
+            .active
+            .is_empty()
+        {
+            captures
+                .unclaimed
+                .clear();
+        }
+        drop(captures);
+        CAPTURE_ENDED.notify_all();
+    }
+}
+
+/// Throws away the messages of an expected failure on this thread, leaving those of other threads alone.
+pub(crate) fn discard_messages() {
+    claim_lines(CURRENT_CAPTURE.with(Cell::get));
+}
+
+/// Drains the libvips error buffer and takes the lines the given call owns.
+/// Without a call, takes the lines written outside any call, and those no call in flight owns.
+fn claim_lines(id: Option<u64>) -> Vec<String> {
+    let mut captures = CAPTURES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    captures.drain();
+
+    let unclaimed = std::mem::take(&mut captures.unclaimed);
+    let mut claimed: Vec<String> = Vec::new();
+    let mut left = Vec::new();
+    for unclaimed in unclaimed {
+        let take = {
+            let line = &unclaimed.line;
+            let domain = line
+                .split_once(": ")
+                .map_or(
+                    "",
+                    |(domain, _)| domain,
+                );
+            let (mine, others): (
+                Vec<&CaptureOwner>,
+                Vec<&CaptureOwner>,
+            ) = captures
+                .active
+                .iter()
+                .filter(|owner| {
+                    unclaimed
+                        .candidates
+                        .contains(&owner.id)
+                })
+                .partition(|owner| Some(owner.id) == id);
+            if id.is_some() && mine.is_empty() {
+                // written before this call started
+                false
+            } else if mine
+                .iter()
+                .any(|owner| owner.marks(line))
+            {
+                true
+            } else if others
+                .iter()
+                .any(|owner| owner.marks(line))
+            {
+                false
+            } else if mine
+                .iter()
+                .any(|owner| owner.owns_domain(domain))
+            {
+                true
+            } else {
+                !others
+                    .iter()
+                    .any(|owner| owner.owns_domain(domain))
+            }
+        };
+        if take {
+            claimed.push(unclaimed.line);
+        } else {
+            left.push(unclaimed);
+        }
+    }
+    captures.unclaimed = left;
+    claimed
+}
+
+/// Records why the last operation on this thread was refused, for the next extend() to pick up.
+pub(crate) fn set_pending_error(error: Option<Error>) {
+    PENDING_ERROR.with(|pending| *pending.borrow_mut() = error);
+}
+
+/// Records which operation failed on this thread, for the next extend() to pick up.
+pub(crate) fn set_failed_operation(nickname: Option<String>) {
+    FAILED_OPERATION.with(|failed| *failed.borrow_mut() = nickname);
+}
+
+/// Records the Rust error behind a failure reported to libvips, such as an I/O error in a custom source.
+pub(crate) fn set_pending_source(source: Option<BoxedSource>) {
+    PENDING_SOURCE.with(|pending| *pending.borrow_mut() = source);
+}
+
+/// Forgets what earlier calls on this thread left behind.
+pub(crate) fn clear_pending() {
+    set_pending_error(None);
+    set_failed_operation(None);
+    set_pending_source(None);
+    PENDING_LINES.with(|pending| *pending.borrow_mut() = None);
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Error::InitializationError(msg) => {
+            Error::InitializationError(info) => {
                 write!(
                     f,
                     "vips error: InitializationError - {}",
-                    msg
+                    info
                 )
             }
-            Error::OperationError(msg) => write!(
+            Error::OperationError(info) => write!(
                 f,
                 "vips error: OperationError - {}",
-                msg
+                info
             ),
-            Error::IOError(msg) => write!(
+            Error::IOError(info) => write!(
                 f,
                 "vips error: IOError - {}",
-                msg
+                info
+            ),
+            Error::ArgumentError(e) => write!(
+                f,
+                "vips error: ArgumentError - {}",
+                e
+            ),
+            Error::BlockedError(nickname) => write!(
+                f,
+                "vips error: BlockedError - {} is blocked",
+                nickname
             ),
         }
     }
 }
 
 impl Error {
+    /// What kind of failure this is.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InitializationError(info)
+            | Error::IOError(info)
+            | Error::OperationError(info) => info.kind,
+            Error::ArgumentError(_) => ErrorKind::BadArgument,
+            Error::BlockedError(_) => ErrorKind::BlockedOperation,
+        }
+    }
+
+    /// The nickname of the operation that failed, when the error came from one.
+    pub fn operation(&self) -> Option<&str> {
+        match self {
+            Error::InitializationError(info)
+            | Error::IOError(info)
+            | Error::OperationError(info) => info
+                .operation
+                .as_deref(),
+            Error::ArgumentError(e) => Some(&e.operation),
+            Error::BlockedError(nickname) => Some(nickname),
+        }
+    }
+
+    /// The details of the failure, for the variants which carry them.
+    pub fn info(&self) -> Option<&ErrorInfo> {
+        match self {
+            Error::InitializationError(info)
+            | Error::IOError(info)
+            | Error::OperationError(info) => Some(info),
+            Error::ArgumentError(_) | Error::BlockedError(_) => None,
+        }
+    }
+
+    /// Adds what libvips reported for the failed call on this thread to the error.
     pub(crate) fn extend(self) -> Self {
-        let erro_buffer = unsafe { vips_error_buffer() };
-        if erro_buffer.is_null() {
-            return self;
-        }
-
-        if let Ok(detail) = unsafe { CStr::from_ptr(erro_buffer).to_str() } {
-            match self {
-                Error::InitializationError(msg) => Error::InitializationError(format!(
-                    "{}. {}",
-                    msg, detail
-                )),
-                Error::OperationError(msg) => Error::OperationError(format!(
-                    "{}. {}",
-                    msg, detail
-                )),
-                Error::IOError(msg) => Error::IOError(format!(
-                    "{}. {}",
-                    msg, detail
-                )),
-            }
-        } else {
-            self
+        if let Some(e) = PENDING_ERROR.with(
+            |pending| {
+                pending
+                    .borrow_mut()
+                    .take()
+            },
+        ) {
+            return e;
+        }
+
+        let lines = PENDING_LINES
+            .with(
+                |pending| {
+                    pending
+                        .borrow_mut()
+                        .take()
+                },
+            )
+            .unwrap_or_else(|| claim_lines(CURRENT_CAPTURE.with(Cell::get)));
+        let operation = FAILED_OPERATION.with(|failed| {
+            failed
+                .borrow_mut()
+                .take()
+        });
+        let source = PENDING_SOURCE.with(
+            |pending| {
+                pending
+                    .borrow_mut()
+                    .take()
+            },
+        );
+        match self {
+            Error::InitializationError(info) => Error::InitializationError(
+                info.attach(
+                    lines,
+                    operation,
+                    source,
+                ),
+            ),
+            Error::IOError(info) => Error::IOError(
+                info.attach(
+                    lines,
+                    operation,
+                    source,
+                ),
+            ),
+            Error::OperationError(info) => Error::OperationError(
+                info.attach(
+                    lines,
+                    operation,
+                    source,
+                ),
+            ),
+            Error::ArgumentError(_) | Error::BlockedError(_) => self,
         }
     }
 }
 
-impl std::error::Error for Error {}
+/// Copies and clears the libvips error buffer in one step, so no message can be lost in between.
+fn take_error_buffer() -> Vec<String> {
+    unsafe {
+        let buffer = vips_error_buffer_copy();
+        if buffer.is_null() {
+            return Vec::new();
+        }
+        let lines = CStr::from_ptr(buffer)
+            .to_string_lossy()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+        g_free(buffer as _);
+        lines
+    }
+}
+
+fn classify(lines: &[String]) -> ErrorKind {
+    let text = lines
+        .join("\n")
+        .to_ascii_lowercase();
+    let has = |patterns: &[&str]| {
+        patterns
+            .iter()
+            .any(|pattern| text.contains(pattern))
+    };
+    if has(&["operation is blocked"]) {
+        ErrorKind::BlockedOperation
+    } else if has(&["out of memory", "unable to allocate", "failed to allocate"]) {
+        ErrorKind::OutOfMemory
+    } else if has(&[
+        "not a known file format",
+        "is not in a known format",
+        "unsupported",
+        "no known saver",
+        "no known loader",
+    ]) {
+        ErrorKind::UnsupportedFormat
+    } else if has(&[
+        "premature end",
+        "truncated",
+        "unexpected end",
+        "out of data",
+        "end of file",
+        "short read",
+    ]) {
+        ErrorKind::TruncatedInput
+    } else if has(&["no property named", "invalid value", "bad parameter"]) {
+        ErrorKind::BadArgument
+    } else {
+        ErrorKind::Other
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.info()?
+            .source
+            .as_deref()
+            .map(|source| source as _)
+    }
+}
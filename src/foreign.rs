@@ -0,0 +1,643 @@
+// (c) Copyright 2025 mrdkprj
+use crate::{
+    bindings,
+    error::{self, Capture, Error},
+    utils, Result, Vips, VipsSource,
+};
+use std::{
+    ffi::{c_char, c_void, CStr},
+    io::Read,
+    ptr::null_mut,
+};
+
+/// Image formats libvips can load or save, as identified by the loader or saver it picks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+    /// HEIF with HEVC compression, usually HEIC
+    Heif,
+    /// AVIF. libvips loads and saves it with heifload and heifsave, so it is told apart from HEIC by the
+    /// brand in the `ftyp` box of the input, or by the `.avif` suffix when saving.
+    Avif,
+    Jxl,
+    Tiff,
+    Gif,
+    Pdf,
+    Svg,
+    Jp2k,
+    /// PPM, PGM, PBM and PFM
+    Ppm,
+    Fits,
+    OpenExr,
+    Radiance,
+    Csv,
+    Matrix,
+    Analyze,
+    Vips,
+    /// Camera raw files, from rawload or dcrawload, whichever this libvips has
+    Raw,
+    OpenSlide,
+    /// Anything loaded through ImageMagick
+    Magick,
+    /// A loader this crate does not know about
+    Other,
+}
+
+/// Which loaders may run. Set it with `Vips::loader_policy_set()` before handling untrusted input.
+///
+/// ```no_run
+/// use rs_vips::{ImageFormat, LoaderPolicy, Vips};
+///
+/// // allowing AVIF unblocks heifload
+/// Vips::loader_policy_set(&LoaderPolicy::Allow(vec![
+///     ImageFormat::Jpeg,
+///     ImageFormat::Png,
+///     ImageFormat::Webp,
+///     ImageFormat::Avif,
+/// ]))
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoaderPolicy {
+    /// Every loader may run, including the untrusted ones.
+    AllowAll,
+    /// Loaders libvips flags as untrusted, such as magickload, pdfload and svgload, are blocked.
+    BlockUntrusted,
+    /// Only the loaders for these formats may run.
+    ///
+    /// Loaders are allowed whole, so a format which shares its loader with another lets both through. In
+    /// particular heifload reads AVIF and HEIC alike: allowing `Avif` lets HEIC through too, and the other way round.
+    Allow(Vec<ImageFormat>),
+}
+
+/// The loader libvips picked for some input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignLoader {
+    /// The operation nickname, for example `jpegload_buffer`.
+    pub nickname: String,
+    /// The format of the loader. Input found by heifload is reported as `Avif` or `Heif` depending on its brand.
+    pub format: ImageFormat,
+}
+
+/// The saver libvips picked for some output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignSaver {
+    /// The operation nickname, for example `jpegsave_buffer`.
+    pub nickname: String,
+    /// The format of the saver. heifsave is reported as `Avif` for an `.avif` suffix and as `Heif` otherwise.
+    pub format: ImageFormat,
+}
+
+impl ImageFormat {
+    /// The format handled by the loader with this nickname.
+    pub fn from_loader(nickname: &str) -> Self {
+        Self::from_name(
+            match nickname.find("load") {
+                Some(index) => &nickname[..index],
+                None => nickname,
+            },
+        )
+    }
+
+    /// The format handled by the saver with this nickname.
+    pub fn from_saver(nickname: &str) -> Self {
+        Self::from_name(
+            match nickname.find("save") {
+                Some(index) => &nickname[..index],
+                None => nickname,
+            },
+        )
+    }
+
+    fn from_name(name: &str) -> Self {
+        match name {
+            "jpeg" => ImageFormat::Jpeg,
+            "png" => ImageFormat::Png,
+            "webp" => ImageFormat::Webp,
+            "heif" => ImageFormat::Heif,
+            "jxl" => ImageFormat::Jxl,
+            "tiff" => ImageFormat::Tiff,
+            "gif" => ImageFormat::Gif,
+            "pdf" => ImageFormat::Pdf,
+            "svg" => ImageFormat::Svg,
+            "jp2k" => ImageFormat::Jp2k,
+            "ppm" => ImageFormat::Ppm,
+            "fits" => ImageFormat::Fits,
+            "openexr" => ImageFormat::OpenExr,
+            "rad" => ImageFormat::Radiance,
+            "csv" => ImageFormat::Csv,
+            "matrix" => ImageFormat::Matrix,
+            "analyze" => ImageFormat::Analyze,
+            "vips" => ImageFormat::Vips,
+            "raw" | "dcraw" => ImageFormat::Raw,
+            "openslide" => ImageFormat::OpenSlide,
+            "magick" | "magick7" => ImageFormat::Magick,
+            _ => ImageFormat::Other,
+        }
+    }
+
+    /// The MIME type of the format, if it has a registered one.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        match self {
+            ImageFormat::Jpeg => Some("image/jpeg"),
+            ImageFormat::Png => Some("image/png"),
+            ImageFormat::Webp => Some("image/webp"),
+            ImageFormat::Heif => Some("image/heif"),
+            ImageFormat::Avif => Some("image/avif"),
+            ImageFormat::Jxl => Some("image/jxl"),
+            ImageFormat::Tiff => Some("image/tiff"),
+            ImageFormat::Gif => Some("image/gif"),
+            ImageFormat::Pdf => Some("application/pdf"),
+            ImageFormat::Svg => Some("image/svg+xml"),
+            ImageFormat::Jp2k => Some("image/jp2"),
+            ImageFormat::Ppm => Some("image/x-portable-anymap"),
+            ImageFormat::Fits => Some("image/fits"),
+            ImageFormat::OpenExr => Some("image/x-exr"),
+            ImageFormat::Radiance => Some("image/vnd.radiance"),
+            ImageFormat::Csv => Some("text/csv"),
+            _ => None,
+        }
+    }
+}
+
+impl ForeignLoader {
+    unsafe fn from_class(class: *mut bindings::VipsForeignLoadClass) -> Self {
+        Self::from_nickname((*(class as *mut bindings::VipsObjectClass)).nickname)
+    }
+
+    unsafe fn from_nickname(nickname: *const c_char) -> Self {
+        let nickname = CStr::from_ptr(nickname)
+            .to_string_lossy()
+            .to_string();
+        Self {
+            format: ImageFormat::from_loader(&nickname),
+            nickname,
+        }
+    }
+
+    /// The MIME type of the format, if it has a registered one.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        self.format
+            .mime_type()
+    }
+
+    /// heifload reads both HEIC and AVIF, so look at the brand of the input to tell them apart.
+    fn with_header(mut self, header: &[u8]) -> Self {
+        if self.format == ImageFormat::Heif {
+            self.format = heif_format(header);
+        }
+        self
+    }
+}
+
+impl ForeignSaver {
+    unsafe fn from_nickname(nickname: *const c_char) -> Self {
+        let nickname = CStr::from_ptr(nickname)
+            .to_string_lossy()
+            .to_string();
+        Self {
+            format: ImageFormat::from_saver(&nickname),
+            nickname,
+        }
+    }
+
+    /// The MIME type of the format, if it has a registered one.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        self.format
+            .mime_type()
+    }
+
+    /// heifsave writes both HEIC and AVIF, and picks AVIF for the `.avif` suffix.
+    fn with_suffix(mut self, suffix: &str) -> Self {
+        // drop any options, as in `out.avif[Q=50]`
+        let suffix = match suffix.rfind('[') {
+            Some(index) if suffix.ends_with(']') => &suffix[..index],
+            _ => suffix,
+        };
+        if self.format == ImageFormat::Heif
+            && suffix
+                .to_ascii_lowercase()
+                .ends_with(".avif")
+        {
+            self.format = ImageFormat::Avif;
+        }
+        self
+    }
+}
+
+impl Vips {
+    /// Find the loader for a file, by sniffing its first few bytes. Nothing is decoded.
+    pub fn find_load(filename: &str) -> Result<ForeignLoader> {
+        unsafe {
+            let f = utils::new_c_string(filename)?;
+            let capture = loader_capture(vec![
+                filename.to_string(),
+            ]);
+            let class_name = bindings::vips_foreign_find_load(f.as_ptr());
+            if class_name.is_null() {
+                capture.fail(None);
+            }
+            foreign_loader_result(
+                class_name,
+                Error::IOError("Could not find a loader for file".into()),
+                &file_header(filename),
+                "load",
+            )
+        }
+    }
+
+    /// Find the loader for a formatted buffer, by sniffing its first few bytes. Nothing is decoded.
+    pub fn find_load_buffer(buffer: &[u8]) -> Result<ForeignLoader> {
+        unsafe {
+            let capture = loader_capture(Vec::new());
+            let class_name = bindings::vips_foreign_find_load_buffer(
+                buffer.as_ptr() as _,
+                buffer.len() as _,
+            );
+            if class_name.is_null() {
+                capture.fail(None);
+            }
+            foreign_loader_result(
+                class_name,
+                Error::IOError("Could not find a loader for buffer".into()),
+                buffer,
+                "load_buffer",
+            )
+        }
+    }
+
+    /// Find the loader for a source, by sniffing its first few bytes. The source is rewound afterwards.
+    pub fn find_load_source(source: &VipsSource) -> Result<ForeignLoader> {
+        unsafe {
+            let capture = loader_capture(Vec::new());
+            let class_name = bindings::vips_foreign_find_load_source(source.ctx);
+            if class_name.is_null() {
+                capture.fail(None);
+            }
+            foreign_loader_result(
+                class_name,
+                Error::IOError("Could not find a loader for source".into()),
+                &source_header(source),
+                "load_source",
+            )
+        }
+    }
+
+    /// Find the saver write_to_file() would use for a filename or suffix, for example `.png` or `out.jpg[Q=90]`.
+    pub fn find_saver(suffix: &str) -> Result<ForeignSaver> {
+        unsafe {
+            let f = utils::new_c_string(suffix)?;
+            let class_name = bindings::vips_foreign_find_save(f.as_ptr());
+            foreign_saver_result(
+                class_name,
+                Error::IOError(
+                    format!(
+                        "Could not find a saver for {}",
+                        suffix
+                    )
+                    .into(),
+                ),
+            )
+            .map(|saver| saver.with_suffix(suffix))
+        }
+    }
+
+    /// Find the saver write_to_buffer() would use for a suffix.
+    pub fn find_saver_buffer(suffix: &str) -> Result<ForeignSaver> {
+        unsafe {
+            let f = utils::new_c_string(suffix)?;
+            let class_name = bindings::vips_foreign_find_save_buffer(f.as_ptr());
+            foreign_saver_result(
+                class_name,
+                Error::IOError(
+                    format!(
+                        "Could not find a buffer saver for {}",
+                        suffix
+                    )
+                    .into(),
+                ),
+            )
+            .map(|saver| saver.with_suffix(suffix))
+        }
+    }
+
+    /// Find the saver write_to_target() would use for a suffix.
+    pub fn find_saver_target(suffix: &str) -> Result<ForeignSaver> {
+        unsafe {
+            let f = utils::new_c_string(suffix)?;
+            let class_name = bindings::vips_foreign_find_save_target(f.as_ptr());
+            foreign_saver_result(
+                class_name,
+                Error::IOError(
+                    format!(
+                        "Could not find a target saver for {}",
+                        suffix
+                    )
+                    .into(),
+                ),
+            )
+            .map(|saver| saver.with_suffix(suffix))
+        }
+    }
+
+    /// Block or unblock every operation libvips flags as untrusted, such as magickload, pdfload and svgload.
+    pub fn block_untrusted(state: bool) {
+        unsafe {
+            bindings::vips_block_untrusted_set(if state { 1 } else { 0 });
+        }
+    }
+
+    /// Block or unblock an operation by nickname or class name. Naming a base class, such as `VipsForeignLoad`,
+    /// sets the state of all operations derived from it.
+    pub fn block_operation(name: &str, state: bool) -> Result<()> {
+        let c_name = utils::new_c_string(name)?;
+        unsafe {
+            bindings::vips_operation_block_set(
+                c_name.as_ptr(),
+                if state { 1 } else { 0 },
+            );
+        }
+        Ok(())
+    }
+
+    /// Block every loader the policy does not allow, and unblock the rest.
+    pub fn loader_policy_set(policy: &LoaderPolicy) -> Result<()> {
+        match policy {
+            LoaderPolicy::AllowAll => Self::block_operation(
+                "VipsForeignLoad",
+                false,
+            ),
+            LoaderPolicy::BlockUntrusted => {
+                Self::block_operation(
+                    "VipsForeignLoad",
+                    false,
+                )?;
+                Self::block_untrusted(true);
+                Ok(())
+            }
+            LoaderPolicy::Allow(formats) => {
+                Self::block_operation(
+                    "VipsForeignLoad",
+                    true,
+                )?;
+                for class in loader_classes() {
+                    let loader = unsafe { ForeignLoader::from_class(class) };
+                    // heifload reads AVIF as well
+                    if formats.contains(&loader.format)
+                        || (loader.format == ImageFormat::Heif
+                            && formats.contains(&ImageFormat::Avif))
+                    {
+                        Self::block_operation(
+                            &loader.nickname,
+                            false,
+                        )?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// All file suffixes libvips can save to, such as `.jpg`, sorted and without duplicates.
+    pub fn supported_save_suffixes() -> Vec<String> {
+        foreign_suffixes("VipsForeignSave")
+    }
+
+    /// All file suffixes libvips can load from, such as `.jpg`, sorted and without duplicates.
+    pub fn supported_load_suffixes() -> Vec<String> {
+        foreign_suffixes("VipsForeignLoad")
+    }
+}
+
+fn foreign_suffixes(base: &str) -> Vec<String> {
+    let mut suffixes: Vec<String> = Vec::new();
+    if let Ok(base) = utils::new_c_string(base) {
+        unsafe {
+            bindings::vips_foreign_map(
+                base.as_ptr(),
+                Some(collect_suffixes),
+                &mut suffixes as *mut Vec<String> as _,
+                null_mut(),
+            );
+        }
+    }
+    suffixes.sort();
+    suffixes.dedup();
+    suffixes
+}
+
+unsafe extern "C" fn collect_suffixes(
+    class: *mut c_void,
+    suffixes: *mut c_void,
+    _b: *mut c_void,
+) -> *mut c_void {
+    let suffixes = &mut *(suffixes as *mut Vec<String>);
+    let mut suff = (*(class as *mut bindings::VipsForeignClass)).suffs;
+    if !suff.is_null() {
+        while !(*suff).is_null() {
+            suffixes.push(
+                CStr::from_ptr(*suff)
+                    .to_string_lossy()
+                    .to_string(),
+            );
+            suff = suff.add(1);
+        }
+    }
+    null_mut()
+}
+
+unsafe fn foreign_saver_result(class_name: *const c_char, err: Error) -> Result<ForeignSaver> {
+    if class_name.is_null() {
+        Err(err.extend())
+    } else {
+        Ok(ForeignSaver::from_nickname(class_nickname(class_name)))
+    }
+}
+
+/// Brackets a loader lookup, whose messages libvips reports as VipsForeignLoad.
+fn loader_capture(markers: Vec<String>) -> Capture {
+    Capture::begin(
+        vec!["VipsForeignLoad".to_string()],
+        markers,
+    )
+}
+
+/// When no loader was found, reports a blocked loader for the format the header is in instead, so the caller
+/// can tell the two apart. The format is sniffed here from a fixed table of magic bytes: the blocked loaders'
+/// own sniffers are never run on the input.
+unsafe fn foreign_loader_result(
+    class_name: *const c_char,
+    err: Error,
+    header: &[u8],
+    suffix: &str,
+) -> Result<ForeignLoader> {
+    if !class_name.is_null() {
+        return Ok(ForeignLoader::from_nickname(class_nickname(class_name)).with_header(header));
+    }
+
+    let format = match sniff_format(header) {
+        // heifload reads both
+        Some(ImageFormat::Avif) => Some(ImageFormat::Heif),
+        format => format,
+    };
+    let blocked: Vec<ForeignLoader> = loader_classes()
+        .into_iter()
+        .filter(|class| {
+            (*(*class as *mut bindings::VipsOperationClass)).flags
+                & bindings::VipsOperationFlags_VIPS_OPERATION_BLOCKED
+                != 0
+        })
+        .map(|class| ForeignLoader::from_class(class))
+        .filter(|loader| Some(loader.format) == format)
+        .collect();
+    let loader = blocked
+        .iter()
+        .find(|loader| {
+            loader
+                .nickname
+                .ends_with(suffix)
+        })
+        .or(blocked.first());
+    match loader {
+        Some(loader) => {
+            error::clear_pending();
+            Err(
+                Error::BlockedError(
+                    loader
+                        .nickname
+                        .clone(),
+                ),
+            )
+        }
+        None => Err(err.extend()),
+    }
+}
+
+/// The format of a header, from the magic bytes of the formats libvips commonly loads.
+fn sniff_format(header: &[u8]) -> Option<ImageFormat> {
+    let format = match header {
+        [0xff, 0xd8, 0xff, ..] => ImageFormat::Jpeg,
+        [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, ..] => ImageFormat::Png,
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => ImageFormat::Webp,
+        [b'G', b'I', b'F', b'8', ..] => ImageFormat::Gif,
+        [b'I', b'I', 0x2a, 0x00, ..] | [b'M', b'M', 0x00, 0x2a, ..] => ImageFormat::Tiff,
+        [b'I', b'I', 0x2b, 0x00, ..] | [b'M', b'M', 0x00, 0x2b, ..] => ImageFormat::Tiff,
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => heif_format(header),
+        [0xff, 0x0a, ..] | [0, 0, 0, 0x0c, b'J', b'X', b'L', b' ', ..] => ImageFormat::Jxl,
+        [0xff, 0x4f, 0xff, 0x51, ..] | [0, 0, 0, 0x0c, b'j', b'P', b' ', b' ', ..] => {
+            ImageFormat::Jp2k
+        }
+        [b'%', b'P', b'D', b'F', ..] => ImageFormat::Pdf,
+        [b'<', b's', b'v', b'g', ..] => ImageFormat::Svg,
+        [b'P', b'1'..=b'6' | b'f' | b'F', ..] => ImageFormat::Ppm,
+        [b'S', b'I', b'M', b'P', b'L', b'E', ..] => ImageFormat::Fits,
+        [0x76, 0x2f, 0x31, 0x01, ..] => ImageFormat::OpenExr,
+        [b'#', b'?', b'R', b'A', b'D', b'I', b'A', b'N', b'C', b'E', ..] => ImageFormat::Radiance,
+        [0x08, 0xf2, 0xa6, 0xb6, ..] | [0xb6, 0xa6, 0xf2, 0x08, ..] => ImageFormat::Vips,
+        _ => return None,
+    };
+    Some(format)
+}
+
+// Enough for the ftyp box of any HEIF file libvips accepts.
+const HEADER_LENGTH: usize = 64;
+
+/// Reads the header of a file named as for vips_foreign_find_load(), which may end with options in brackets.
+fn file_header(filename: &str) -> Vec<u8> {
+    let filename = match filename.rfind('[') {
+        Some(index) if filename.ends_with(']') => &filename[..index],
+        _ => filename,
+    };
+    let mut header = Vec::with_capacity(HEADER_LENGTH);
+    if let Ok(file) = std::fs::File::open(filename) {
+        let _ = file
+            .take(HEADER_LENGTH as u64)
+            .read_to_end(&mut header);
+    }
+    header
+}
+
+/// Peeks at the header of a source without moving its read position.
+unsafe fn source_header(source: &VipsSource) -> Vec<u8> {
+    let mut data: *mut u8 = null_mut();
+    let length = bindings::vips_source_sniff_at_most(
+        source.ctx,
+        &mut data,
+        HEADER_LENGTH as _,
+    );
+    if length <= 0 || data.is_null() {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(
+        data,
+        length as usize,
+    )
+    .to_vec()
+}
+
+/// Tells AVIF from other HEIF by the major brand of the ftyp box, then by its compatible brands.
+fn heif_format(header: &[u8]) -> ImageFormat {
+    if header.len() < 16 || &header[4..8] != b"ftyp" {
+        return ImageFormat::Heif;
+    }
+    let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let end = size.clamp(
+        16,
+        header.len(),
+    );
+    // the major brand at 8, then the minor version, then the compatible brands
+    let brands = std::iter::once(&header[8..12]).chain(header[16..end].chunks_exact(4));
+    for brand in brands {
+        match brand {
+            b"avif" | b"avis" => return ImageFormat::Avif,
+            b"heic" | b"heix" | b"heim" | b"heis" | b"hevc" | b"hevx" => return ImageFormat::Heif,
+            _ => {}
+        }
+    }
+    ImageFormat::Heif
+}
+
+/// The vips_foreign_find_*() functions return a class name, such as `VipsForeignLoadPngBuffer`.
+/// Returns the nickname of that class, such as `pngload_buffer`, or the class name if it is not an operation.
+unsafe fn class_nickname(class_name: *const c_char) -> *const c_char {
+    let nickname = bindings::vips_nickname_find(bindings::g_type_from_name(class_name));
+    if nickname.is_null() {
+        class_name
+    } else {
+        nickname
+    }
+}
+
+/// Every concrete loader class, blocked or not.
+fn loader_classes() -> Vec<*mut bindings::VipsForeignLoadClass> {
+    let mut classes: Vec<*mut bindings::VipsForeignLoadClass> = Vec::new();
+    if let Ok(base) = utils::new_c_string("VipsForeignLoad") {
+        unsafe {
+            bindings::vips_foreign_map(
+                base.as_ptr(),
+                Some(collect_loader_class),
+                &mut classes as *mut Vec<*mut bindings::VipsForeignLoadClass> as _,
+                null_mut(),
+            );
+        }
+    }
+    classes
+}
+
+unsafe extern "C" fn collect_loader_class(
+    class: *mut c_void,
+    classes: *mut c_void,
+    _b: *mut c_void,
+) -> *mut c_void {
+    let classes = &mut *(classes as *mut Vec<*mut bindings::VipsForeignLoadClass>);
+    if bindings::g_type_test_flags(
+        (*(class as *mut bindings::GTypeClass)).g_type,
+        bindings::GTypeFlags_G_TYPE_FLAG_ABSTRACT,
+    ) == 0
+    {
+        classes.push(class as _);
+    }
+    null_mut()
+}
@@ -1,22 +1,23 @@
 // (c) Copyright 2019-2025 OLX
 // (c) Copyright 2025 mrdkprj
 use crate::{
-    bindings::{self, vips_blob_new},
+    bindings,
     connection::{VipsSource, VipsTarget},
-    error::Error,
+    error::{self, Capture, Error},
     ops::*,
-    region::VipsBlob,
+    region::{VipsBlob, VipsBuffer},
     utils::{self, ensure_null_terminated, vips_image_result, vips_image_result_ext},
     voption::{call, call_option_string_, Setter, VOption},
-    Result,
+    Result, Vips,
 };
 use num_traits::{FromPrimitive, ToPrimitive};
 use std::ptr::null_mut;
-use std::{ffi::*, path::Path};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::{ffi::*, mem::MaybeUninit, path::Path};
 
 const NULL: *const c_void = null_mut();
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsImage {
     pub(crate) ctx: *mut bindings::VipsImage,
 }
@@ -30,7 +31,11 @@ impl Default for VipsImage {
 }
 
 /// This is the main type of vips. It represents an image and most operations will take one as input and output a new one.
-/// In the moment this type is not thread safe. Be careful working within thread environments.
+///
+/// `VipsImage` is `Send` and `Sync`. libvips images are immutable once built, so an image can be moved to
+/// or shared between threads and used as the input of operations concurrently.
+/// Metadata setters take `&mut self`. Clones share the same underlying image, so a setter first gives its
+/// handle a copy of its own when the image is shared, and the change is never seen through other handles.
 impl VipsImage {
     pub fn new() -> VipsImage {
         VipsImage {
@@ -43,7 +48,7 @@ impl VipsImage {
             let res = bindings::vips_image_new_memory();
             vips_image_result(
                 res,
-                Error::InitializationError("Could not generate object".to_string()),
+                Error::InitializationError("Could not generate object".into()),
             )
         }
     }
@@ -69,24 +74,20 @@ impl VipsImage {
             let filename_ = bindings::vips_filename_get_filename(f.as_ptr());
             let string_options = bindings::vips_filename_get_options(f.as_ptr());
 
-            let operation = bindings::vips_foreign_find_load(filename_);
-            if operation.is_null() {
-                return vips_image_result(
-                    NULL as _,
-                    Error::InitializationError("Could not find operation".to_string()),
-                );
-            }
+            let filename_ = CStr::from_ptr(filename_)
+                .to_str()
+                .map_err(|_| Error::InitializationError("Invalid filename".into()))?;
+            let loader = Vips::find_load(filename_)?;
+            let operation = utils::new_c_string(loader.nickname)?;
 
             let mut out_out = VipsImage::from(null_mut());
             call_option_string_(
-                operation as _,
+                operation.as_ptr() as _,
                 string_options as _,
                 option
                     .set(
                         "filename",
-                        CStr::from_ptr(filename_)
-                            .to_str()
-                            .unwrap(),
+                        filename_,
                     )
                     .set(
                         "out",
@@ -95,7 +96,7 @@ impl VipsImage {
             );
             vips_image_result_ext(
                 out_out,
-                Error::InitializationError("Could not initialise VipsImage from file".to_string()),
+                Error::InitializationError("Could not initialise VipsImage from file".into()),
             )
         }
     }
@@ -111,7 +112,7 @@ impl VipsImage {
             let res = bindings::vips_image_new_from_file_RW(f.as_ptr());
             vips_image_result(
                 res,
-                Error::InitializationError("Could not initialise VipsImage from file".to_string()),
+                Error::InitializationError("Could not initialise VipsImage from file".into()),
             )
         }
     }
@@ -139,7 +140,7 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError("Could not initialise VipsImage from file".to_string()),
+                Error::InitializationError("Could not initialise VipsImage from file".into()),
             )
         }
     }
@@ -168,11 +169,12 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError("Could not initialise VipsImage from file".to_string()),
+                Error::InitializationError("Could not initialise VipsImage from file".into()),
             )
         }
     }
 
+    /// Loads an image from a formatted buffer. buffer is copied, so it can be dropped as soon as this returns.
     pub fn new_from_buffer(buffer: &[u8], option_str: &str) -> Result<VipsImage> {
         Self::new_from_buffer_with_opts(
             buffer,
@@ -185,46 +187,98 @@ impl VipsImage {
         buffer: &[u8],
         option_str: &str,
         option: VOption,
+    ) -> Result<VipsImage> {
+        let blob = VipsBlob::copy_from_slice(buffer);
+        Self::new_from_blob_with_opts(
+            &blob,
+            option_str,
+            option,
+        )
+    }
+
+    /// Loads an image from a formatted buffer without copying it. The image takes ownership of buffer
+    /// and drops it once libvips no longer needs it.
+    pub fn new_from_buffer_owned<T>(buffer: T, option_str: &str) -> Result<VipsImage>
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        Self::new_from_buffer_owned_with_opts(
+            buffer,
+            option_str,
+            VOption::new(),
+        )
+    }
+
+    pub fn new_from_buffer_owned_with_opts<T>(
+        buffer: T,
+        option_str: &str,
+        option: VOption,
+    ) -> Result<VipsImage>
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        let blob = VipsBlob::new(buffer);
+        Self::new_from_blob_with_opts(
+            &blob,
+            option_str,
+            option,
+        )
+    }
+
+    /// Loads an image from a formatted blob. The image takes a new reference to blob; nothing is copied.
+    pub fn new_from_blob(blob: &VipsBlob, option_str: &str) -> Result<VipsImage> {
+        Self::new_from_blob_with_opts(
+            blob,
+            option_str,
+            VOption::new(),
+        )
+    }
+
+    pub fn new_from_blob_with_opts(
+        blob: &VipsBlob,
+        option_str: &str,
+        option: VOption,
     ) -> Result<VipsImage> {
         unsafe {
-            let operation = bindings::vips_foreign_find_load_buffer(
-                buffer.as_ptr() as *const c_void,
-                buffer.len() as u64,
+            let mut length = 0;
+            let data = bindings::vips_blob_get(
+                blob.ctx,
+                &mut length,
             );
-            if operation.is_null() {
-                return vips_image_result(
-                    NULL as _,
-                    Error::InitializationError(
-                        "Could not initialise VipsImage from buffer".to_string(),
-                    ),
-                );
-            }
+            let loader = Vips::find_load_buffer(
+                std::slice::from_raw_parts(
+                    data as *const u8,
+                    length as usize,
+                ),
+            )?;
+            let operation = utils::new_c_string(
+                loader
+                    .nickname
+                    .as_str(),
+            )?;
 
-            let vips_blob = vips_blob_new(
-                None,
-                buffer.as_ptr() as _,
-                buffer.len() as _,
-            );
             let mut out_out = VipsImage::from(null_mut());
-            let blob = VipsBlob::from(vips_blob);
             call_option_string_(
-                operation as _,
+                operation.as_ptr() as _,
                 utils::new_c_string(option_str)?.as_ptr() as _,
                 option
                     .set(
                         "buffer",
-                        &blob,
+                        blob,
                     )
                     .set(
                         "out",
                         &mut out_out,
                     ),
             );
-            blob.area_unref();
             vips_image_result_ext(
                 out_out,
                 Error::InitializationError(
-                    "Could not initialise VipsImage from buffer".to_string(),
+                    format!(
+                        "Could not initialise VipsImage from buffer with {}",
+                        loader.nickname
+                    )
+                    .into(),
                 ),
             )
         }
@@ -243,40 +297,40 @@ impl VipsImage {
         option_str: &str,
         option: VOption,
     ) -> Result<VipsImage> {
-        unsafe {
-            let operation = bindings::vips_foreign_find_load_source(source.ctx);
-            if operation.is_null() {
-                return vips_image_result(
-                    NULL as _,
-                    Error::InitializationError(
-                        "Could not initialise VipsImage from source".to_string(),
-                    ),
-                );
-            }
-
-            let mut out_out = VipsImage::from(null_mut());
-            call_option_string_(
-                operation as _,
-                utils::new_c_string(option_str)?.as_ptr() as _,
-                option
-                    .set(
-                        "source",
-                        source,
-                    )
-                    .set(
-                        "out",
-                        &mut out_out,
-                    ),
-            );
-            vips_image_result_ext(
-                out_out,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from buffer".to_string(),
+        let loader = Vips::find_load_source(source)?;
+        let operation = utils::new_c_string(
+            loader
+                .nickname
+                .as_str(),
+        )?;
+
+        let mut out_out = VipsImage::from(null_mut());
+        call_option_string_(
+            operation.as_ptr() as _,
+            utils::new_c_string(option_str)?.as_ptr() as _,
+            option
+                .set(
+                    "source",
+                    source,
+                )
+                .set(
+                    "out",
+                    &mut out_out,
                 ),
-            )
-        }
+        );
+        vips_image_result_ext(
+            out_out,
+            Error::InitializationError(
+                format!(
+                    "Could not initialise VipsImage from source with {}",
+                    loader.nickname
+                )
+                .into(),
+            ),
+        )
     }
 
+    /// Wraps an image around an area of memory containing pixels. buffer is copied, so it can be dropped as soon as this returns.
     pub fn new_from_memory(
         buffer: &[u8],
         width: i32,
@@ -286,7 +340,7 @@ impl VipsImage {
     ) -> Result<VipsImage> {
         unsafe {
             if let Some(format) = format.to_i32() {
-                let res = bindings::vips_image_new_from_memory(
+                let res = bindings::vips_image_new_from_memory_copy(
                     buffer.as_ptr() as *const c_void,
                     buffer.len() as u64,
                     width,
@@ -296,14 +350,60 @@ impl VipsImage {
                 );
                 vips_image_result(
                     res,
-                    Error::InitializationError(
-                        "Could not initialise VipsImage from memory".to_string(),
-                    ),
+                    Error::InitializationError("Could not initialise VipsImage from memory".into()),
                 )
             } else {
                 Err(Error::InitializationError(
-                    "Invalid BandFormat. Please file a bug report, as this should never happen.".to_string(),
-                ))
+                    "Invalid BandFormat. Please file a bug report, as this should never happen.".into()))
+            }
+        }
+    }
+
+    /// Wraps an image around an area of memory containing pixels without copying it. The image takes ownership of buffer
+    /// and drops it when the image and every image derived from it are gone.
+    pub fn new_from_memory_owned<T>(
+        buffer: T,
+        width: i32,
+        height: i32,
+        bands: i32,
+        format: BandFormat,
+    ) -> Result<VipsImage>
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        unsafe {
+            if let Some(format) = format.to_i32() {
+                let blob = VipsBlob::new(buffer);
+                let mut length = 0;
+                let data = bindings::vips_blob_get(
+                    blob.ctx,
+                    &mut length,
+                );
+                let res = bindings::vips_image_new_from_memory(
+                    data,
+                    length,
+                    width,
+                    height,
+                    bands,
+                    format,
+                );
+                if !res.is_null() {
+                    // the image keeps the blob alive until it is finalized
+                    let key = utils::new_c_string("rs-vips-memory")?;
+                    bindings::g_object_set_data_full(
+                        res as _,
+                        key.as_ptr(),
+                        bindings::vips_area_copy(&mut (*blob.ctx).area) as _,
+                        Some(unref_blob),
+                    );
+                }
+                vips_image_result(
+                    res,
+                    Error::InitializationError("Could not initialise VipsImage from memory".into()),
+                )
+            } else {
+                Err(Error::InitializationError(
+                    "Invalid BandFormat. Please file a bug report, as this should never happen.".into()))
             }
         }
     }
@@ -316,9 +416,7 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from matrix".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from matrix".into()),
             )
         }
     }
@@ -343,9 +441,7 @@ impl VipsImage {
             }
             vips_image_result(
                 matrix,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from matrix".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from matrix".into()),
             )
         }
     }
@@ -360,9 +456,7 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from matrix".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from matrix".into()),
             )
         }
     }
@@ -376,9 +470,7 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from Object".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from Object".into()),
             )
         }
     }
@@ -391,9 +483,7 @@ impl VipsImage {
             );
             vips_image_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from Object".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from Object".into()),
             )
         }
     }
@@ -404,9 +494,7 @@ impl VipsImage {
             let res = bindings::vips_image_new_temp_file(format_c_str.as_ptr());
             vips_image_result(
                 res,
-                Error::InitializationError(
-                    "Could not initialise VipsImage from format".to_string(),
-                ),
+                Error::InitializationError("Could not initialise VipsImage from format".into()),
             )
         }
     }
@@ -416,7 +504,7 @@ impl VipsImage {
             let result = bindings::vips_image_copy_memory(image.ctx);
             vips_image_result(
                 result,
-                Error::OperationError("Could not copy memory".to_string()),
+                Error::OperationError("Could not copy memory".into()),
             )
         }
     }
@@ -427,7 +515,7 @@ impl VipsImage {
             utils::result(
                 result,
                 (),
-                Error::OperationError("Error on vips image_wio_input".to_string()),
+                Error::OperationError("Error on vips image_wio_input".into()),
             )
         }
     }
@@ -488,7 +576,7 @@ impl VipsImage {
         unsafe {
             let res = bindings::vips_image_get_format(self.ctx);
             let format_enum = FromPrimitive::from_i32(res);
-            format_enum.ok_or(Error::IOError("Could get format from image".to_string()))
+            format_enum.ok_or(Error::IOError("Could get format from image".into()))
         }
     }
 
@@ -496,7 +584,7 @@ impl VipsImage {
         unsafe {
             let res = bindings::vips_image_get_format(self.ctx);
             let format_enum = FromPrimitive::from_i32(res);
-            format_enum.ok_or(Error::IOError("Could get format from image".to_string()))
+            format_enum.ok_or(Error::IOError("Could get format from image".into()))
         }
     }
 
@@ -504,7 +592,7 @@ impl VipsImage {
         unsafe {
             let res = bindings::vips_image_guess_format(self.ctx);
             let format_enum = FromPrimitive::from_i32(res);
-            format_enum.ok_or(Error::IOError("Could get format from image".to_string()))
+            format_enum.ok_or(Error::IOError("Could get format from image".into()))
         }
     }
 
@@ -516,7 +604,7 @@ impl VipsImage {
         unsafe {
             let res = bindings::vips_image_get_interpretation(self.ctx);
             let format_enum = FromPrimitive::from_i32(res);
-            format_enum.ok_or(Error::IOError("Could get format from image".to_string()))
+            format_enum.ok_or(Error::IOError("Could get format from image".into()))
         }
     }
 
@@ -524,7 +612,7 @@ impl VipsImage {
         unsafe {
             let res = bindings::vips_image_guess_interpretation(self.ctx);
             let format_enum = FromPrimitive::from_i32(res);
-            format_enum.ok_or(Error::IOError("Could get format from image".to_string()))
+            format_enum.ok_or(Error::IOError("Could get format from image".into()))
         }
     }
 
@@ -575,7 +663,7 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot read image".to_string()),
+                Error::IOError("Cannot read image".into()),
             )
         }
     }
@@ -586,7 +674,7 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot write image".to_string()),
+                Error::IOError("Cannot write image".into()),
             )
         }
     }
@@ -597,7 +685,7 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot cannot be modified inplace".to_string()),
+                Error::IOError("Cannot cannot be modified inplace".into()),
             )
         }
     }
@@ -632,7 +720,7 @@ impl VipsImage {
                 VipsImage {
                     ctx: out,
                 },
-                Error::IOError("Cannot write input to output".to_string()),
+                Error::IOError("Cannot write input to output".into()),
             )
         }
     }
@@ -659,12 +747,22 @@ impl VipsImage {
             let filename_ = bindings::vips_filename_get_filename(f.as_ptr());
             let string_options = bindings::vips_filename_get_options(f.as_ptr());
 
+            let capture = Capture::begin(
+                vec!["VipsForeignSave".to_string()],
+                vec![CStr::from_ptr(filename_)
+                    .to_string_lossy()
+                    .to_string()],
+            );
             let operation = bindings::vips_foreign_find_save(filename_);
+            if operation.is_null() {
+                capture.fail(None);
+            }
+            drop(capture);
             if operation.is_null() {
                 return utils::result(
                     -1,
                     (),
-                    Error::IOError("Cannot write to file".to_string()),
+                    Error::IOError("Cannot write to file".into()),
                 );
             }
 
@@ -683,7 +781,7 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot write to file".to_string()),
+                Error::IOError("Cannot write to file".into()),
             )
         }
     }
@@ -694,19 +792,20 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot prepare file to write".to_string()),
+                Error::IOError("Cannot prepare file to write".into()),
             )
         }
     }
 
-    pub fn write_to_buffer(&self, suffix: &str) -> Result<Vec<u8>> {
+    /// Saves the image to a formatted buffer. The returned buffer holds the memory allocated by libvips without copying it.
+    pub fn write_to_buffer(&self, suffix: &str) -> Result<VipsBuffer> {
         self.write_to_buffer_with_opts(
             suffix,
             VOption::new(),
         )
     }
 
-    pub fn write_to_buffer_with_opts(&self, suffix: &str, option: VOption) -> Result<Vec<u8>> {
+    pub fn write_to_buffer_with_opts(&self, suffix: &str, option: VOption) -> Result<VipsBuffer> {
         unsafe {
             let f = utils::new_c_string(suffix)?;
             let filename = bindings::vips_filename_get_filename(f.as_ptr());
@@ -717,9 +816,13 @@ impl VipsImage {
              *
              * We need to hide any errors from this first phase.
              */
-            bindings::vips_error_freeze();
+            let capture = Capture::begin(
+                vec!["VipsForeignSave".to_string()],
+                Vec::new(),
+            );
             let operation = bindings::vips_foreign_find_save_target(filename);
-            bindings::vips_error_thaw();
+            error::discard_messages();
+            drop(capture);
 
             if !operation.is_null() {
                 let target = VipsTarget::new_to_memory()?;
@@ -736,12 +839,8 @@ impl VipsImage {
                 return utils::safe_result(
                     res,
                     target,
-                    move |target| {
-                        target
-                            .get_blob()
-                            .into()
-                    },
-                    Error::IOError("Cannot write to buffer".to_string()),
+                    move |target| VipsBuffer::from(target.get_blob()),
+                    Error::IOError("Cannot write to buffer".into()),
                 );
             }
 
@@ -749,8 +848,8 @@ impl VipsImage {
             if operation.is_null() {
                 return utils::result(
                     -1,
-                    Vec::new(),
-                    Error::IOError("Cannot write to buffer".to_string()),
+                    VipsBuffer::from(VipsBlob::from(null_mut())),
+                    Error::IOError("Cannot write to buffer".into()),
                 );
             }
 
@@ -767,8 +866,8 @@ impl VipsImage {
             );
             utils::result(
                 res,
-                buffer_out.into(),
-                Error::IOError("Cannot write to buffer".to_string()),
+                VipsBuffer::from(buffer_out),
+                Error::IOError("Cannot write to buffer".into()),
             )
         }
     }
@@ -798,7 +897,7 @@ impl VipsImage {
                 return utils::result(
                     -1,
                     (),
-                    Error::IOError("Cannot write to target".to_string()),
+                    Error::IOError("Cannot write to target".into()),
                 );
             }
 
@@ -815,25 +914,29 @@ impl VipsImage {
             utils::result(
                 res,
                 (),
-                Error::IOError("Cannot write to target".to_string()),
+                Error::IOError("Cannot write to target".into()),
             )
         }
     }
 
-    pub fn write_to_memory(&self) -> Vec<u8> {
+    /// Writes the pixels of the image to memory. The returned buffer holds the memory allocated by libvips without copying it.
+    pub fn write_to_memory(&self) -> Result<VipsBuffer> {
+        crate::register_thread();
         unsafe {
             let mut buffer_buf_size: u64 = 0;
             let buffer_out = bindings::vips_image_write_to_memory(
                 self.ctx,
                 &mut buffer_buf_size,
             );
-            let buf = std::slice::from_raw_parts(
-                buffer_out as *mut u8,
-                buffer_buf_size as usize,
+            if buffer_out.is_null() {
+                return Err(Error::IOError("Cannot write to memory".into()).extend());
+            }
+            Ok(
+                VipsBuffer::from_glib(
+                    buffer_out,
+                    buffer_buf_size,
+                ),
             )
-            .to_vec();
-            bindings::g_free(buffer_out);
-            buf
         }
     }
 
@@ -859,10 +962,10 @@ impl VipsImage {
                         out_bands,
                         format_enum,
                     ),
-                    Error::IOError("Could not predict image format".to_string()),
+                    Error::IOError("Could not predict image format".into()),
                 )
             } else {
-                Err(Error::IOError("Could not predict image format".to_string()))
+                Err(Error::IOError("Could not predict image format".into()))
             }
         }
     }
@@ -879,7 +982,7 @@ impl VipsImage {
                 VipsImage {
                     ctx: out,
                 },
-                Error::IOError("Cannot decode image".to_string()),
+                Error::IOError("Cannot decode image".into()),
             )
         }
     }
@@ -897,7 +1000,7 @@ impl VipsImage {
                 VipsImage {
                     ctx: out,
                 },
-                Error::IOError("Cannot encode image".to_string()),
+                Error::IOError("Cannot encode image".into()),
             )
         }
     }
@@ -917,7 +1020,7 @@ impl VipsImage {
             utils::result(
                 0,
                 gtype,
-                Error::IOError("Cannot get type".to_string()),
+                Error::IOError("Cannot get type".into()),
             )
         }
     }
@@ -935,15 +1038,16 @@ impl VipsImage {
             utils::result(
                 res,
                 out,
-                Error::IOError("Cannot get int".to_string()),
+                Error::IOError("Cannot get int".into()),
             )
         }
     }
 
     /// Attaches int as a metadata item on image as name.
-    pub fn set_int(&self, name: impl AsRef<[u8]>, value: i32) -> Result<()> {
+    pub fn set_int(&mut self, name: impl AsRef<[u8]>, value: i32) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
             bindings::vips_image_set_int(
                 self.ctx,
                 name.as_ptr(),
@@ -966,15 +1070,16 @@ impl VipsImage {
             utils::result(
                 res,
                 out,
-                Error::IOError("Cannot get int".to_string()),
+                Error::IOError("Cannot get int".into()),
             )
         }
     }
 
     /// Attaches double as a metadata item on image as name.
-    pub fn set_double(&self, name: impl AsRef<[u8]>, value: f64) -> Result<()> {
+    pub fn set_double(&mut self, name: impl AsRef<[u8]>, value: f64) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
             bindings::vips_image_set_double(
                 self.ctx,
                 name.as_ptr(),
@@ -1004,16 +1109,17 @@ impl VipsImage {
                         String::new()
                     }
                 },
-                Error::IOError("Cannot get string".to_string()),
+                Error::IOError("Cannot get string".into()),
             )
         }
     }
 
     /// Attaches string as a metadata item on image as name.
-    pub fn set_string(&self, name: impl AsRef<[u8]>, value: &str) -> Result<()> {
+    pub fn set_string(&mut self, name: impl AsRef<[u8]>, value: &str) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
             let value = ensure_null_terminated(value)?;
+            self.make_unique()?;
 
             bindings::vips_image_set_string(
                 self.ctx,
@@ -1024,41 +1130,47 @@ impl VipsImage {
         }
     }
 
-    /// Gets data from image under the name.
-    pub fn get_blob(&self, name: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    /// Gets data from image under the name. The returned blob shares the memory held by the image.
+    pub fn get_blob(&self, name: impl AsRef<[u8]>) -> Result<VipsBlob> {
         unsafe {
-            let mut out: *const c_void = std::ptr::null();
-            let mut length = 0;
             let name = ensure_null_terminated(name)?;
-            let res = bindings::vips_image_get_blob(
+            let mut gvalue = MaybeUninit::<bindings::GValue>::zeroed();
+            let gvalue_ptr = gvalue.as_mut_ptr();
+            let res = bindings::vips_image_get(
                 self.ctx,
                 name.as_ptr(),
-                &mut out,
-                &mut length,
+                gvalue_ptr,
             );
-            utils::safe_result(
-                res,
-                out,
-                move |out| {
-                    std::slice::from_raw_parts(
-                        out as *const u8,
-                        length as _,
-                    )
-                    .to_vec()
-                },
-                Error::IOError("Cannot get blob".to_string()),
-            )
+            if res != 0 {
+                return utils::result(
+                    res,
+                    VipsBlob::from(null_mut()),
+                    Error::IOError("Cannot get blob".into()),
+                );
+            }
+
+            let blob = if (*gvalue_ptr).g_type == bindings::vips_blob_get_type() {
+                Ok(
+                    VipsBlob::from(
+                        bindings::g_value_dup_boxed(gvalue_ptr) as *mut bindings::VipsBlob,
+                    ),
+                )
+            } else {
+                Err(Error::IOError("Cannot get blob".into()))
+            };
+            bindings::g_value_unset(gvalue_ptr);
+            blob
         }
     }
 
-    /// Attaches data as a metadata item on image under the name.
-    pub fn set_blob(&self, name: impl AsRef<[u8]>, blob: &[u8]) -> Result<()> {
+    /// Attaches a copy of data as a metadata item on image under the name.
+    pub fn set_blob(&mut self, name: impl AsRef<[u8]>, blob: &[u8]) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
-            bindings::vips_image_set_blob(
+            self.make_unique()?;
+            bindings::vips_image_set_blob_copy(
                 self.ctx,
                 name.as_ptr(),
-                None,
                 blob.as_ptr() as _,
                 blob.len() as _,
             );
@@ -1066,6 +1178,31 @@ impl VipsImage {
         }
     }
 
+    /// Attaches blob as a metadata item on image under the name. The image takes a new reference to blob; nothing is copied.
+    pub fn set_vips_blob(&mut self, name: impl AsRef<[u8]>, blob: &VipsBlob) -> Result<()> {
+        unsafe {
+            let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
+            let mut gvalue = MaybeUninit::<bindings::GValue>::zeroed();
+            let gvalue_ptr = gvalue.as_mut_ptr();
+            bindings::g_value_init(
+                gvalue_ptr,
+                bindings::vips_blob_get_type(),
+            );
+            bindings::g_value_set_boxed(
+                gvalue_ptr,
+                blob.ctx as *const c_void,
+            );
+            bindings::vips_image_set(
+                self.ctx,
+                name.as_ptr(),
+                gvalue_ptr,
+            );
+            bindings::g_value_unset(gvalue_ptr);
+            Ok(())
+        }
+    }
+
     /// Gets an array of int from image under the name.
     pub fn get_array_int(&self, name: impl AsRef<[u8]>) -> Result<Vec<i32>> {
         unsafe {
@@ -1087,15 +1224,16 @@ impl VipsImage {
                         size as _,
                     )
                 },
-                Error::IOError("Cannot get array int".to_string()),
+                Error::IOError("Cannot get array int".into()),
             )
         }
     }
 
     /// Attaches array as a metadata item on image as name.
-    pub fn set_array_int(&self, name: impl AsRef<[u8]>, value: &[i32]) -> Result<()> {
+    pub fn set_array_int(&mut self, name: impl AsRef<[u8]>, value: &[i32]) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
             bindings::vips_image_set_array_int(
                 self.ctx,
                 name.as_ptr(),
@@ -1127,15 +1265,16 @@ impl VipsImage {
                         size as _,
                     )
                 },
-                Error::IOError("Cannot get array double".to_string()),
+                Error::IOError("Cannot get array double".into()),
             )
         }
     }
 
     /// Attaches array as a metadata item on image as name.
-    pub fn set_array_double(&self, name: impl AsRef<[u8]>, value: &[f64]) -> Result<()> {
+    pub fn set_array_double(&mut self, name: impl AsRef<[u8]>, value: &[f64]) -> Result<()> {
         unsafe {
             let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
             bindings::vips_image_set_array_double(
                 self.ctx,
                 name.as_ptr(),
@@ -1147,9 +1286,10 @@ impl VipsImage {
     }
 
     /// Find and remove an item of metadata.
-    pub fn remove(&self, name: impl AsRef<[u8]>) -> Result<bool> {
+    pub fn remove(&mut self, name: impl AsRef<[u8]>) -> Result<bool> {
         unsafe {
             let name = ensure_null_terminated(name)?;
+            self.make_unique()?;
             Ok(
                 bindings::vips_image_remove(
                     self.ctx,
@@ -1159,6 +1299,53 @@ impl VipsImage {
         }
     }
 
+    /// Gives this handle an image of its own before its metadata or pixels change, so clones never see the change.
+    pub(crate) fn make_unique(&mut self) -> Result<()> {
+        unsafe {
+            if self
+                .ctx
+                .is_null()
+            {
+                return Ok(());
+            }
+            // other handles may ref or unref concurrently, so read the count atomically
+            let ref_count = &*(std::ptr::addr_of!(
+                (*self.ctx)
+                    .parent_instance
+                    .parent_instance
+                    .ref_count
+            ) as *const AtomicU32);
+            if ref_count.load(Ordering::Acquire) <= 1 {
+                // changed in place, so it no longer matches the linear it was made with
+                self.forget_linear_source();
+                return Ok(());
+            }
+            *self = self.private_copy()?;
+            Ok(())
+        }
+    }
+
+    /// A new image computed from this one that no other handle can reach.
+    pub(crate) fn private_copy(&self) -> Result<VipsImage> {
+        unsafe {
+            // vips_image_write() links a new image to this one without going through the operation cache,
+            // which could hand the same copy to another caller
+            let out = bindings::vips_image_new();
+            let res = bindings::vips_image_write(
+                self.ctx,
+                out,
+            );
+            // on failure the unused image is dropped along with the error
+            utils::result(
+                res,
+                VipsImage {
+                    ctx: out,
+                },
+                Error::OperationError("Cannot copy image".into()),
+            )
+        }
+    }
+
     pub fn minpos(&self) -> Result<(f64, f64)> {
         let mut x: f64 = 0.0;
         let mut y: f64 = 0.0;
@@ -1179,7 +1366,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (x, y),
-            Error::OperationError("minpos failed".to_string()),
+            Error::OperationError("minpos failed".into()),
         )
     }
 
@@ -1203,11 +1390,30 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (x, y),
-            Error::OperationError("maxpos failed".to_string()),
+            Error::OperationError("maxpos failed".into()),
         )
     }
 }
 
+unsafe impl Send for VipsImage {}
+unsafe impl Sync for VipsImage {}
+
+impl Clone for VipsImage {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        Self {
+            ctx: self.ctx,
+        }
+    }
+}
+
 impl Drop for VipsImage {
     fn drop(&mut self) {
         unsafe {
@@ -1221,6 +1427,10 @@ impl Drop for VipsImage {
     }
 }
 
+unsafe extern "C" fn unref_blob(blob: *mut c_void) {
+    bindings::vips_area_unref(blob as _);
+}
+
 impl From<*mut bindings::VipsImage> for VipsImage {
     fn from(value: *mut bindings::VipsImage) -> Self {
         Self {
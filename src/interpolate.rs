@@ -3,7 +3,7 @@
 use crate::{bindings, error::Error, utils, Result};
 use std::ffi::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsInterpolate {
     pub(crate) ctx: *mut bindings::VipsInterpolate,
 }
@@ -12,7 +12,10 @@ impl Default for VipsInterpolate {
     fn default() -> VipsInterpolate {
         unsafe {
             VipsInterpolate {
-                ctx: bindings::vips_interpolate_nearest_static(),
+                ctx: bindings::g_object_ref(
+                    bindings::vips_interpolate_nearest_static() as *mut c_void,
+                )
+                .cast(),
             }
         }
     }
@@ -23,7 +26,10 @@ impl VipsInterpolate {
     pub fn new() -> VipsInterpolate {
         unsafe {
             VipsInterpolate {
-                ctx: bindings::vips_interpolate_nearest_static(),
+                ctx: bindings::g_object_ref(
+                    bindings::vips_interpolate_nearest_static() as *mut c_void,
+                )
+                .cast(),
             }
         }
     }
@@ -32,7 +38,10 @@ impl VipsInterpolate {
     pub fn new_from_neasest_static() -> VipsInterpolate {
         unsafe {
             VipsInterpolate {
-                ctx: bindings::vips_interpolate_nearest_static(),
+                ctx: bindings::g_object_ref(
+                    bindings::vips_interpolate_nearest_static() as *mut c_void,
+                )
+                .cast(),
             }
         }
     }
@@ -41,7 +50,10 @@ impl VipsInterpolate {
     pub fn new_from_bilinear_static() -> VipsInterpolate {
         unsafe {
             VipsInterpolate {
-                ctx: bindings::vips_interpolate_bilinear_static(),
+                ctx: bindings::g_object_ref(
+                    bindings::vips_interpolate_bilinear_static() as *mut c_void,
+                )
+                .cast(),
             }
         }
     }
@@ -54,7 +66,7 @@ impl VipsInterpolate {
             if res.is_null() {
                 Err(
                     Error::InitializationError(
-                        "Cannot initialize interpolator with provided nickname".to_string(),
+                        "Cannot initialize interpolator with provided nickname".into(),
                     ),
                 )
             } else {
@@ -78,6 +90,25 @@ impl VipsInterpolate {
     }
 }
 
+unsafe impl Send for VipsInterpolate {}
+unsafe impl Sync for VipsInterpolate {}
+
+impl Clone for VipsInterpolate {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        Self {
+            ctx: self.ctx,
+        }
+    }
+}
+
 impl Drop for VipsInterpolate {
     fn drop(&mut self) {
         unsafe {
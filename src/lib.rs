@@ -9,56 +9,232 @@
 extern crate num_derive;
 extern crate num_traits;
 
+#[cfg(feature = "tokio")]
+/// Async sources, targets and blocking wrappers
+mod async_io;
 pub mod bindings;
+/// VipsConfig, VipsSettings
+mod config;
 /// VipsConnection, VipsSource, VipsTarget
 mod connection;
 pub mod error;
+/// ImageFormat, ForeignLoader, ForeignSaver
+mod foreign;
 /// VipsImage
 mod image;
 /// VipsInterpolate
 mod interpolate;
+/// Operation metadata
+pub mod operation;
 pub mod operator;
 /// Vips Enumerations
 pub mod ops;
-/// VipsBlob
+/// Typed optional arguments for the `*_with_opts` operations
+pub mod options;
+/// VipsBlob, VipsBuffer
 mod region;
 pub mod utils;
 /// VOption, a list of name-value pairs
 pub mod voption;
 
+pub use config::*;
 pub use connection::*;
 use error::Error;
+pub use foreign::*;
 pub use image::*;
 pub use interpolate::*;
 pub use region::*;
 use std::ffi::*;
+use std::sync::Mutex;
 pub type Result<T> = std::result::Result<T, error::Error>;
 
 pub struct Vips;
 
+struct VipsState {
+    guards: usize,
+    started: bool,
+    shut_down: bool,
+    leak: bool,
+    progress: bool,
+    pipe_read_limit: i64,
+}
+
+static STATE: Mutex<VipsState> = Mutex::new(
+    VipsState {
+        guards: 0,
+        started: false,
+        shut_down: false,
+        leak: false,
+        progress: false,
+        // libvips default, 1GB
+        pipe_read_limit: 1024 * 1024 * 1024,
+    },
+);
+
+fn lock_state() -> std::sync::MutexGuard<'static, VipsState> {
+    STATE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Keeps libvips running. Returned by [`Vips::init`].
+///
+/// libvips is shut down when the last guard is dropped. If `VipsImage` objects are still alive at that point,
+/// the shutdown is refused with a warning, since the images would be unusable afterwards.
+#[must_use = "libvips is shut down as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct VipsGuard {
+    _private: (),
+}
+
+impl Drop for VipsGuard {
+    fn drop(&mut self) {
+        let mut state = lock_state();
+        state.guards -= 1;
+        if state.guards > 0 || state.shut_down {
+            return;
+        }
+
+        // cached operations hold images that nobody can use any more
+        Vips::cache_drop_all();
+        let live_images = Vips::live_image_count();
+        if live_images > 0 {
+            warning(&format!(
+                "vips shutdown refused: {} VipsImage objects are still alive",
+                live_images
+            ));
+            return;
+        }
+
+        unsafe {
+            bindings::vips_shutdown();
+        }
+        state.shut_down = true;
+    }
+}
+
+struct ThreadCleanup;
+
+impl Drop for ThreadCleanup {
+    fn drop(&mut self) {
+        if !lock_state().shut_down {
+            unsafe {
+                bindings::vips_thread_shutdown();
+            }
+        }
+    }
+}
+
+thread_local! {
+    static THREAD_CLEANUP: ThreadCleanup = const { ThreadCleanup };
+}
+
+/// Makes sure vips_thread_shutdown() runs when the current thread exits.
+pub(crate) fn register_thread() {
+    THREAD_CLEANUP.with(|_| {});
+}
+
+/// Starts up libvips while the caller holds the state lock.
+fn init_locked(state: &mut VipsState, name: &str) -> Result<VipsGuard> {
+    if state.shut_down {
+        return Err(Error::InitializationError("libvips has already been shut down".into()));
+    }
+
+    let c_name = utils::new_c_string(name)?;
+    let res = unsafe { bindings::vips_init(c_name.as_ptr()) };
+    if res == 0 {
+        if !state.started {
+            // vips_init() turns these on when the variables are set, whatever their value. Set them
+            // here as well so the recorded settings match what libvips runs with.
+            if std::env::var_os("VIPS_LEAK").is_some() {
+                unsafe { bindings::vips_leak_set(1) };
+                state.leak = true;
+            }
+            if std::env::var_os("VIPS_PROGRESS").is_some() {
+                unsafe { bindings::vips_progress_set(1) };
+                state.progress = true;
+            }
+        }
+        state.guards += 1;
+        state.started = true;
+        Ok(
+            VipsGuard {
+                _private: (),
+            },
+        )
+    } else {
+        Err(Error::InitializationError("Failed to init libvips".into()))
+    }
+}
+
+unsafe extern "C" fn count_image(
+    object: *mut c_void,
+    count: *mut c_void,
+    _b: *mut c_void,
+) -> *mut c_void {
+    if bindings::g_type_check_instance_is_a(
+        object as _,
+        bindings::vips_image_get_type(),
+    ) != 0
+    {
+        *(count as *mut usize) += 1;
+    }
+    std::ptr::null_mut()
+}
+
+fn warning(message: &str) {
+    let domain = utils::new_c_string("VIPS").unwrap();
+    let format = utils::new_c_string("%s").unwrap();
+    let message = utils::new_c_string(message).unwrap();
+    unsafe {
+        bindings::g_log(
+            domain.as_ptr(),
+            bindings::GLogLevelFlags_G_LOG_LEVEL_WARNING,
+            format.as_ptr(),
+            message.as_ptr(),
+        )
+    };
+}
+
 /// That's the main type of this crate. Use it to initialize the system
 impl Vips {
-    /// Starts up libvips
-    pub fn init(name: &str) -> Result<()> {
-        let c_name = utils::new_c_string(name)?;
-        let res = unsafe { bindings::vips_init(c_name.as_ptr()) };
-        if res == 0 {
-            Ok(())
-        } else {
-            Err(Error::InitializationError("Failed to init libvips".to_string()))
+    /// Starts up libvips. Keep the returned guard alive for as long as you use libvips.
+    /// libvips cannot be started again once it has been shut down.
+    pub fn init(name: &str) -> Result<VipsGuard> {
+        init_locked(
+            &mut lock_state(),
+            name,
+        )
+    }
+
+    /// Returns the number of `VipsImage` objects currently alive, including the ones held by the operation cache.
+    /// Call `Vips::cache_drop_all()` first to count only the images still in use.
+    pub fn live_image_count() -> usize {
+        let mut count: usize = 0;
+        unsafe {
+            bindings::vips_object_map(
+                Some(count_image),
+                &mut count as *mut usize as _,
+                std::ptr::null_mut(),
+            );
         }
+        count
     }
 
     /// Turn on or off vips leak checking.
     pub fn leak_set(leak: bool) {
+        let mut state = lock_state();
         unsafe { bindings::vips_leak_set(if leak { 1 } else { 0 }) };
+        state.leak = leak;
     }
 
     /// A structure available to eval callbacks giving information on evaluation progress
     pub fn progress_set(flag: bool) {
+        let mut state = lock_state();
         unsafe {
             bindings::vips_progress_set(if flag { 1 } else { 0 });
         }
+        state.progress = flag;
     }
 
     /// Return the number of bytes at which we flip between open via memory and open via disc
@@ -72,11 +248,19 @@ impl Vips {
             let version = CStr::from_ptr(bindings::vips_version_string());
             let version_str = version
                 .to_str()
-                .map_err(|_| Error::InitializationError("Error initializing string".to_string()))?;
+                .map_err(|_| Error::InitializationError("Error initializing string".into()))?;
             Ok(version_str.to_string())
         }
     }
 
+    /// Makes sure `vips_thread_shutdown()` runs when the current thread exits.
+    ///
+    /// Threads register themselves when they call an operation or `write_to_memory()`. Call this on threads that
+    /// only use other functions, such as the metadata getters or `VipsSource::read()`.
+    pub fn register_thread() {
+        register_thread();
+    }
+
     /// Free any thread-private data and flush any profiling information.
     pub fn thread_shutdown() {
         unsafe {
@@ -90,7 +274,7 @@ impl Vips {
             let buffer = CStr::from_ptr(bindings::vips_error_buffer());
             let buffer_str = buffer
                 .to_str()
-                .map_err(|_| Error::InitializationError("Error initializing string".to_string()))?;
+                .map_err(|_| Error::InitializationError("Error initializing string".into()))?;
             Ok(buffer_str.to_string())
         }
     }
@@ -194,6 +378,13 @@ impl Vips {
         unsafe { bindings::vips_cache_get_max_files() }
     }
 
+    /// Drop the whole operation cache, releasing the images it holds.
+    pub fn cache_drop_all() {
+        unsafe {
+            bindings::vips_cache_drop_all();
+        }
+    }
+
     /// Get the current number of operations in cache.
     pub fn cache_get_size() -> i32 {
         unsafe { bindings::vips_cache_get_size() }
@@ -247,16 +438,21 @@ impl Vips {
 
     /// If a source does not support mmap or seek and the source is used with a loader that can only work from memory, then the data will be automatically read into memory to EOF before the loader starts.
     pub fn pipe_read_limit_set(limit: i64) {
+        let mut state = lock_state();
         unsafe {
             bindings::vips_pipe_read_limit_set(limit);
         }
+        state.pipe_read_limit = limit;
     }
 
     /// Call this to drop caches, close plugins, terminate background threads, and finalize any internal library testing.
     /// vips_shutdown() is optional.
+    #[deprecated(note = "drop the guard returned by Vips::init instead")]
     pub fn shutdown() {
+        let mut state = lock_state();
         unsafe {
             bindings::vips_shutdown();
         }
+        state.shut_down = true;
     }
 }
@@ -0,0 +1,1206 @@
+// (c) Copyright 2025 mrdkprj
+use crate::{
+    bindings::{
+        self, g_object_get_property, g_object_ref, g_object_set_property, g_object_unref,
+        g_param_spec_get_blurb, g_param_spec_get_default_value, g_type_check_instance_is_a,
+        g_type_class_ref, g_type_class_unref, g_type_fundamental, g_type_name, g_type_test_flags,
+        g_value_dup_boxed, g_value_get_boolean, g_value_get_double, g_value_get_enum,
+        g_value_get_flags, g_value_get_int, g_value_get_object, g_value_get_string,
+        g_value_get_uint64, g_value_init, g_value_set_boolean, g_value_set_boxed,
+        g_value_set_double, g_value_set_enum, g_value_set_flags, g_value_set_int,
+        g_value_set_object, g_value_set_string, g_value_set_uint64, g_value_unset,
+        vips_argument_map, vips_array_double_get_type, vips_array_image_get_type,
+        vips_array_int_get_type, vips_blob_get_type, vips_cache_operation_buildp,
+        vips_enum_from_nick, vips_enum_nick, vips_flags_from_nick, vips_image_get_type,
+        vips_interpolate_get_type, vips_nickname_find, vips_object_argument_isset,
+        vips_object_get_description, vips_object_unref_outputs, vips_operation_get_flags,
+        vips_operation_new, vips_source_get_type, vips_target_get_type, vips_type_map_all,
+        vips_value_get_array_double, vips_value_get_array_image, vips_value_get_array_int,
+        vips_value_set_array_double, vips_value_set_array_image, vips_value_set_array_int,
+        GEnumClass, GParamSpec, GParamSpecDouble, GParamSpecInt, GParamSpecUInt64, GType,
+        GTypeInstance, GValue, VipsArgumentClass, VipsArgumentInstance, VipsObject,
+    },
+    error::{ArgumentError, Error},
+    interpolate::VipsInterpolate,
+    region::VipsBlob,
+    utils::{
+        get_g_type, new_c_string, G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_FLAGS, G_TYPE_INT,
+        G_TYPE_STRING, G_TYPE_UINT64,
+    },
+    Result, VipsImage, VipsSource, VipsTarget,
+};
+use std::{
+    collections::HashMap,
+    ffi::{c_char, c_int, c_void, CStr},
+    mem::MaybeUninit,
+    ptr::null_mut,
+};
+
+/// Describes a vips operation: what it does, its flags and its arguments.
+#[derive(Debug, Clone)]
+pub struct OperationInfo {
+    /// The name used to call the operation, for example `resize`.
+    pub nickname: String,
+    pub description: String,
+    pub flags: OperationFlags,
+    /// Arguments in the order libvips lists them: required ones first, by priority.
+    pub arguments: Vec<ArgumentInfo>,
+}
+
+/// Flags of a vips operation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationFlags {
+    /// The operation can work with sequential access.
+    pub sequential: bool,
+    /// The operation must not be cached.
+    pub nocache: bool,
+    /// The operation is hidden from the docs and kept only for compatibility.
+    pub deprecated: bool,
+    /// The operation is unsafe to run on untrusted input.
+    pub untrusted: bool,
+    /// The operation has been blocked and will refuse to run.
+    pub blocked: bool,
+}
+
+/// Describes one argument of a vips operation.
+#[derive(Debug, Clone)]
+pub struct ArgumentInfo {
+    pub name: String,
+    pub description: String,
+    pub direction: ArgumentDirection,
+    pub required: bool,
+    pub deprecated: bool,
+    /// Arguments are listed by priority.
+    pub priority: i32,
+    pub value_type: ArgumentType,
+    /// The GType name of the argument, for example `VipsImage` or `VipsKernel`.
+    pub type_name: String,
+    /// The default value of optional scalar arguments.
+    pub default: Option<Value>,
+    /// The smallest and largest values of numeric arguments.
+    pub range: Option<(f64, f64)>,
+    /// The allowed nicks of enum arguments.
+    pub enum_values: Vec<String>,
+}
+
+/// Whether an argument is read or written by the operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentDirection {
+    Input,
+    Output,
+}
+
+/// The type of an argument of a vips operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentType {
+    Bool,
+    Int,
+    UInt64,
+    Double,
+    String,
+    Enum,
+    Flags,
+    Image,
+    ImageArray,
+    IntArray,
+    DoubleArray,
+    Blob,
+    Source,
+    Target,
+    Interpolate,
+    /// Any other type, see `ArgumentInfo::type_name`.
+    Other,
+}
+
+/// A dynamically typed argument value.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Bool(bool),
+    Int(i32),
+    UInt64(u64),
+    Double(f64),
+    String(String),
+    /// An enum value, as its nick.
+    Enum(String),
+    Flags(u32),
+    IntArray(Vec<i32>),
+    DoubleArray(Vec<f64>),
+    Image(VipsImage),
+    ImageArray(Vec<VipsImage>),
+    Blob(VipsBlob),
+    Source(VipsSource),
+    Target(VipsTarget),
+    Interpolate(VipsInterpolate),
+}
+
+/// Calls any vips operation by name, checking argument names and types at runtime.
+///
+/// ```no_run
+/// use rs_vips::{operation::Operation, VipsImage};
+///
+/// let image = VipsImage::new_from_file("test.png").unwrap();
+/// let outputs = Operation::new("resize")
+///     .unwrap()
+///     .set("in", &image)
+///     .unwrap()
+///     .set("scale", 0.5)
+///     .unwrap()
+///     .call()
+///     .unwrap();
+/// let resized = outputs.image("out").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Operation {
+    info: OperationInfo,
+    inputs: Vec<(
+        String,
+        Value,
+    )>,
+}
+
+/// The outputs of an operation, by argument name.
+#[derive(Debug, Clone, Default)]
+pub struct Outputs {
+    values: HashMap<String, Value>,
+}
+
+impl OperationInfo {
+    /// Describes every operation available in this libvips, sorted by nickname.
+    pub fn all() -> Vec<OperationInfo> {
+        let mut types: Vec<GType> = Vec::new();
+        unsafe {
+            vips_type_map_all(
+                get_g_type("VipsOperation"),
+                Some(collect_type),
+                &mut types as *mut Vec<GType> as _,
+            );
+        }
+        let mut operations: Vec<OperationInfo> = types
+            .into_iter()
+            .filter_map(
+                |gtype| unsafe {
+                    if g_type_test_flags(
+                        gtype,
+                        bindings::GTypeFlags_G_TYPE_FLAG_ABSTRACT,
+                    ) != 0
+                    {
+                        return None;
+                    }
+                    let nickname = vips_nickname_find(gtype);
+                    if nickname.is_null() {
+                        return None;
+                    }
+                    Self::find(&CStr::from_ptr(nickname).to_string_lossy()).ok()
+                },
+            )
+            .collect();
+        operations.sort_by(|a, b| {
+            a.nickname
+                .cmp(&b.nickname)
+        });
+        operations.dedup_by(|a, b| a.nickname == b.nickname);
+        operations
+    }
+
+    /// Describes the operation with this nickname.
+    pub fn find(nickname: &str) -> Result<OperationInfo> {
+        unsafe {
+            let name = new_c_string(nickname)?;
+            let operation = vips_operation_new(name.as_ptr());
+            if operation.is_null() {
+                return Err(
+                    Error::OperationError(
+                        format!(
+                            "Unknown operation {}",
+                            nickname
+                        )
+                        .into(),
+                    )
+                    .extend(),
+                );
+            }
+
+            let description = vips_object_get_description(operation as _);
+            let flags = vips_operation_get_flags(operation);
+            let mut arguments: Vec<ArgumentInfo> = Vec::new();
+            vips_argument_map(
+                operation as _,
+                Some(collect_argument),
+                &mut arguments as *mut Vec<ArgumentInfo> as _,
+                null_mut(),
+            );
+            let info = OperationInfo {
+                nickname: nickname.to_string(),
+                description: to_string(description),
+                flags: OperationFlags {
+                    sequential: flags & bindings::VipsOperationFlags_VIPS_OPERATION_SEQUENTIAL != 0,
+                    nocache: flags & bindings::VipsOperationFlags_VIPS_OPERATION_NOCACHE != 0,
+                    deprecated: flags & bindings::VipsOperationFlags_VIPS_OPERATION_DEPRECATED != 0,
+                    untrusted: flags & bindings::VipsOperationFlags_VIPS_OPERATION_UNTRUSTED != 0,
+                    blocked: flags & bindings::VipsOperationFlags_VIPS_OPERATION_BLOCKED != 0,
+                },
+                arguments,
+            };
+            g_object_unref(operation as _);
+            Ok(info)
+        }
+    }
+
+    /// The argument with this name.
+    pub fn argument(&self, name: &str) -> Option<&ArgumentInfo> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name == name)
+    }
+
+    /// The arguments that must be set to run the operation.
+    pub fn required_inputs(&self) -> impl Iterator<Item = &ArgumentInfo> {
+        self.arguments
+            .iter()
+            .filter(|argument| argument.required && argument.direction == ArgumentDirection::Input)
+    }
+
+    /// The arguments that may be set to change the behaviour of the operation.
+    pub fn optional_inputs(&self) -> impl Iterator<Item = &ArgumentInfo> {
+        self.arguments
+            .iter()
+            .filter(|argument| !argument.required && argument.direction == ArgumentDirection::Input)
+    }
+
+    /// The arguments the operation writes.
+    pub fn outputs(&self) -> impl Iterator<Item = &ArgumentInfo> {
+        self.arguments
+            .iter()
+            .filter(|argument| argument.direction == ArgumentDirection::Output)
+    }
+}
+
+impl ArgumentType {
+    pub(crate) fn from_g_type(gtype: GType) -> Self {
+        unsafe {
+            if gtype == get_g_type(G_TYPE_BOOLEAN) {
+                ArgumentType::Bool
+            } else if gtype == get_g_type(G_TYPE_INT) {
+                ArgumentType::Int
+            } else if gtype == get_g_type(G_TYPE_UINT64) {
+                ArgumentType::UInt64
+            } else if gtype == get_g_type(G_TYPE_DOUBLE) {
+                ArgumentType::Double
+            } else if gtype == get_g_type(G_TYPE_STRING) {
+                ArgumentType::String
+            } else if gtype == vips_image_get_type() {
+                ArgumentType::Image
+            } else if gtype == vips_array_image_get_type() {
+                ArgumentType::ImageArray
+            } else if gtype == vips_array_int_get_type() {
+                ArgumentType::IntArray
+            } else if gtype == vips_array_double_get_type() {
+                ArgumentType::DoubleArray
+            } else if gtype == vips_blob_get_type() {
+                ArgumentType::Blob
+            } else if gtype == vips_source_get_type() {
+                ArgumentType::Source
+            } else if gtype == vips_target_get_type() {
+                ArgumentType::Target
+            } else if gtype == vips_interpolate_get_type() {
+                ArgumentType::Interpolate
+            } else if g_type_fundamental(gtype) == get_g_type("GEnum") {
+                ArgumentType::Enum
+            } else if g_type_fundamental(gtype) == get_g_type(G_TYPE_FLAGS) {
+                ArgumentType::Flags
+            } else {
+                ArgumentType::Other
+            }
+        }
+    }
+}
+
+impl Operation {
+    /// Prepares a call to the operation with this nickname.
+    pub fn new(nickname: &str) -> Result<Self> {
+        Ok(Self {
+            info: OperationInfo::find(nickname)?,
+            inputs: Vec::new(),
+        })
+    }
+
+    /// Describes the operation.
+    pub fn info(&self) -> &OperationInfo {
+        &self.info
+    }
+
+    /// Sets an input argument. Fails if the operation has no such input or the value has the wrong type.
+    pub fn set<V: Into<Value>>(mut self, name: &str, value: V) -> Result<Self> {
+        let value = value.into();
+        let argument = self
+            .info
+            .argument(name)
+            .filter(|argument| argument.direction == ArgumentDirection::Input)
+            .ok_or_else(|| {
+                self.argument_error(
+                    name,
+                    "no such input argument".to_string(),
+                )
+            })?;
+        if !value.fits(argument.value_type) {
+            return Err(
+                self.argument_error(
+                    name,
+                    format!(
+                        "expected {}, got {}",
+                        argument.type_name,
+                        value.type_name()
+                    ),
+                ),
+            );
+        }
+        self.inputs
+            .retain(|(input, _)| input != name);
+        self.inputs
+            .push((
+                name.to_string(),
+                value,
+            ));
+        Ok(self)
+    }
+
+    fn argument_error(&self, name: &str, reason: String) -> Error {
+        Error::ArgumentError(
+            ArgumentError {
+                operation: self
+                    .info
+                    .nickname
+                    .clone(),
+                argument: name.to_string(),
+                reason,
+                valid_arguments: self
+                    .info
+                    .arguments
+                    .iter()
+                    .filter(|argument| argument.direction == ArgumentDirection::Input)
+                    .map(
+                        |argument| {
+                            argument
+                                .name
+                                .clone()
+                        },
+                    )
+                    .collect(),
+            },
+        )
+    }
+
+    /// Builds the operation through the operation cache and returns its outputs.
+    pub fn call(self) -> Result<Outputs> {
+        let missing: Vec<&str> = self
+            .info
+            .required_inputs()
+            .filter(
+                |argument| {
+                    !self
+                        .inputs
+                        .iter()
+                        .any(|(name, _)| *name == argument.name)
+                },
+            )
+            .map(
+                |argument| {
+                    argument
+                        .name
+                        .as_str()
+                },
+            )
+            .collect();
+        if !missing.is_empty() {
+            return Err(
+                Error::OperationError(
+                    format!(
+                        "{} is missing required arguments: {}",
+                        self.info
+                            .nickname,
+                        missing.join(", ")
+                    )
+                    .into(),
+                ),
+            );
+        }
+
+        crate::register_thread();
+        unsafe {
+            let nickname = new_c_string(
+                self.info
+                    .nickname
+                    .as_str(),
+            )?;
+            let mut operation = vips_operation_new(nickname.as_ptr());
+            if operation.is_null() {
+                return Err(
+                    Error::OperationError(
+                        format!(
+                            "Unknown operation {}",
+                            self.info
+                                .nickname
+                        )
+                        .into(),
+                    )
+                    .extend(),
+                );
+            }
+
+            for (name, value) in &self.inputs {
+                let argument = self
+                    .info
+                    .argument(name)
+                    .unwrap();
+                if let Err(e) = set_argument(
+                    operation,
+                    argument,
+                    value,
+                ) {
+                    g_object_unref(operation as _);
+                    return Err(e);
+                }
+            }
+
+            if vips_cache_operation_buildp(&mut operation) < 0 {
+                vips_object_unref_outputs(operation as _);
+                g_object_unref(operation as _);
+                return Err(
+                    Error::OperationError(
+                        format!(
+                            "{} failed",
+                            self.info
+                                .nickname
+                        )
+                        .into(),
+                    )
+                    .extend(),
+                );
+            }
+
+            let mut outputs = Outputs::default();
+            for argument in self
+                .info
+                .outputs()
+            {
+                let name = new_c_string(
+                    argument
+                        .name
+                        .as_str(),
+                )?;
+                if vips_object_argument_isset(
+                    operation as _,
+                    name.as_ptr(),
+                ) == 0
+                {
+                    continue;
+                }
+                let mut gvalue = MaybeUninit::<GValue>::zeroed();
+                let gvalue_ptr = gvalue.as_mut_ptr();
+                g_value_init(
+                    gvalue_ptr,
+                    get_g_type(&argument.type_name),
+                );
+                g_object_get_property(
+                    operation as _,
+                    name.as_ptr(),
+                    gvalue_ptr,
+                );
+                if let Some(value) = Value::from_gvalue(gvalue_ptr) {
+                    outputs
+                        .values
+                        .insert(
+                            argument
+                                .name
+                                .clone(),
+                            value,
+                        );
+                }
+                g_value_unset(gvalue_ptr);
+            }
+
+            // the outputs now hold their own references
+            vips_object_unref_outputs(operation as _);
+            g_object_unref(operation as _);
+            Ok(outputs)
+        }
+    }
+}
+
+impl Outputs {
+    /// The output with this name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values
+            .get(name)
+    }
+
+    /// Takes the output with this name out of the map.
+    pub fn remove(&mut self, name: &str) -> Option<Value> {
+        self.values
+            .remove(name)
+    }
+
+    /// The image output with this name.
+    pub fn image(&self, name: &str) -> Option<VipsImage> {
+        match self.get(name) {
+            Some(Value::Image(image)) => Some(image.clone()),
+            _ => None,
+        }
+    }
+
+    /// The numeric output with this name, as a double.
+    pub fn double(&self, name: &str) -> Option<f64> {
+        match self.get(name) {
+            Some(Value::Double(value)) => Some(*value),
+            Some(Value::Int(value)) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// The int output with this name.
+    pub fn int(&self, name: &str) -> Option<i32> {
+        match self.get(name) {
+            Some(Value::Int(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The string output with this name.
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(Value::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values
+            .is_empty()
+    }
+
+    /// Iterates over the outputs in arbitrary order.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            &str,
+            &Value,
+        ),
+    > {
+        self.values
+            .iter()
+            .map(
+                |(name, value)| {
+                    (
+                        name.as_str(),
+                        value,
+                    )
+                },
+            )
+    }
+}
+
+impl IntoIterator for Outputs {
+    type Item = (
+        String,
+        Value,
+    );
+    type IntoIter = std::collections::hash_map::IntoIter<String, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values
+            .into_iter()
+    }
+}
+
+impl Value {
+    /// Reads a GValue. Returns None for unset objects and types this crate does not wrap.
+    pub(crate) unsafe fn from_gvalue(value: *const GValue) -> Option<Value> {
+        let gtype = (*value).g_type;
+        match ArgumentType::from_g_type(gtype) {
+            ArgumentType::Bool => Some(Value::Bool(g_value_get_boolean(value) != 0)),
+            ArgumentType::Int => Some(Value::Int(g_value_get_int(value))),
+            ArgumentType::UInt64 => Some(Value::UInt64(g_value_get_uint64(value))),
+            ArgumentType::Double => Some(Value::Double(g_value_get_double(value))),
+            ArgumentType::String => {
+                let string = g_value_get_string(value);
+                if string.is_null() {
+                    None
+                } else {
+                    Some(
+                        Value::String(to_string(
+                            string,
+                        )),
+                    )
+                }
+            }
+            ArgumentType::Enum => Some(
+                Value::Enum(to_string(
+                    vips_enum_nick(
+                        gtype,
+                        g_value_get_enum(value),
+                    ),
+                )),
+            ),
+            ArgumentType::Flags => Some(Value::Flags(g_value_get_flags(value))),
+            ArgumentType::IntArray => {
+                let mut len = 0;
+                let array = vips_value_get_array_int(
+                    value,
+                    &mut len,
+                );
+                Some(Value::IntArray(slice(array, len).to_vec()))
+            }
+            ArgumentType::DoubleArray => {
+                let mut len = 0;
+                let array = vips_value_get_array_double(
+                    value,
+                    &mut len,
+                );
+                Some(Value::DoubleArray(slice(array, len).to_vec()))
+            }
+            ArgumentType::ImageArray => {
+                let mut len = 0;
+                let array = vips_value_get_array_image(
+                    value,
+                    &mut len,
+                );
+                Some(
+                    Value::ImageArray(
+                        slice(array, len)
+                            .iter()
+                            .map(|image| {
+                                VipsImage::from(
+                                    g_object_ref(*image as _) as *mut bindings::VipsImage,
+                                )
+                            })
+                            .collect(),
+                    ),
+                )
+            }
+            ArgumentType::Blob => {
+                let blob = g_value_dup_boxed(value) as *mut bindings::VipsBlob;
+                if blob.is_null() {
+                    None
+                } else {
+                    Some(
+                        Value::Blob(VipsBlob {
+                            ctx: blob,
+                        }),
+                    )
+                }
+            }
+            ArgumentType::Image => ref_object(value)
+                .map(|object| Value::Image(VipsImage::from(object as *mut bindings::VipsImage))),
+            ArgumentType::Source => ref_object(value)
+                .map(|object| Value::Source(VipsSource::from(object as *mut bindings::VipsSource))),
+            ArgumentType::Target => ref_object(value)
+                .map(|object| Value::Target(VipsTarget::from(object as *mut bindings::VipsTarget))),
+            ArgumentType::Interpolate => ref_object(value).map(|object| {
+                Value::Interpolate(
+                    VipsInterpolate {
+                        ctx: object as *mut bindings::VipsInterpolate,
+                    },
+                )
+            }),
+            ArgumentType::Other => None,
+        }
+    }
+
+    /// Whether the value can be passed to an argument of this type.
+    fn fits(&self, value_type: ArgumentType) -> bool {
+        matches!(
+            (
+                value_type,
+                self
+            ),
+            (
+                ArgumentType::Bool,
+                Value::Bool(_)
+            ) | (
+                ArgumentType::Int,
+                Value::Int(_)
+            ) | (
+                ArgumentType::UInt64,
+                Value::UInt64(_)
+            ) | (
+                ArgumentType::UInt64,
+                Value::Int(0..)
+            ) | (
+                ArgumentType::Double,
+                Value::Double(_) | Value::Int(_)
+            ) | (
+                ArgumentType::String,
+                Value::String(_)
+            ) | (
+                ArgumentType::Enum,
+                Value::Enum(_) | Value::String(_) | Value::Int(_)
+            ) | (
+                ArgumentType::Flags,
+                Value::Flags(_) | Value::String(_) | Value::Int(_)
+            ) | (
+                ArgumentType::IntArray,
+                Value::IntArray(_) | Value::Int(_)
+            ) | (
+                ArgumentType::DoubleArray,
+                Value::DoubleArray(_) | Value::Double(_) | Value::IntArray(_) | Value::Int(_)
+            ) | (
+                ArgumentType::Image,
+                Value::Image(_)
+            ) | (
+                ArgumentType::ImageArray,
+                Value::ImageArray(_) | Value::Image(_)
+            ) | (
+                ArgumentType::Blob,
+                Value::Blob(_)
+            ) | (
+                ArgumentType::Source,
+                Value::Source(_)
+            ) | (
+                ArgumentType::Target,
+                Value::Target(_)
+            ) | (
+                ArgumentType::Interpolate,
+                Value::Interpolate(_)
+            )
+        )
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Bool(_) => "bool",
+            Value::Int(_) => "int",
+            Value::UInt64(_) => "u64",
+            Value::Double(_) => "double",
+            Value::String(_) => "string",
+            Value::Enum(_) => "enum",
+            Value::Flags(_) => "flags",
+            Value::IntArray(_) => "int array",
+            Value::DoubleArray(_) => "double array",
+            Value::Image(_) => "image",
+            Value::ImageArray(_) => "image array",
+            Value::Blob(_) => "blob",
+            Value::Source(_) => "source",
+            Value::Target(_) => "target",
+            Value::Interpolate(_) => "interpolate",
+        }
+    }
+}
+
+unsafe fn ref_object(value: *const GValue) -> Option<*mut c_void> {
+    let object = g_value_get_object(value);
+    if object.is_null() {
+        None
+    } else {
+        Some(g_object_ref(object))
+    }
+}
+
+unsafe fn slice<'a, T>(array: *const T, len: c_int) -> &'a [T] {
+    if array.is_null() || len <= 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(
+            array,
+            len as usize,
+        )
+    }
+}
+
+/// Converts value to the type of argument and sets it on operation.
+unsafe fn set_argument(
+    operation: *mut bindings::VipsOperation,
+    argument: &ArgumentInfo,
+    value: &Value,
+) -> Result<()> {
+    let gtype = get_g_type(&argument.type_name);
+    let mut gvalue = MaybeUninit::<GValue>::zeroed();
+    let gvalue_ptr = gvalue.as_mut_ptr();
+    g_value_init(
+        gvalue_ptr,
+        gtype,
+    );
+
+    let nick_error = |nick: &str| {
+        Error::OperationError(
+            format!(
+                "{} is not a valid value for {}",
+                nick, argument.name
+            )
+            .into(),
+        )
+        .extend()
+    };
+
+    match (
+        argument.value_type,
+        value,
+    ) {
+        (ArgumentType::Bool, Value::Bool(v)) => g_value_set_boolean(
+            gvalue_ptr,
+            (*v).into(),
+        ),
+        (ArgumentType::Int, Value::Int(v)) => g_value_set_int(
+            gvalue_ptr,
+            *v,
+        ),
+        (ArgumentType::UInt64, Value::UInt64(v)) => g_value_set_uint64(
+            gvalue_ptr,
+            *v,
+        ),
+        (ArgumentType::UInt64, Value::Int(v)) => g_value_set_uint64(
+            gvalue_ptr,
+            *v as u64,
+        ),
+        (ArgumentType::Double, Value::Double(v)) => g_value_set_double(
+            gvalue_ptr,
+            *v,
+        ),
+        (ArgumentType::Double, Value::Int(v)) => g_value_set_double(
+            gvalue_ptr,
+            *v as f64,
+        ),
+        (ArgumentType::String, Value::String(v)) => {
+            let string = new_c_string(v.as_str())?;
+            g_value_set_string(
+                gvalue_ptr,
+                string.as_ptr(),
+            );
+        }
+        (ArgumentType::Enum, Value::Enum(nick) | Value::String(nick)) => {
+            let c_nick = new_c_string(nick.as_str())?;
+            let enum_value = vips_enum_from_nick(
+                (*(operation as *mut VipsObject)).nickname,
+                gtype,
+                c_nick.as_ptr(),
+            );
+            if enum_value < 0 {
+                g_value_unset(gvalue_ptr);
+                return Err(nick_error(nick));
+            }
+            g_value_set_enum(
+                gvalue_ptr,
+                enum_value,
+            );
+        }
+        (ArgumentType::Enum, Value::Int(v)) => g_value_set_enum(
+            gvalue_ptr,
+            *v,
+        ),
+        (ArgumentType::Flags, Value::String(nick)) => {
+            let c_nick = new_c_string(nick.as_str())?;
+            let flags_value = vips_flags_from_nick(
+                (*(operation as *mut VipsObject)).nickname,
+                gtype,
+                c_nick.as_ptr(),
+            );
+            if flags_value < 0 {
+                g_value_unset(gvalue_ptr);
+                return Err(nick_error(nick));
+            }
+            g_value_set_flags(
+                gvalue_ptr,
+                flags_value as u32,
+            );
+        }
+        (ArgumentType::Flags, Value::Flags(v)) => g_value_set_flags(
+            gvalue_ptr,
+            *v,
+        ),
+        (ArgumentType::Flags, Value::Int(v)) => g_value_set_flags(
+            gvalue_ptr,
+            *v as u32,
+        ),
+        (ArgumentType::IntArray, Value::IntArray(v)) => vips_value_set_array_int(
+            gvalue_ptr,
+            v.as_ptr(),
+            v.len() as _,
+        ),
+        (ArgumentType::IntArray, Value::Int(v)) => vips_value_set_array_int(
+            gvalue_ptr,
+            v,
+            1,
+        ),
+        (ArgumentType::DoubleArray, _) => {
+            let array: Vec<f64> = match value {
+                Value::DoubleArray(v) => v.clone(),
+                Value::Double(v) => vec![*v],
+                Value::IntArray(v) => v
+                    .iter()
+                    .map(|v| *v as f64)
+                    .collect(),
+                Value::Int(v) => vec![*v as f64],
+                _ => Vec::new(),
+            };
+            vips_value_set_array_double(
+                gvalue_ptr,
+                array.as_ptr(),
+                array.len() as _,
+            );
+        }
+        (ArgumentType::ImageArray, Value::ImageArray(_) | Value::Image(_)) => {
+            let images = match value {
+                Value::ImageArray(images) => images.as_slice(),
+                Value::Image(image) => std::slice::from_ref(image),
+                _ => &[],
+            };
+            vips_value_set_array_image(
+                gvalue_ptr,
+                images.len() as _,
+            );
+            let array = vips_value_get_array_image(
+                gvalue_ptr,
+                &mut 0,
+            );
+            let array = std::slice::from_raw_parts_mut(
+                array,
+                images.len(),
+            );
+            for (slot, image) in array
+                .iter_mut()
+                .zip(images)
+            {
+                g_object_ref(image.ctx as _);
+                *slot = image.ctx;
+            }
+        }
+        (ArgumentType::Blob, Value::Blob(v)) => g_value_set_boxed(
+            gvalue_ptr,
+            v.ctx as *const c_void,
+        ),
+        (ArgumentType::Image, Value::Image(v)) => g_value_set_object(
+            gvalue_ptr,
+            v.ctx as _,
+        ),
+        (ArgumentType::Source, Value::Source(v)) => g_value_set_object(
+            gvalue_ptr,
+            v.ctx as _,
+        ),
+        (ArgumentType::Target, Value::Target(v)) => g_value_set_object(
+            gvalue_ptr,
+            v.ctx as _,
+        ),
+        (ArgumentType::Interpolate, Value::Interpolate(v)) => g_value_set_object(
+            gvalue_ptr,
+            v.ctx as _,
+        ),
+        _ => {
+            g_value_unset(gvalue_ptr);
+            return Err(
+                Error::OperationError(
+                    format!(
+                        "{} expects {}, got {}",
+                        argument.name,
+                        argument.type_name,
+                        value.type_name()
+                    )
+                    .into(),
+                ),
+            );
+        }
+    }
+
+    let name = new_c_string(
+        argument
+            .name
+            .as_str(),
+    )?;
+    g_object_set_property(
+        operation as _,
+        name.as_ptr(),
+        gvalue_ptr,
+    );
+    g_value_unset(gvalue_ptr);
+    Ok(())
+}
+
+macro_rules! value_from {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+value_from!(
+    bool => Bool,
+    i32 => Int,
+    u64 => UInt64,
+    f64 => Double,
+    String => String,
+    Vec<i32> => IntArray,
+    Vec<f64> => DoubleArray,
+    VipsImage => Image,
+    Vec<VipsImage> => ImageArray,
+    VipsBlob => Blob,
+    VipsSource => Source,
+    VipsTarget => Target,
+    VipsInterpolate => Interpolate,
+);
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<&[i32]> for Value {
+    fn from(value: &[i32]) -> Self {
+        Value::IntArray(value.to_vec())
+    }
+}
+
+impl From<&[f64]> for Value {
+    fn from(value: &[f64]) -> Self {
+        Value::DoubleArray(value.to_vec())
+    }
+}
+
+impl From<&VipsImage> for Value {
+    fn from(value: &VipsImage) -> Self {
+        Value::Image(value.clone())
+    }
+}
+
+impl From<&[VipsImage]> for Value {
+    fn from(value: &[VipsImage]) -> Self {
+        Value::ImageArray(value.to_vec())
+    }
+}
+
+impl From<&VipsBlob> for Value {
+    fn from(value: &VipsBlob) -> Self {
+        Value::Blob(value.clone())
+    }
+}
+
+impl From<&VipsSource> for Value {
+    fn from(value: &VipsSource) -> Self {
+        Value::Source(value.clone())
+    }
+}
+
+impl From<&VipsTarget> for Value {
+    fn from(value: &VipsTarget) -> Self {
+        Value::Target(value.clone())
+    }
+}
+
+impl From<&VipsInterpolate> for Value {
+    fn from(value: &VipsInterpolate) -> Self {
+        Value::Interpolate(value.clone())
+    }
+}
+
+unsafe extern "C" fn collect_type(gtype: GType, types: *mut c_void) -> *mut c_void {
+    (*(types as *mut Vec<GType>)).push(gtype);
+    null_mut()
+}
+
+unsafe extern "C" fn collect_argument(
+    _object: *mut VipsObject,
+    pspec: *mut GParamSpec,
+    argument_class: *mut VipsArgumentClass,
+    _argument_instance: *mut VipsArgumentInstance,
+    arguments: *mut c_void,
+    _b: *mut c_void,
+) -> *mut c_void {
+    let flags = (*argument_class).flags;
+    // only constructor arguments can be set and read when calling an operation
+    if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_CONSTRUCT == 0 {
+        return null_mut();
+    }
+
+    let gtype = (*pspec).value_type;
+    let value_type = ArgumentType::from_g_type(gtype);
+    let default = g_param_spec_get_default_value(pspec);
+    (*(arguments as *mut Vec<ArgumentInfo>)).push(
+        ArgumentInfo {
+            name: to_string((*pspec).name),
+            description: to_string(g_param_spec_get_blurb(pspec)),
+            direction: if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_OUTPUT != 0 {
+                ArgumentDirection::Output
+            } else {
+                ArgumentDirection::Input
+            },
+            required: flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_REQUIRED != 0,
+            deprecated: flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_DEPRECATED != 0,
+            priority: (*argument_class).priority,
+            value_type,
+            type_name: to_string(g_type_name(gtype)),
+            default: if default.is_null() { None } else { Value::from_gvalue(default) },
+            range: param_range(pspec),
+            enum_values: if value_type == ArgumentType::Enum {
+                enum_nicks(gtype)
+            } else {
+                Vec::new()
+            },
+        },
+    );
+    null_mut()
+}
+
+unsafe fn param_range(pspec: *mut GParamSpec) -> Option<(f64, f64)> {
+    let is_a = |name: &str| {
+        g_type_check_instance_is_a(
+            pspec as *mut GTypeInstance,
+            get_g_type(name),
+        ) != 0
+    };
+    if is_a("GParamInt") {
+        let pspec = pspec as *mut GParamSpecInt;
+        Some((
+            (*pspec).minimum as f64,
+            (*pspec).maximum as f64,
+        ))
+    } else if is_a("GParamUInt64") {
+        let pspec = pspec as *mut GParamSpecUInt64;
+        Some((
+            (*pspec).minimum as f64,
+            (*pspec).maximum as f64,
+        ))
+    } else if is_a("GParamDouble") {
+        let pspec = pspec as *mut GParamSpecDouble;
+        Some((
+            (*pspec).minimum,
+            (*pspec).maximum,
+        ))
+    } else {
+        None
+    }
+}
+
+unsafe fn enum_nicks(gtype: GType) -> Vec<String> {
+    let class = g_type_class_ref(gtype) as *mut GEnumClass;
+    let values = std::slice::from_raw_parts(
+        (*class).values,
+        (*class).n_values as usize,
+    );
+    let nicks = values
+        .iter()
+        .map(|value| to_string(value.value_nick))
+        // libvips enums end with a "last" member which is not a valid value
+        .filter(|nick| nick != "last")
+        .collect();
+    g_type_class_unref(class as _);
+    nicks
+}
+
+fn to_string(string: *const c_char) -> String {
+    if string.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(string) }
+            .to_string_lossy()
+            .to_string()
+    }
+}
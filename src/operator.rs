@@ -1,16 +1,31 @@
 // (c) Copyright 2025 mrdkprj
 //! Operator overloads for VipsImage
 //!
-//! `+`, `-`, `*`, `/`
+//! `+`, `-`, `*`, `/`, `%`, `&`, `|`, `^`, `<<`, `>>`, unary `-` and `!`, and their assigning forms such as `+=`
 //!
-//! `lt`(<), `le`(<=), `gt`(>), `ge`(>=), and `at`([])
+//! `eq`(==), `lt`(<), `le`(<=), `gt`(>), `ge`(>=), `pow`, `atan2`, and `at`([]) for a band or a range of bands
 //!
-//! Every overload returns VipsImage as the result of Vips operation.
+//! Every overload returns VipsImage as the result of Vips operation, and panics if the operation fails,
+//! for example because the band counts of the operands do not match.
+//! The `checked_*` methods of VipsImage do the same operations and return the error instead.
+//!
+//! Arithmetic with constants is folded into the `linear` that made its left-hand side, if any, so a chain
+//! such as `image * 1.2 + 10.0` runs as a single `linear` of `image`.
 use crate::{
-    ops::{OperationBoolean, OperationRelational},
-    VipsImage,
+    bindings::{g_object_get_data, g_object_replace_data, g_object_set_data, gpointer},
+    ops::{OperationBoolean, OperationMath2, OperationRelational},
+    options::ExtractBandOptions,
+    Result, VipsImage,
+};
+use std::{
+    borrow::Cow,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Range, RangeInclusive, Rem, RemAssign, Shl, ShlAssign,
+        Shr, ShrAssign, Sub, SubAssign,
+    },
+    ptr::null_mut,
 };
-use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
 
 pub trait Index<Idx> {
     type Output: ?Sized;
@@ -37,6 +52,443 @@ fn negate(vector: &[f64]) -> Vec<f64> {
     new_vector
 }
 
+/// Recorded on the images made by `linear` here: the image and the coefficients they were made from.
+/// Scalar arithmetic on such an image is folded into one `linear` of the same input, so that a chain like
+/// `image * 1.2 + 10.0` runs a single `linear` per pixel.
+///
+/// The record stays valid as long as images only change through the `&mut self` methods of VipsImage:
+/// a shared image is copied first, and the copy has no record, while an image changed in place forgets it.
+struct LinearSource {
+    input: VipsImage,
+    a: Vec<f64>,
+    b: Vec<f64>,
+}
+
+const LINEAR_SOURCE: &[u8] = b"rs-vips-linear-source\0";
+
+unsafe extern "C" fn free_linear_source(data: gpointer) {
+    drop(Box::from_raw(data as *mut LinearSource));
+}
+
+/// The coefficients of `a * (inner_a * x + inner_b) + b`, if the vectors can be combined band by band.
+fn combine(
+    inner_a: &[f64],
+    inner_b: &[f64],
+    a: &[f64],
+    b: &[f64],
+) -> Option<(
+    Vec<f64>,
+    Vec<f64>,
+)> {
+    let vectors = [inner_a, inner_b, a, b];
+    let n = vectors
+        .iter()
+        .map(|vector| vector.len())
+        .max()?;
+    if vectors
+        .iter()
+        .any(|vector| vector.is_empty() || (vector.len() != 1 && vector.len() != n))
+    {
+        return None;
+    }
+
+    let band = |vector: &[f64], i: usize| vector[if vector.len() == 1 { 0 } else { i }];
+    Some(
+        (0..n)
+            .map(|i| {
+                (
+                    band(a, i) * band(inner_a, i),
+                    band(a, i) * band(inner_b, i) + band(b, i),
+                )
+            })
+            .unzip(),
+    )
+}
+
+impl VipsImage {
+    /// `linear`, folded into the `linear` this image was made with, if there is one.
+    fn fused_linear(&self, a: &[f64], b: &[f64]) -> Result<VipsImage> {
+        let source = self
+            .linear_source()
+            .and_then(|source| {
+                let (a, b) = combine(
+                    &source.a,
+                    &source.b,
+                    a,
+                    b,
+                )?;
+                Some(
+                    LinearSource {
+                        input: source.input,
+                        a,
+                        b,
+                    },
+                )
+            })
+            .unwrap_or_else(
+                || LinearSource {
+                    input: self.clone(),
+                    a: a.to_vec(),
+                    b: b.to_vec(),
+                },
+            );
+
+        let out = source
+            .input
+            .linear(
+                &source.a,
+                &source.b,
+            )?;
+        out.set_linear_source(source);
+        Ok(out)
+    }
+
+    fn linear_source(&self) -> Option<LinearSource> {
+        unsafe {
+            let data = g_object_get_data(
+                self.ctx as _,
+                LINEAR_SOURCE.as_ptr() as _,
+            ) as *const LinearSource;
+            if data.is_null() {
+                return None;
+            }
+            Some(
+                LinearSource {
+                    input: (*data)
+                        .input
+                        .clone(),
+                    a: (*data)
+                        .a
+                        .clone(),
+                    b: (*data)
+                        .b
+                        .clone(),
+                },
+            )
+        }
+    }
+
+    /// Drops the record of where the image came from. Only for images no other handle or thread can reach.
+    pub(crate) fn forget_linear_source(&mut self) {
+        unsafe {
+            g_object_set_data(
+                self.ctx as _,
+                LINEAR_SOURCE.as_ptr() as _,
+                null_mut(),
+            );
+        }
+    }
+
+    /// Records where the image came from. The record is never replaced, since other threads may be reading it:
+    /// libvips can hand out the same image again from its operation cache.
+    fn set_linear_source(&self, source: LinearSource) {
+        unsafe {
+            let data = Box::into_raw(Box::new(
+                source,
+            ));
+            if g_object_replace_data(
+                self.ctx as _,
+                LINEAR_SOURCE.as_ptr() as _,
+                null_mut(),
+                data as _,
+                Some(free_linear_source),
+                null_mut(),
+            ) == 0
+            {
+                drop(Box::from_raw(data));
+            }
+        }
+    }
+}
+
+/// The right-hand side of a checked operation: an image, or a constant for all bands or for each band.
+pub enum Operand<'a> {
+    Image(&'a VipsImage),
+    Constant(Cow<'a, [f64]>),
+}
+
+impl<'a> From<&'a VipsImage> for Operand<'a> {
+    fn from(value: &'a VipsImage) -> Self {
+        Operand::Image(value)
+    }
+}
+
+impl From<f64> for Operand<'_> {
+    fn from(value: f64) -> Self {
+        Operand::Constant(
+            Cow::Owned(vec![
+                value,
+            ]),
+        )
+    }
+}
+
+impl<'a> From<&'a [f64]> for Operand<'a> {
+    fn from(value: &'a [f64]) -> Self {
+        Operand::Constant(Cow::Borrowed(value))
+    }
+}
+
+impl<'a, const N: usize> From<&'a [f64; N]> for Operand<'a> {
+    fn from(value: &'a [f64; N]) -> Self {
+        Operand::Constant(Cow::Borrowed(value))
+    }
+}
+
+impl From<i32> for Operand<'_> {
+    fn from(value: i32) -> Self {
+        Operand::from(f64::from(
+            value,
+        ))
+    }
+}
+
+impl From<u8> for Operand<'_> {
+    fn from(value: u8) -> Self {
+        Operand::from(f64::from(
+            value,
+        ))
+    }
+}
+
+/// The bands `at` and `checked_at` extract: a single band, or a range of bands such as `1..3`.
+pub struct Bands {
+    first: i32,
+    n: i32,
+}
+
+impl From<i32> for Bands {
+    fn from(value: i32) -> Self {
+        Bands {
+            first: value,
+            n: 1,
+        }
+    }
+}
+
+impl From<Range<i32>> for Bands {
+    fn from(value: Range<i32>) -> Self {
+        Bands {
+            first: value.start,
+            n: value.end - value.start,
+        }
+    }
+}
+
+impl From<RangeInclusive<i32>> for Bands {
+    fn from(value: RangeInclusive<i32>) -> Self {
+        Bands {
+            first: *value.start(),
+            n: value.end() - value.start() + 1,
+        }
+    }
+}
+
+impl From<Vec<f64>> for Operand<'_> {
+    fn from(value: Vec<f64>) -> Self {
+        Operand::Constant(Cow::Owned(value))
+    }
+}
+
+impl VipsImage {
+    /// `self + b`, returning the error instead of panicking.
+    pub fn checked_add<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        match b.into() {
+            Operand::Image(b) => self.add_image(b),
+            Operand::Constant(b) => self.fused_linear(&[1.0], &b),
+        }
+    }
+
+    /// `self - b`, returning the error instead of panicking.
+    pub fn checked_sub<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        match b.into() {
+            Operand::Image(b) => self.subtract(b),
+            Operand::Constant(b) => self.fused_linear(
+                &[1.0],
+                &negate(&b),
+            ),
+        }
+    }
+
+    /// `self * b`, returning the error instead of panicking.
+    pub fn checked_mul<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        match b.into() {
+            Operand::Image(b) => self.multiply(b),
+            Operand::Constant(b) => self.fused_linear(&b, &[0.0]),
+        }
+    }
+
+    /// `self / b`, returning the error instead of panicking.
+    pub fn checked_div<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        match b.into() {
+            Operand::Image(b) => self.divide(b),
+            Operand::Constant(b) => self.fused_linear(
+                &invert(&b),
+                &[0.0],
+            ),
+        }
+    }
+
+    /// `self % b`, returning the error instead of panicking.
+    pub fn checked_rem<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        match b.into() {
+            Operand::Image(b) => self.remainder(b),
+            Operand::Constant(b) => self.remainder_const(&b),
+        }
+    }
+
+    /// `self & b`, returning the error instead of panicking.
+    pub fn checked_bitand<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_boolean(
+            b.into(),
+            OperationBoolean::And,
+        )
+    }
+
+    /// `self | b`, returning the error instead of panicking.
+    pub fn checked_bitor<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_boolean(
+            b.into(),
+            OperationBoolean::Or,
+        )
+    }
+
+    /// `self ^ b`, returning the error instead of panicking.
+    pub fn checked_bitxor<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_boolean(
+            b.into(),
+            OperationBoolean::Eor,
+        )
+    }
+
+    /// `self << b`, returning the error instead of panicking.
+    pub fn checked_shl<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_boolean(
+            b.into(),
+            OperationBoolean::Lshift,
+        )
+    }
+
+    /// `self >> b`, returning the error instead of panicking.
+    pub fn checked_shr<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_boolean(
+            b.into(),
+            OperationBoolean::Rshift,
+        )
+    }
+
+    /// `self.eq(b)`, returning the error instead of panicking.
+    pub fn checked_eq<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_relational(
+            b.into(),
+            OperationRelational::Equal,
+        )
+    }
+
+    /// `self.lt(b)`, returning the error instead of panicking.
+    pub fn checked_lt<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_relational(
+            b.into(),
+            OperationRelational::Less,
+        )
+    }
+
+    /// `self.le(b)`, returning the error instead of panicking.
+    pub fn checked_le<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_relational(
+            b.into(),
+            OperationRelational::Lesseq,
+        )
+    }
+
+    /// `self.gt(b)`, returning the error instead of panicking.
+    pub fn checked_gt<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_relational(
+            b.into(),
+            OperationRelational::More,
+        )
+    }
+
+    /// `self.ge(b)`, returning the error instead of panicking.
+    pub fn checked_ge<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_relational(
+            b.into(),
+            OperationRelational::Moreeq,
+        )
+    }
+
+    /// `self.pow(b)`, returning the error instead of panicking.
+    pub fn checked_pow<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_math2(
+            b.into(),
+            OperationMath2::Pow,
+        )
+    }
+
+    /// `self.atan2(b)`, returning the error instead of panicking.
+    pub fn checked_atan2<'a>(&self, b: impl Into<Operand<'a>>) -> Result<VipsImage> {
+        self.checked_math2(
+            b.into(),
+            OperationMath2::Atan2,
+        )
+    }
+
+    /// `-self`, returning the error instead of panicking.
+    pub fn checked_neg(&self) -> Result<VipsImage> {
+        self.fused_linear(
+            &[-1.0],
+            &[0.0],
+        )
+    }
+
+    /// `!self`, returning the error instead of panicking.
+    pub fn checked_not(&self) -> Result<VipsImage> {
+        self.boolean_const(
+            OperationBoolean::Eor,
+            &[-1.0],
+        )
+    }
+
+    /// `self.at(bands)`, returning the error instead of panicking.
+    pub fn checked_at(&self, bands: impl Into<Bands>) -> Result<VipsImage> {
+        let bands = bands.into();
+        self.extract_band_with_opts(
+            bands.first,
+            ExtractBandOptions::new().n(bands.n),
+        )
+    }
+
+    fn checked_boolean(&self, b: Operand, boolean: OperationBoolean) -> Result<VipsImage> {
+        match b {
+            Operand::Image(b) => self.boolean(b, boolean),
+            Operand::Constant(b) => self.boolean_const(
+                boolean,
+                &b,
+            ),
+        }
+    }
+
+    fn checked_math2(&self, b: Operand, math2: OperationMath2) -> Result<VipsImage> {
+        match b {
+            Operand::Image(b) => self.math2(b, math2),
+            Operand::Constant(b) => self.math2_const(math2, &b),
+        }
+    }
+
+    fn checked_relational(&self, b: Operand, relational: OperationRelational) -> Result<VipsImage> {
+        match b {
+            Operand::Image(b) => self.relational(
+                b,
+                relational,
+            ),
+            Operand::Constant(b) => self.relational_const(
+                relational,
+                &b,
+            ),
+        }
+    }
+}
+
 // index
 impl Index<i32> for VipsImage {
     type Output = VipsImage;
@@ -46,6 +498,22 @@ impl Index<i32> for VipsImage {
     }
 }
 
+impl Index<Range<i32>> for VipsImage {
+    type Output = VipsImage;
+    fn at(&self, index: Range<i32>) -> Self::Output {
+        self.checked_at(index)
+            .unwrap()
+    }
+}
+
+impl Index<RangeInclusive<i32>> for VipsImage {
+    type Output = VipsImage;
+    fn at(&self, index: RangeInclusive<i32>) -> Self::Output {
+        self.checked_at(index)
+            .unwrap()
+    }
+}
+
 // add
 impl Add for VipsImage {
     type Output = VipsImage;
@@ -58,7 +526,7 @@ impl Add for VipsImage {
 impl Add<VipsImage> for f64 {
     type Output = VipsImage;
     fn add(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             &[self],
         )
@@ -69,7 +537,7 @@ impl Add<VipsImage> for f64 {
 impl Add<f64> for VipsImage {
     type Output = VipsImage;
     fn add(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &[b],
         )
@@ -80,7 +548,7 @@ impl Add<f64> for VipsImage {
 impl Add<VipsImage> for &[f64] {
     type Output = VipsImage;
     fn add(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             self,
         )
@@ -91,7 +559,7 @@ impl Add<VipsImage> for &[f64] {
 impl Add<&[f64]> for VipsImage {
     type Output = VipsImage;
     fn add(self, b: &[f64]) -> VipsImage {
-        self.linear(&[1.0], b)
+        self.fused_linear(&[1.0], b)
             .unwrap()
     }
 }
@@ -99,7 +567,7 @@ impl Add<&[f64]> for VipsImage {
 impl<const N: usize> Add<VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn add(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             self,
         )
@@ -110,7 +578,7 @@ impl<const N: usize> Add<VipsImage> for &[f64; N] {
 impl<const N: usize> Add<&[f64; N]> for VipsImage {
     type Output = VipsImage;
     fn add(self, b: &[f64; N]) -> VipsImage {
-        self.linear(&[1.0], b)
+        self.fused_linear(&[1.0], b)
             .unwrap()
     }
 }
@@ -127,7 +595,7 @@ impl Add for &VipsImage {
 impl Add<&VipsImage> for f64 {
     type Output = VipsImage;
     fn add(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             &[self],
         )
@@ -138,7 +606,7 @@ impl Add<&VipsImage> for f64 {
 impl Add<f64> for &VipsImage {
     type Output = VipsImage;
     fn add(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &[b],
         )
@@ -149,7 +617,7 @@ impl Add<f64> for &VipsImage {
 impl Add<&VipsImage> for &[f64] {
     type Output = VipsImage;
     fn add(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             self,
         )
@@ -160,7 +628,7 @@ impl Add<&VipsImage> for &[f64] {
 impl Add<&[f64]> for &VipsImage {
     type Output = VipsImage;
     fn add(self, b: &[f64]) -> VipsImage {
-        self.linear(&[1.0], b)
+        self.fused_linear(&[1.0], b)
             .unwrap()
     }
 }
@@ -168,7 +636,7 @@ impl Add<&[f64]> for &VipsImage {
 impl<const N: usize> Add<&VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn add(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[1.0],
             self,
         )
@@ -179,7 +647,7 @@ impl<const N: usize> Add<&VipsImage> for &[f64; N] {
 impl<const N: usize> Add<&[f64; N]> for &VipsImage {
     type Output = VipsImage;
     fn add(self, b: &[f64; N]) -> VipsImage {
-        self.linear(&[1.0], b)
+        self.fused_linear(&[1.0], b)
             .unwrap()
     }
 }
@@ -196,7 +664,7 @@ impl Sub for VipsImage {
 impl Sub<VipsImage> for f64 {
     type Output = VipsImage;
     fn sub(self, b: VipsImage) -> Self::Output {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             &[self],
         )
@@ -207,9 +675,9 @@ impl Sub<VipsImage> for f64 {
 impl Sub<f64> for VipsImage {
     type Output = VipsImage;
     fn sub(self, b: f64) -> Self::Output {
-        self.linear(
+        self.fused_linear(
             &[1.0],
-            &[b],
+            &[-b],
         )
         .unwrap()
     }
@@ -218,7 +686,7 @@ impl Sub<f64> for VipsImage {
 impl Sub<VipsImage> for &[f64] {
     type Output = VipsImage;
     fn sub(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             self,
         )
@@ -229,7 +697,7 @@ impl Sub<VipsImage> for &[f64] {
 impl Sub<&[f64]> for VipsImage {
     type Output = VipsImage;
     fn sub(self, b: &[f64]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &negate(b),
         )
@@ -240,7 +708,7 @@ impl Sub<&[f64]> for VipsImage {
 impl<const N: usize> Sub<VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn sub(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             self,
         )
@@ -251,7 +719,7 @@ impl<const N: usize> Sub<VipsImage> for &[f64; N] {
 impl<const N: usize> Sub<&[f64; N]> for VipsImage {
     type Output = VipsImage;
     fn sub(self, b: &[f64; N]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &negate(b),
         )
@@ -271,7 +739,7 @@ impl Sub for &VipsImage {
 impl Sub<&VipsImage> for f64 {
     type Output = VipsImage;
     fn sub(self, b: &VipsImage) -> Self::Output {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             &[self],
         )
@@ -282,9 +750,9 @@ impl Sub<&VipsImage> for f64 {
 impl Sub<f64> for &VipsImage {
     type Output = VipsImage;
     fn sub(self, b: f64) -> Self::Output {
-        self.linear(
+        self.fused_linear(
             &[1.0],
-            &[b],
+            &[-b],
         )
         .unwrap()
     }
@@ -293,7 +761,7 @@ impl Sub<f64> for &VipsImage {
 impl Sub<&VipsImage> for &[f64] {
     type Output = VipsImage;
     fn sub(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             self,
         )
@@ -304,7 +772,7 @@ impl Sub<&VipsImage> for &[f64] {
 impl Sub<&[f64]> for &VipsImage {
     type Output = VipsImage;
     fn sub(self, b: &[f64]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &negate(b),
         )
@@ -315,7 +783,7 @@ impl Sub<&[f64]> for &VipsImage {
 impl<const N: usize> Sub<&VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn sub(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[-1.0],
             self,
         )
@@ -326,7 +794,7 @@ impl<const N: usize> Sub<&VipsImage> for &[f64; N] {
 impl<const N: usize> Sub<&[f64; N]> for &VipsImage {
     type Output = VipsImage;
     fn sub(self, b: &[f64; N]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0],
             &negate(b),
         )
@@ -346,7 +814,7 @@ impl Mul for VipsImage {
 impl Mul<VipsImage> for f64 {
     type Output = VipsImage;
     fn mul(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[self],
             &[0.0],
         )
@@ -357,7 +825,7 @@ impl Mul<VipsImage> for f64 {
 impl Mul<f64> for VipsImage {
     type Output = VipsImage;
     fn mul(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[b],
             &[0.0],
         )
@@ -368,7 +836,7 @@ impl Mul<f64> for VipsImage {
 impl Mul<VipsImage> for &[f64] {
     type Output = VipsImage;
     fn mul(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             self,
             &[0.0],
         )
@@ -379,7 +847,7 @@ impl Mul<VipsImage> for &[f64] {
 impl Mul<&[f64]> for VipsImage {
     type Output = VipsImage;
     fn mul(self, b: &[f64]) -> VipsImage {
-        self.linear(b, &[0.0])
+        self.fused_linear(b, &[0.0])
             .unwrap()
     }
 }
@@ -387,7 +855,7 @@ impl Mul<&[f64]> for VipsImage {
 impl<const N: usize> Mul<VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn mul(self, b: VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             self,
             &[0.0],
         )
@@ -398,7 +866,7 @@ impl<const N: usize> Mul<VipsImage> for &[f64; N] {
 impl<const N: usize> Mul<&[f64; N]> for VipsImage {
     type Output = VipsImage;
     fn mul(self, b: &[f64; N]) -> VipsImage {
-        self.linear(b, &[0.0])
+        self.fused_linear(b, &[0.0])
             .unwrap()
     }
 }
@@ -415,7 +883,7 @@ impl Mul for &VipsImage {
 impl Mul<&VipsImage> for f64 {
     type Output = VipsImage;
     fn mul(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             &[self],
             &[0.0],
         )
@@ -426,7 +894,7 @@ impl Mul<&VipsImage> for f64 {
 impl Mul<f64> for &VipsImage {
     type Output = VipsImage;
     fn mul(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[b],
             &[0.0],
         )
@@ -437,7 +905,7 @@ impl Mul<f64> for &VipsImage {
 impl Mul<&VipsImage> for &[f64] {
     type Output = VipsImage;
     fn mul(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             self,
             &[0.0],
         )
@@ -448,7 +916,7 @@ impl Mul<&VipsImage> for &[f64] {
 impl Mul<&[f64]> for &VipsImage {
     type Output = VipsImage;
     fn mul(self, b: &[f64]) -> VipsImage {
-        self.linear(b, &[0.0])
+        self.fused_linear(b, &[0.0])
             .unwrap()
     }
 }
@@ -456,7 +924,7 @@ impl Mul<&[f64]> for &VipsImage {
 impl<const N: usize> Mul<&VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn mul(self, b: &VipsImage) -> VipsImage {
-        b.linear(
+        b.fused_linear(
             self,
             &[0.0],
         )
@@ -467,7 +935,7 @@ impl<const N: usize> Mul<&VipsImage> for &[f64; N] {
 impl<const N: usize> Mul<&[f64; N]> for &VipsImage {
     type Output = VipsImage;
     fn mul(self, b: &[f64; N]) -> VipsImage {
-        self.linear(b, &[0.0])
+        self.fused_linear(b, &[0.0])
             .unwrap()
     }
 }
@@ -484,12 +952,12 @@ impl Div for VipsImage {
 impl Div<VipsImage> for f64 {
     type Output = VipsImage;
     fn div(self, b: VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             &[self],
             &[0.0],
         )
@@ -500,7 +968,7 @@ impl Div<VipsImage> for f64 {
 impl Div<f64> for VipsImage {
     type Output = VipsImage;
     fn div(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0 / b],
             &[0.0],
         )
@@ -511,12 +979,12 @@ impl Div<f64> for VipsImage {
 impl Div<VipsImage> for &[f64] {
     type Output = VipsImage;
     fn div(self, b: VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             self,
             &[0.0],
         )
@@ -527,7 +995,7 @@ impl Div<VipsImage> for &[f64] {
 impl Div<&[f64]> for VipsImage {
     type Output = VipsImage;
     fn div(self, b: &[f64]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &invert(b),
             &[0.0],
         )
@@ -538,12 +1006,12 @@ impl Div<&[f64]> for VipsImage {
 impl<const N: usize> Div<VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn div(self, b: VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             self,
             &[0.0],
         )
@@ -554,7 +1022,7 @@ impl<const N: usize> Div<VipsImage> for &[f64; N] {
 impl<const N: usize> Div<&[f64; N]> for VipsImage {
     type Output = VipsImage;
     fn div(self, b: &[f64; N]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &invert(b),
             &[0.0],
         )
@@ -574,12 +1042,12 @@ impl Div for &VipsImage {
 impl Div<&VipsImage> for f64 {
     type Output = VipsImage;
     fn div(self, b: &VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             &[self],
             &[0.0],
         )
@@ -590,7 +1058,7 @@ impl Div<&VipsImage> for f64 {
 impl Div<f64> for &VipsImage {
     type Output = VipsImage;
     fn div(self, b: f64) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &[1.0 / b],
             &[0.0],
         )
@@ -601,12 +1069,12 @@ impl Div<f64> for &VipsImage {
 impl Div<&VipsImage> for &[f64] {
     type Output = VipsImage;
     fn div(self, b: &VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             self,
             &[0.0],
         )
@@ -617,7 +1085,7 @@ impl Div<&VipsImage> for &[f64] {
 impl Div<&[f64]> for &VipsImage {
     type Output = VipsImage;
     fn div(self, b: &[f64]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &invert(b),
             &[0.0],
         )
@@ -628,12 +1096,12 @@ impl Div<&[f64]> for &VipsImage {
 impl<const N: usize> Div<&VipsImage> for &[f64; N] {
     type Output = VipsImage;
     fn div(self, b: &VipsImage) -> VipsImage {
-        b.boolean_const(
-            OperationBoolean::Eor,
+        b.math2_const(
+            OperationMath2::Pow,
             &[-1.0],
         )
         .unwrap()
-        .linear(
+        .fused_linear(
             self,
             &[0.0],
         )
@@ -644,7 +1112,7 @@ impl<const N: usize> Div<&VipsImage> for &[f64; N] {
 impl<const N: usize> Div<&[f64; N]> for &VipsImage {
     type Output = VipsImage;
     fn div(self, b: &[f64; N]) -> VipsImage {
-        self.linear(
+        self.fused_linear(
             &invert(b),
             &[0.0],
         )
@@ -1334,6 +1802,17 @@ impl<const N: usize> Shr<&[f64; N]> for &VipsImage {
 }
 
 // Not in ops
+pub trait Pow<T> {
+    type Output: ?Sized;
+    fn pow(self, b: T) -> Self::Output;
+}
+
+/// `f64` has an inherent `atan2`, so write `Atan2::atan2(1.0, &image)` when the first argument is a scalar.
+pub trait Atan2<T> {
+    type Output: ?Sized;
+    fn atan2(self, b: T) -> Self::Output;
+}
+
 pub trait Eq<T> {
     type Output: ?Sized;
     fn eq(self, b: T) -> Self::Output;
@@ -2060,3 +2539,591 @@ impl<const N: usize> Ge<&[f64; N]> for &VipsImage {
         .unwrap()
     }
 }
+
+// The impls below go through the checked methods.
+
+/// Implements `$trait<$rhs>` for each `$lhs` image with `$checked`.
+macro_rules! image_lhs {
+    ($trait:ident, $method:ident, $checked:ident, [$($lhs:ty),+], $rhs:tt) => {
+        $(image_lhs!(@lhs $trait, $method, $checked, $lhs, $rhs);)+
+    };
+    (@lhs $trait:ident, $method:ident, $checked:ident, $lhs:ty, [$($rhs:ty),+]) => {
+        $(
+            impl $trait<$rhs> for $lhs {
+                type Output = VipsImage;
+                fn $method(self, b: $rhs) -> VipsImage {
+                    self.$checked(b)
+                        .unwrap()
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `$trait<&[f64; N]>` for each `$lhs` image with `$checked`.
+macro_rules! image_lhs_array {
+    ($trait:ident, $method:ident, $checked:ident, [$($lhs:ty),+]) => {
+        $(
+            impl<const N: usize> $trait<&[f64; N]> for $lhs {
+                type Output = VipsImage;
+                fn $method(self, b: &[f64; N]) -> VipsImage {
+                    self.$checked(b)
+                        .unwrap()
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `$trait` between an owned image and a borrowed one, in both orders, with `$checked`.
+macro_rules! mixed_images {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait<&VipsImage> for VipsImage {
+            type Output = VipsImage;
+            fn $method(self, b: &VipsImage) -> VipsImage {
+                self.$checked(b)
+                    .unwrap()
+            }
+        }
+
+        impl $trait<VipsImage> for &VipsImage {
+            type Output = VipsImage;
+            fn $method(self, b: VipsImage) -> VipsImage {
+                self.$checked(&b)
+                    .unwrap()
+            }
+        }
+    };
+}
+
+/// Implements `$trait<$rhs>` for each integer `$scalar` by converting it to f64.
+macro_rules! scalar_lhs {
+    ($trait:ident, $method:ident, [$($scalar:ty),+], $rhs:tt) => {
+        $(scalar_lhs!(@scalar $trait, $method, $scalar, $rhs);)+
+    };
+    (@scalar $trait:ident, $method:ident, $scalar:ty, [$($rhs:ty),+]) => {
+        $(
+            impl $trait<$rhs> for $scalar {
+                type Output = VipsImage;
+                fn $method(self, b: $rhs) -> VipsImage {
+                    <f64 as $trait<$rhs>>::$method(
+                        f64::from(self),
+                        b,
+                    )
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `$trait<T>` for VipsImage wherever `VipsImage: $op<T>`.
+macro_rules! assign {
+    ($trait:ident, $method:ident, $op:ident, $op_method:ident) => {
+        impl<T> $trait<T> for VipsImage
+        where
+            VipsImage: $op<T, Output = VipsImage>,
+        {
+            fn $method(&mut self, b: T) {
+                *self = $op::$op_method(
+                    self.clone(),
+                    b,
+                );
+            }
+        }
+    };
+}
+
+// integer scalars
+image_lhs!(
+    Add,
+    add,
+    checked_add,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Sub,
+    sub,
+    checked_sub,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Mul,
+    mul,
+    checked_mul,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Div,
+    div,
+    checked_div,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Rem,
+    rem,
+    checked_rem,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    BitAnd,
+    bitand,
+    checked_bitand,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    BitOr,
+    bitor,
+    checked_bitor,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    BitXor,
+    bitxor,
+    checked_bitxor,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Shl,
+    shl,
+    checked_shl,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Shr,
+    shr,
+    checked_shr,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Eq,
+    eq,
+    checked_eq,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Lt,
+    lt,
+    checked_lt,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Le,
+    le,
+    checked_le,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Gt,
+    gt,
+    checked_gt,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+image_lhs!(
+    Ge,
+    ge,
+    checked_ge,
+    [VipsImage, &VipsImage],
+    [i32, u8]
+);
+
+scalar_lhs!(
+    Add,
+    add,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Sub,
+    sub,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Mul,
+    mul,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Div,
+    div,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    BitAnd,
+    bitand,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    BitOr,
+    bitor,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    BitXor,
+    bitxor,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Eq,
+    eq,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Lt,
+    lt,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Le,
+    le,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Gt,
+    gt,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+scalar_lhs!(
+    Ge,
+    ge,
+    [i32, u8],
+    [VipsImage, &VipsImage]
+);
+
+// owned and borrowed images together
+mixed_images!(
+    Add,
+    add,
+    checked_add
+);
+mixed_images!(
+    Sub,
+    sub,
+    checked_sub
+);
+mixed_images!(
+    Mul,
+    mul,
+    checked_mul
+);
+mixed_images!(
+    Div,
+    div,
+    checked_div
+);
+mixed_images!(
+    Rem,
+    rem,
+    checked_rem
+);
+mixed_images!(
+    BitAnd,
+    bitand,
+    checked_bitand
+);
+mixed_images!(
+    BitOr,
+    bitor,
+    checked_bitor
+);
+mixed_images!(
+    BitXor,
+    bitxor,
+    checked_bitxor
+);
+mixed_images!(
+    Shl,
+    shl,
+    checked_shl
+);
+mixed_images!(
+    Shr,
+    shr,
+    checked_shr
+);
+mixed_images!(
+    Eq,
+    eq,
+    checked_eq
+);
+mixed_images!(
+    Lt,
+    lt,
+    checked_lt
+);
+mixed_images!(
+    Le,
+    le,
+    checked_le
+);
+mixed_images!(
+    Gt,
+    gt,
+    checked_gt
+);
+mixed_images!(
+    Ge,
+    ge,
+    checked_ge
+);
+
+// rem ref
+image_lhs!(
+    Rem,
+    rem,
+    checked_rem,
+    [&VipsImage],
+    [&VipsImage, f64, &[f64]]
+);
+image_lhs_array!(
+    Rem,
+    rem,
+    checked_rem,
+    [&VipsImage]
+);
+
+// eq ref
+image_lhs!(
+    Eq,
+    eq,
+    checked_eq,
+    [&VipsImage],
+    [&VipsImage, f64, &[f64]]
+);
+image_lhs_array!(
+    Eq,
+    eq,
+    checked_eq,
+    [&VipsImage]
+);
+
+impl Eq<&VipsImage> for f64 {
+    type Output = VipsImage;
+    fn eq(self, b: &VipsImage) -> Self::Output {
+        b.checked_eq(self)
+            .unwrap()
+    }
+}
+
+impl Eq<&VipsImage> for &[f64] {
+    type Output = VipsImage;
+    fn eq(self, b: &VipsImage) -> Self::Output {
+        b.checked_eq(self)
+            .unwrap()
+    }
+}
+
+impl<const N: usize> Eq<&VipsImage> for &[f64; N] {
+    type Output = VipsImage;
+    fn eq(self, b: &VipsImage) -> Self::Output {
+        b.checked_eq(self)
+            .unwrap()
+    }
+}
+
+// pow
+image_lhs!(
+    Pow,
+    pow,
+    checked_pow,
+    [VipsImage, &VipsImage],
+    [&VipsImage, f64, &[f64], i32, u8]
+);
+image_lhs_array!(
+    Pow,
+    pow,
+    checked_pow,
+    [VipsImage, &VipsImage]
+);
+
+impl Pow<VipsImage> for VipsImage {
+    type Output = VipsImage;
+    fn pow(self, b: VipsImage) -> VipsImage {
+        self.checked_pow(&b)
+            .unwrap()
+    }
+}
+
+impl Pow<VipsImage> for f64 {
+    type Output = VipsImage;
+    fn pow(self, b: VipsImage) -> VipsImage {
+        b.math2_const(
+            OperationMath2::Wop,
+            &[self],
+        )
+        .unwrap()
+    }
+}
+
+impl Pow<&VipsImage> for f64 {
+    type Output = VipsImage;
+    fn pow(self, b: &VipsImage) -> VipsImage {
+        b.math2_const(
+            OperationMath2::Wop,
+            &[self],
+        )
+        .unwrap()
+    }
+}
+
+// atan2
+image_lhs!(
+    Atan2,
+    atan2,
+    checked_atan2,
+    [VipsImage, &VipsImage],
+    [&VipsImage, f64, &[f64], i32, u8]
+);
+image_lhs_array!(
+    Atan2,
+    atan2,
+    checked_atan2,
+    [VipsImage, &VipsImage]
+);
+
+impl Atan2<VipsImage> for VipsImage {
+    type Output = VipsImage;
+    fn atan2(self, b: VipsImage) -> VipsImage {
+        self.checked_atan2(&b)
+            .unwrap()
+    }
+}
+
+impl Atan2<VipsImage> for f64 {
+    type Output = VipsImage;
+    fn atan2(self, b: VipsImage) -> VipsImage {
+        Atan2::atan2(self, &b)
+    }
+}
+
+impl Atan2<&VipsImage> for f64 {
+    type Output = VipsImage;
+    fn atan2(self, b: &VipsImage) -> VipsImage {
+        // there is no wrong-way atan2, so make a float image of the constant the size of b
+        b.linear(
+            &[0.0],
+            &[self],
+        )
+        .and_then(|a| a.checked_atan2(b))
+        .unwrap()
+    }
+}
+
+// neg
+impl Neg for VipsImage {
+    type Output = VipsImage;
+    fn neg(self) -> VipsImage {
+        self.checked_neg()
+            .unwrap()
+    }
+}
+
+impl Neg for &VipsImage {
+    type Output = VipsImage;
+    fn neg(self) -> VipsImage {
+        self.checked_neg()
+            .unwrap()
+    }
+}
+
+// not
+impl Not for VipsImage {
+    type Output = VipsImage;
+    fn not(self) -> VipsImage {
+        self.checked_not()
+            .unwrap()
+    }
+}
+
+impl Not for &VipsImage {
+    type Output = VipsImage;
+    fn not(self) -> VipsImage {
+        self.checked_not()
+            .unwrap()
+    }
+}
+
+// assign
+assign!(
+    AddAssign,
+    add_assign,
+    Add,
+    add
+);
+assign!(
+    SubAssign,
+    sub_assign,
+    Sub,
+    sub
+);
+assign!(
+    MulAssign,
+    mul_assign,
+    Mul,
+    mul
+);
+assign!(
+    DivAssign,
+    div_assign,
+    Div,
+    div
+);
+assign!(
+    RemAssign,
+    rem_assign,
+    Rem,
+    rem
+);
+assign!(
+    BitAndAssign,
+    bitand_assign,
+    BitAnd,
+    bitand
+);
+assign!(
+    BitOrAssign,
+    bitor_assign,
+    BitOr,
+    bitor
+);
+assign!(
+    BitXorAssign,
+    bitxor_assign,
+    BitXor,
+    bitxor
+);
+assign!(
+    ShlAssign,
+    shl_assign,
+    Shl,
+    shl
+);
+assign!(
+    ShrAssign,
+    shr_assign,
+    Shr,
+    shr
+);
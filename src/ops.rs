@@ -2,7 +2,7 @@
 // (c) Copyright 2025 mrdkprj
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::upper_case_acronyms)]
-use crate::bindings::vips_blob_new;
+use crate::bindings::vips_blob_copy;
 use crate::connection::VipsSource;
 use crate::connection::VipsTarget;
 use crate::error::*;
@@ -302,18 +302,20 @@ pub enum ForeignDzLayout {
     Last = 5,
 }
 
-#[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq, PartialOrd)]
-pub enum ForeignFlags {
-    ///  `None` -> VIPS_FOREIGN_NONE = 0
-    None = 0,
-    ///  `Partial` -> VIPS_FOREIGN_PARTIAL = 1
-    Partial = 1,
-    ///  `Bigendian` -> VIPS_FOREIGN_BIGENDIAN = 2
-    Bigendian = 2,
-    ///  `Sequential` -> VIPS_FOREIGN_SEQUENTIAL = 4
-    Sequential = 4,
-    ///  `All` -> VIPS_FOREIGN_ALL = 7
-    All = 7,
+bitflags::bitflags! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct ForeignFlags: u32 {
+        ///  `None` -> VIPS_FOREIGN_NONE = 0
+        const None = 0;
+        ///  `Partial` -> VIPS_FOREIGN_PARTIAL = 1
+        const Partial = 1;
+        ///  `Bigendian` -> VIPS_FOREIGN_BIGENDIAN = 2
+        const Bigendian = 2;
+        ///  `Sequential` -> VIPS_FOREIGN_SEQUENTIAL = 4
+        const Sequential = 4;
+        ///  `All` -> VIPS_FOREIGN_ALL = 7
+        const All = 7;
+    }
 }
 
 #[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq, PartialOrd)]
@@ -346,38 +348,42 @@ pub enum ForeignHeifEncoder {
     Last = 5,
 }
 
-#[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq, PartialOrd)]
-pub enum ForeignKeep {
-    ///  `None` -> VIPS_FOREIGN_KEEP_NONE = 0
-    None = 0,
-    ///  `Exif` -> VIPS_FOREIGN_KEEP_EXIF = 1
-    Exif = 1,
-    ///  `Xmp` -> VIPS_FOREIGN_KEEP_XMP = 2
-    Xmp = 2,
-    ///  `Iptc` -> VIPS_FOREIGN_KEEP_IPTC = 4
-    Iptc = 4,
-    ///  `Icc` -> VIPS_FOREIGN_KEEP_ICC = 8
-    Icc = 8,
-    ///  `Other` -> VIPS_FOREIGN_KEEP_OTHER = 16
-    Other = 16,
-    ///  `All` -> VIPS_FOREIGN_KEEP_ALL = 31
-    All = 31,
+bitflags::bitflags! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct ForeignKeep: u32 {
+        ///  `None` -> VIPS_FOREIGN_KEEP_NONE = 0
+        const None = 0;
+        ///  `Exif` -> VIPS_FOREIGN_KEEP_EXIF = 1
+        const Exif = 1;
+        ///  `Xmp` -> VIPS_FOREIGN_KEEP_XMP = 2
+        const Xmp = 2;
+        ///  `Iptc` -> VIPS_FOREIGN_KEEP_IPTC = 4
+        const Iptc = 4;
+        ///  `Icc` -> VIPS_FOREIGN_KEEP_ICC = 8
+        const Icc = 8;
+        ///  `Other` -> VIPS_FOREIGN_KEEP_OTHER = 16
+        const Other = 16;
+        ///  `All` -> VIPS_FOREIGN_KEEP_ALL = 31
+        const All = 31;
+    }
 }
 
-#[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq, PartialOrd)]
-pub enum ForeignPngFilter {
-    ///  `None` -> VIPS_FOREIGN_PNG_FILTER_NONE = 8
-    None = 8,
-    ///  `Sub` -> VIPS_FOREIGN_PNG_FILTER_SUB = 16
-    Sub = 16,
-    ///  `Up` -> VIPS_FOREIGN_PNG_FILTER_UP = 32
-    Up = 32,
-    ///  `Avg` -> VIPS_FOREIGN_PNG_FILTER_AVG = 64
-    Avg = 64,
-    ///  `Paeth` -> VIPS_FOREIGN_PNG_FILTER_PAETH = 128
-    Paeth = 128,
-    ///  `All` -> VIPS_FOREIGN_PNG_FILTER_ALL = 248
-    All = 248,
+bitflags::bitflags! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct ForeignPngFilter: u32 {
+        ///  `None` -> VIPS_FOREIGN_PNG_FILTER_NONE = 8
+        const None = 8;
+        ///  `Sub` -> VIPS_FOREIGN_PNG_FILTER_SUB = 16
+        const Sub = 16;
+        ///  `Up` -> VIPS_FOREIGN_PNG_FILTER_UP = 32
+        const Up = 32;
+        ///  `Avg` -> VIPS_FOREIGN_PNG_FILTER_AVG = 64
+        const Avg = 64;
+        ///  `Paeth` -> VIPS_FOREIGN_PNG_FILTER_PAETH = 128
+        const Paeth = 128;
+        ///  `All` -> VIPS_FOREIGN_PNG_FILTER_ALL = 248
+        const All = 248;
+    }
 }
 
 #[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq, PartialOrd)]
@@ -812,7 +818,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Cmc2LCh (vips_CMC2LCh) failed".to_string()),
+            Error::OperationError("Cmc2LCh (vips_CMC2LCh) failed".into()),
         )
     }
 
@@ -833,7 +839,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Cmyk2Xyz (vips_CMYK2XYZ) failed".to_string()),
+            Error::OperationError("Cmyk2Xyz (vips_CMYK2XYZ) failed".into()),
         )
     }
 
@@ -854,7 +860,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Hsv2SRgb (vips_HSV2sRGB) failed".to_string()),
+            Error::OperationError("Hsv2SRgb (vips_HSV2sRGB) failed".into()),
         )
     }
 
@@ -875,7 +881,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LCh2Cmc (vips_LCh2CMC) failed".to_string()),
+            Error::OperationError("LCh2Cmc (vips_LCh2CMC) failed".into()),
         )
     }
 
@@ -896,7 +902,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LCh2Lab (vips_LCh2Lab) failed".to_string()),
+            Error::OperationError("LCh2Lab (vips_LCh2Lab) failed".into()),
         )
     }
 
@@ -917,7 +923,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Lab2LCh (vips_Lab2LCh) failed".to_string()),
+            Error::OperationError("Lab2LCh (vips_Lab2LCh) failed".into()),
         )
     }
 
@@ -938,7 +944,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Lab2LabQ (vips_Lab2LabQ) failed".to_string()),
+            Error::OperationError("Lab2LabQ (vips_Lab2LabQ) failed".into()),
         )
     }
 
@@ -959,7 +965,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Lab2LabSs (vips_Lab2LabS) failed".to_string()),
+            Error::OperationError("Lab2LabSs (vips_Lab2LabS) failed".into()),
         )
     }
 
@@ -980,7 +986,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Lab2Xyz (vips_Lab2XYZ) failed".to_string()),
+            Error::OperationError("Lab2Xyz (vips_Lab2XYZ) failed".into()),
         )
     }
 
@@ -990,7 +996,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// temp: `&[f64]` -> Color temperature
-    pub fn Lab2XYZ_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn Lab2XYZ_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "Lab2XYZ",
@@ -1005,7 +1012,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Lab2Xyz (vips_Lab2XYZ) failed".to_string()),
+            Error::OperationError("Lab2Xyz (vips_Lab2XYZ) failed".into()),
         )
     }
 
@@ -1026,7 +1033,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LabQ2Lab (vips_LabQ2Lab) failed".to_string()),
+            Error::OperationError("LabQ2Lab (vips_LabQ2Lab) failed".into()),
         )
     }
 
@@ -1047,7 +1054,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LabQ2LabSs (vips_LabQ2LabS) failed".to_string()),
+            Error::OperationError("LabQ2LabSs (vips_LabQ2LabS) failed".into()),
         )
     }
 
@@ -1068,7 +1075,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LabQ2SRgb (vips_LabQ2sRGB) failed".to_string()),
+            Error::OperationError("LabQ2SRgb (vips_LabQ2sRGB) failed".into()),
         )
     }
 
@@ -1089,7 +1096,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LabS2Lab (vips_LabS2Lab) failed".to_string()),
+            Error::OperationError("LabS2Lab (vips_LabS2Lab) failed".into()),
         )
     }
 
@@ -1110,7 +1117,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("LabS2LabQ (vips_LabS2LabQ) failed".to_string()),
+            Error::OperationError("LabS2LabQ (vips_LabS2LabQ) failed".into()),
         )
     }
 
@@ -1131,7 +1138,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz2Cmyk (vips_XYZ2CMYK) failed".to_string()),
+            Error::OperationError("Xyz2Cmyk (vips_XYZ2CMYK) failed".into()),
         )
     }
 
@@ -1152,7 +1159,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz2Lab (vips_XYZ2Lab) failed".to_string()),
+            Error::OperationError("Xyz2Lab (vips_XYZ2Lab) failed".into()),
         )
     }
 
@@ -1162,7 +1169,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// temp: `&[f64]` -> Colour temperature
-    pub fn XYZ2Lab_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn XYZ2Lab_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "XYZ2Lab",
@@ -1177,7 +1185,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz2Lab (vips_XYZ2Lab) failed".to_string()),
+            Error::OperationError("Xyz2Lab (vips_XYZ2Lab) failed".into()),
         )
     }
 
@@ -1198,7 +1206,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz2Yxy (vips_XYZ2Yxy) failed".to_string()),
+            Error::OperationError("Xyz2Yxy (vips_XYZ2Yxy) failed".into()),
         )
     }
 
@@ -1219,7 +1227,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz2ScRgb (vips_XYZ2scRGB) failed".to_string()),
+            Error::OperationError("Xyz2ScRgb (vips_XYZ2scRGB) failed".into()),
         )
     }
 
@@ -1240,7 +1248,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Yxy2Xyz (vips_Yxy2XYZ) failed".to_string()),
+            Error::OperationError("Yxy2Xyz (vips_Yxy2XYZ) failed".into()),
         )
     }
 
@@ -1261,7 +1269,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Abs (vips_abs) failed".to_string()),
+            Error::OperationError("Abs (vips_abs) failed".into()),
         )
     }
 
@@ -1291,7 +1299,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Add (vips_add) failed".to_string()),
+            Error::OperationError("Add (vips_add) failed".into()),
         )
     }
 
@@ -1312,7 +1320,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Addalpha (vips_addalpha) failed".to_string()),
+            Error::OperationError("Addalpha (vips_addalpha) failed".into()),
         )
     }
 
@@ -1339,7 +1347,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Affine (vips_affine) failed".to_string()),
+            Error::OperationError("Affine (vips_affine) failed".into()),
         )
     }
 
@@ -1367,7 +1375,12 @@ impl VipsImage {
     /// premultiplied: `bool` -> Images have premultiplied alpha
     ///
     /// extend: [`Extend`] -> How to generate the extra pixels
-    pub fn affine_with_opts(&self, matrix: &[f64], option: VOption) -> Result<VipsImage> {
+    pub fn affine_with_opts<'a>(
+        &self,
+        matrix: &[f64],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "affine",
@@ -1386,7 +1399,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Affine (vips_affine) failed".to_string()),
+            Error::OperationError("Affine (vips_affine) failed".into()),
         )
     }
 
@@ -1412,7 +1425,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Analyzeload (vips_analyzeload) failed".to_string()),
+            Error::OperationError("Analyzeload (vips_analyzeload) failed".into()),
         )
     }
 
@@ -1432,7 +1445,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn analyzeload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn analyzeload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "analyzeload",
@@ -1450,7 +1467,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Analyzeload (vips_analyzeload) failed".to_string()),
+            Error::OperationError("Analyzeload (vips_analyzeload) failed".into()),
         )
     }
 
@@ -1473,7 +1490,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Arrayjoin (vips_arrayjoin) failed".to_string()),
+            Error::OperationError("Arrayjoin (vips_arrayjoin) failed".into()),
         )
     }
 
@@ -1497,7 +1514,11 @@ impl VipsImage {
     /// hspacing: `i32` -> Horizontal spacing between images
     ///
     /// vspacing: `i32` -> Vertical spacing between images
-    pub fn arrayjoin_with_opts(inp: &[VipsImage], option: VOption) -> Result<VipsImage> {
+    pub fn arrayjoin_with_opts<'a>(
+        inp: &[VipsImage],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "arrayjoin",
@@ -1512,7 +1533,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Arrayjoin (vips_arrayjoin) failed".to_string()),
+            Error::OperationError("Arrayjoin (vips_arrayjoin) failed".into()),
         )
     }
 
@@ -1533,7 +1554,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Autorot (vips_autorot) failed".to_string()),
+            Error::OperationError("Autorot (vips_autorot) failed".into()),
         )
     }
 
@@ -1545,7 +1566,8 @@ impl VipsImage {
     /// angle: [`Angle`] -> Angle image was rotated by
     ///
     /// flip: `&mut bool` -> Whether the image was flipped or not
-    pub fn autorot_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn autorot_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "autorot",
@@ -1560,7 +1582,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Autorot (vips_autorot) failed".to_string()),
+            Error::OperationError("Autorot (vips_autorot) failed".into()),
         )
     }
 
@@ -1581,7 +1603,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Avg (vips_avg) failed".to_string()),
+            Error::OperationError("Avg (vips_avg) failed".into()),
         )
     }
 
@@ -1608,7 +1630,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandbool (vips_bandbool) failed".to_string()),
+            Error::OperationError("Bandbool (vips_bandbool) failed".into()),
         )
     }
 
@@ -1629,7 +1651,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandfold (vips_bandfold) failed".to_string()),
+            Error::OperationError("Bandfold (vips_bandfold) failed".into()),
         )
     }
 
@@ -1639,7 +1661,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// factor: `i32` -> Fold by this factor
-    pub fn bandfold_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn bandfold_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "bandfold",
@@ -1654,7 +1677,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandfold (vips_bandfold) failed".to_string()),
+            Error::OperationError("Bandfold (vips_bandfold) failed".into()),
         )
     }
 
@@ -1677,7 +1700,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandjoin (vips_bandjoin) failed".to_string()),
+            Error::OperationError("Bandjoin (vips_bandjoin) failed".into()),
         )
     }
 
@@ -1701,7 +1724,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("BandjoinConst (vips_bandjoin_const) failed".to_string()),
+            Error::OperationError("BandjoinConst (vips_bandjoin_const) failed".into()),
         )
     }
 
@@ -1722,7 +1745,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandmean (vips_bandmean) failed".to_string()),
+            Error::OperationError("Bandmean (vips_bandmean) failed".into()),
         )
     }
 
@@ -1745,7 +1768,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandrank (vips_bandrank) failed".to_string()),
+            Error::OperationError("Bandrank (vips_bandrank) failed".into()),
         )
     }
 
@@ -1757,7 +1780,11 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// index: `i32` -> Select this band element from sorted list
-    pub fn bandrank_with_opts(inp: &[VipsImage], option: VOption) -> Result<VipsImage> {
+    pub fn bandrank_with_opts<'a>(
+        inp: &[VipsImage],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "bandrank",
@@ -1772,7 +1799,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandrank (vips_bandrank) failed".to_string()),
+            Error::OperationError("Bandrank (vips_bandrank) failed".into()),
         )
     }
 
@@ -1793,7 +1820,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandunfold (vips_bandunfold) failed".to_string()),
+            Error::OperationError("Bandunfold (vips_bandunfold) failed".into()),
         )
     }
 
@@ -1803,7 +1830,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// factor: `i32` -> Unfold by this factor
-    pub fn bandunfold_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn bandunfold_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "bandunfold",
@@ -1818,7 +1846,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Bandunfold (vips_bandunfold) failed".to_string()),
+            Error::OperationError("Bandunfold (vips_bandunfold) failed".into()),
         )
     }
 
@@ -1850,7 +1878,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Black (vips_black) failed".to_string()),
+            Error::OperationError("Black (vips_black) failed".into()),
         )
     }
 
@@ -1864,7 +1892,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// bands: `i32` -> Number of bands in image
-    pub fn black_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn black_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "black",
@@ -1886,7 +1919,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Black (vips_black) failed".to_string()),
+            Error::OperationError("Black (vips_black) failed".into()),
         )
     }
 
@@ -1922,7 +1955,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Boolean (vips_boolean) failed".to_string()),
+            Error::OperationError("Boolean (vips_boolean) failed".into()),
         )
     }
 
@@ -1952,7 +1985,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("BooleanConst (vips_boolean_const) failed".to_string()),
+            Error::OperationError("BooleanConst (vips_boolean_const) failed".into()),
         )
     }
 
@@ -1973,7 +2006,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Buildlut (vips_buildlut) failed".to_string()),
+            Error::OperationError("Buildlut (vips_buildlut) failed".into()),
         )
     }
 
@@ -1994,7 +2027,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Byteswap (vips_byteswap) failed".to_string()),
+            Error::OperationError("Byteswap (vips_byteswap) failed".into()),
         )
     }
 
@@ -2015,7 +2048,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Canny (vips_canny) failed".to_string()),
+            Error::OperationError("Canny (vips_canny) failed".into()),
         )
     }
 
@@ -2027,7 +2060,8 @@ impl VipsImage {
     /// sigma: `f64` -> Sigma of Gaussian
     ///
     /// precision: [`Precision`] -> Convolve with this precision
-    pub fn canny_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn canny_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "canny",
@@ -2042,7 +2076,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Canny (vips_canny) failed".to_string()),
+            Error::OperationError("Canny (vips_canny) failed".into()),
         )
     }
 
@@ -2072,7 +2106,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Case (vips_case) failed".to_string()),
+            Error::OperationError("Case (vips_case) failed".into()),
         )
     }
 
@@ -2099,7 +2133,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Cast (vips_cast) failed".to_string()),
+            Error::OperationError("Cast (vips_cast) failed".into()),
         )
     }
 
@@ -2111,7 +2145,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// shift: `bool` -> Shift integer values up and down
-    pub fn cast_with_opts(&self, format: BandFormat, option: VOption) -> Result<VipsImage> {
+    pub fn cast_with_opts<'a>(
+        &self,
+        format: BandFormat,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "cast",
@@ -2130,7 +2169,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Cast (vips_cast) failed".to_string()),
+            Error::OperationError("Cast (vips_cast) failed".into()),
         )
     }
 
@@ -2151,7 +2190,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Clamp (vips_clamp) failed".to_string()),
+            Error::OperationError("Clamp (vips_clamp) failed".into()),
         )
     }
 
@@ -2163,7 +2202,8 @@ impl VipsImage {
     /// min: `f64` -> Minimum value
     ///
     /// max: `f64` -> Maximum value
-    pub fn clamp_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn clamp_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "clamp",
@@ -2178,7 +2218,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Clamp (vips_clamp) failed".to_string()),
+            Error::OperationError("Clamp (vips_clamp) failed".into()),
         )
     }
 
@@ -2205,7 +2245,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Colourspace (vips_colourspace) failed".to_string()),
+            Error::OperationError("Colourspace (vips_colourspace) failed".into()),
         )
     }
 
@@ -2217,11 +2257,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// source_space: [`Interpretation`] -> Source color space
-    pub fn colourspace_with_opts(
+    pub fn colourspace_with_opts<'a>(
         &self,
         space: Interpretation,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "colourspace",
@@ -2240,7 +2281,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Colourspace (vips_colourspace) failed".to_string()),
+            Error::OperationError("Colourspace (vips_colourspace) failed".into()),
         )
     }
 
@@ -2267,7 +2308,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Compass (vips_compass) failed".to_string()),
+            Error::OperationError("Compass (vips_compass) failed".into()),
         )
     }
 
@@ -2289,7 +2330,12 @@ impl VipsImage {
     /// layers: `i32` -> Use this many layers in approximation
     ///
     /// cluster: `i32` -> Cluster lines closer than this in approximation
-    pub fn compass_with_opts(&self, mask: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn compass_with_opts<'a>(
+        &self,
+        mask: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "compass",
@@ -2308,7 +2354,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Compass (vips_compass) failed".to_string()),
+            Error::OperationError("Compass (vips_compass) failed".into()),
         )
     }
 
@@ -2344,7 +2390,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Complex2 (vips_complex2) failed".to_string()),
+            Error::OperationError("Complex2 (vips_complex2) failed".into()),
         )
     }
 
@@ -2371,7 +2417,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Complex (vips_complex) failed".to_string()),
+            Error::OperationError("Complex (vips_complex) failed".into()),
         )
     }
 
@@ -2401,7 +2447,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Complexform (vips_complexform) failed".to_string()),
+            Error::OperationError("Complexform (vips_complexform) failed".into()),
         )
     }
 
@@ -2428,7 +2474,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Complexget (vips_complexget) failed".to_string()),
+            Error::OperationError("Complexget (vips_complexget) failed".into()),
         )
     }
 
@@ -2464,7 +2510,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Composite2 (vips_composite2) failed".to_string()),
+            Error::OperationError("Composite2 (vips_composite2) failed".into()),
         )
     }
 
@@ -2484,12 +2530,13 @@ impl VipsImage {
     /// compositing_space: [`Interpretation`] -> Composite images in this colour space
     ///
     /// premultiplied: `bool` -> Images have premultiplied alpha
-    pub fn composite2_with_opts(
+    pub fn composite2_with_opts<'a>(
         &self,
         overlay: &VipsImage,
         mode: BlendMode,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "composite2",
@@ -2515,7 +2562,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Composite2 (vips_composite2) failed".to_string()),
+            Error::OperationError("Composite2 (vips_composite2) failed".into()),
         )
     }
 
@@ -2544,7 +2591,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Composite (vips_composite) failed".to_string()),
+            Error::OperationError("Composite (vips_composite) failed".into()),
         )
     }
 
@@ -2564,11 +2611,12 @@ impl VipsImage {
     /// compositing_space: [`Interpretation`] -> Composite images in this colour space
     ///
     /// premultiplied: `bool` -> Images have premultiplied alpha
-    pub fn composite_with_opts(
+    pub fn composite_with_opts<'a>(
         inp: &[VipsImage],
         mode: &[i32],
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "composite",
@@ -2587,7 +2635,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Composite (vips_composite) failed".to_string()),
+            Error::OperationError("Composite (vips_composite) failed".into()),
         )
     }
 
@@ -2614,7 +2662,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Conv (vips_conv) failed".to_string()),
+            Error::OperationError("Conv (vips_conv) failed".into()),
         )
     }
 
@@ -2630,7 +2678,12 @@ impl VipsImage {
     /// layers: `i32` -> Use this many layers in approximation
     ///
     /// cluster: `i32` -> Cluster lines closer than this in approximation
-    pub fn conv_with_opts(&self, mask: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn conv_with_opts<'a>(
+        &self,
+        mask: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "conv",
@@ -2649,7 +2702,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Conv (vips_conv) failed".to_string()),
+            Error::OperationError("Conv (vips_conv) failed".into()),
         )
     }
 
@@ -2676,7 +2729,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Conva (vips_conva) failed".to_string()),
+            Error::OperationError("Conva (vips_conva) failed".into()),
         )
     }
 
@@ -2690,7 +2743,12 @@ impl VipsImage {
     /// layers: `i32` -> Use this many layers in approximation
     ///
     /// cluster: `i32` -> Cluster lines closer than this in approximation
-    pub fn conva_with_opts(&self, mask: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn conva_with_opts<'a>(
+        &self,
+        mask: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "conva",
@@ -2709,7 +2767,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Conva (vips_conva) failed".to_string()),
+            Error::OperationError("Conva (vips_conva) failed".into()),
         )
     }
 
@@ -2736,7 +2794,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convasep (vips_convasep) failed".to_string()),
+            Error::OperationError("Convasep (vips_convasep) failed".into()),
         )
     }
 
@@ -2748,7 +2806,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// layers: `i32` -> Use this many layers in approximation
-    pub fn convasep_with_opts(&self, mask: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn convasep_with_opts<'a>(
+        &self,
+        mask: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "convasep",
@@ -2767,7 +2830,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convasep (vips_convasep) failed".to_string()),
+            Error::OperationError("Convasep (vips_convasep) failed".into()),
         )
     }
 
@@ -2794,7 +2857,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convf (vips_convf) failed".to_string()),
+            Error::OperationError("Convf (vips_convf) failed".into()),
         )
     }
 
@@ -2821,7 +2884,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convi (vips_convi) failed".to_string()),
+            Error::OperationError("Convi (vips_convi) failed".into()),
         )
     }
 
@@ -2848,7 +2911,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convsep (vips_convsep) failed".to_string()),
+            Error::OperationError("Convsep (vips_convsep) failed".into()),
         )
     }
 
@@ -2864,7 +2927,12 @@ impl VipsImage {
     /// layers: `i32` -> Use this many layers in approximation
     ///
     /// cluster: `i32` -> Cluster lines closer than this in approximation
-    pub fn convsep_with_opts(&self, mask: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn convsep_with_opts<'a>(
+        &self,
+        mask: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "convsep",
@@ -2883,7 +2951,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Convsep (vips_convsep) failed".to_string()),
+            Error::OperationError("Convsep (vips_convsep) failed".into()),
         )
     }
 
@@ -2904,7 +2972,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Copy (vips_copy) failed".to_string()),
+            Error::OperationError("Copy (vips_copy) failed".into()),
         )
     }
 
@@ -2932,7 +3000,8 @@ impl VipsImage {
     /// xoffset: `i32` -> Horizontal offset of origin
     ///
     /// yoffset: `i32` -> Vertical offset of origin
-    pub fn copy_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn copy_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "copy",
@@ -2947,7 +3016,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Copy (vips_copy) failed".to_string()),
+            Error::OperationError("Copy (vips_copy) failed".into()),
         )
     }
 
@@ -2974,7 +3043,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             nolines_out,
-            Error::OperationError("Countlines (vips_countlines) failed".to_string()),
+            Error::OperationError("Countlines (vips_countlines) failed".into()),
         )
     }
 
@@ -3000,7 +3069,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Csvload (vips_csvload) failed".to_string()),
+            Error::OperationError("Csvload (vips_csvload) failed".into()),
         )
     }
 
@@ -3028,7 +3097,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn csvload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn csvload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "csvload",
@@ -3046,7 +3119,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Csvload (vips_csvload) failed".to_string()),
+            Error::OperationError("Csvload (vips_csvload) failed".into()),
         )
     }
 
@@ -3072,7 +3145,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("CsvloadSource (vips_csvload_source) failed".to_string()),
+            Error::OperationError("CsvloadSource (vips_csvload_source) failed".into()),
         )
     }
 
@@ -3100,7 +3173,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn csvload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn csvload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "csvload_source",
@@ -3118,7 +3195,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("CsvloadSource (vips_csvload_source) failed".to_string()),
+            Error::OperationError("CsvloadSource (vips_csvload_source) failed".into()),
         )
     }
 
@@ -3139,7 +3216,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Csvsave (vips_csvsave) failed".to_string()),
+            Error::OperationError("Csvsave (vips_csvsave) failed".into()),
         )
     }
 
@@ -3158,7 +3235,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn csvsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn csvsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "csvsave",
             option
@@ -3172,7 +3254,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Csvsave (vips_csvsave) failed".to_string()),
+            Error::OperationError("Csvsave (vips_csvsave) failed".into()),
         )
     }
 
@@ -3193,7 +3275,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("CsvsaveTarget (vips_csvsave_target) failed".to_string()),
+            Error::OperationError("CsvsaveTarget (vips_csvsave_target) failed".into()),
         )
     }
 
@@ -3212,7 +3294,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn csvsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn csvsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "csvsave_target",
             option
@@ -3226,7 +3313,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("CsvsaveTarget (vips_csvsave_target) failed".to_string()),
+            Error::OperationError("CsvsaveTarget (vips_csvsave_target) failed".into()),
         )
     }
 
@@ -3256,7 +3343,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("DE00 (vips_dE00) failed".to_string()),
+            Error::OperationError("DE00 (vips_dE00) failed".into()),
         )
     }
 
@@ -3286,7 +3373,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("DE76 (vips_dE76) failed".to_string()),
+            Error::OperationError("DE76 (vips_dE76) failed".into()),
         )
     }
 
@@ -3316,7 +3403,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("DEcmc (vips_dECMC) failed".to_string()),
+            Error::OperationError("DEcmc (vips_dECMC) failed".into()),
         )
     }
 
@@ -3337,7 +3424,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Deviate (vips_deviate) failed".to_string()),
+            Error::OperationError("Deviate (vips_deviate) failed".into()),
         )
     }
 
@@ -3367,7 +3454,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Divide (vips_divide) failed".to_string()),
+            Error::OperationError("Divide (vips_divide) failed".into()),
         )
     }
 
@@ -3380,13 +3467,14 @@ impl VipsImage {
     /// cy: `i32` -> Centre of draw_circle
     ///
     /// radius: `i32` -> Radius in pixels
-    pub fn draw_circle(&self, ink: &[f64], cx: i32, cy: i32, radius: i32) -> Result<()> {
+    pub fn draw_circle(&mut self, ink: &[f64], cx: i32, cy: i32, radius: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_circle",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set("cx", cx)
@@ -3400,7 +3488,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawCircle (vips_draw_circle) failed".to_string()),
+            Error::OperationError("DrawCircle (vips_draw_circle) failed".into()),
         )
     }
 
@@ -3417,20 +3505,22 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// fill: `bool` -> Draw a solid object
-    pub fn draw_circle_with_opts(
-        &self,
+    pub fn draw_circle_with_opts<'a>(
+        &mut self,
         ink: &[f64],
         cx: i32,
         cy: i32,
         radius: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<()> {
+        self.make_unique()?;
+        let option: VOption = option.into();
         let vips_op_response = call(
             "draw_circle",
             option
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set("cx", cx)
@@ -3444,7 +3534,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawCircle (vips_draw_circle) failed".to_string()),
+            Error::OperationError("DrawCircle (vips_draw_circle) failed".into()),
         )
     }
 
@@ -3455,13 +3545,14 @@ impl VipsImage {
     /// x: `i32` -> DrawFlood start point
     ///
     /// y: `i32` -> DrawFlood start point
-    pub fn draw_flood(&self, ink: &[f64], x: i32, y: i32) -> Result<()> {
+    pub fn draw_flood(&mut self, ink: &[f64], x: i32, y: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_flood",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set("x", x)
@@ -3471,7 +3562,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawFlood (vips_draw_flood) failed".to_string()),
+            Error::OperationError("DrawFlood (vips_draw_flood) failed".into()),
         )
     }
 
@@ -3496,13 +3587,21 @@ impl VipsImage {
     /// width: `&mut i32` -> Width of modified area
     ///
     /// height: `&mut i32` -> Height of modified area
-    pub fn draw_flood_with_opts(&self, ink: &[f64], x: i32, y: i32, option: VOption) -> Result<()> {
+    pub fn draw_flood_with_opts<'a>(
+        &mut self,
+        ink: &[f64],
+        x: i32,
+        y: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        self.make_unique()?;
+        let option: VOption = option.into();
         let vips_op_response = call(
             "draw_flood",
             option
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set("x", x)
@@ -3512,7 +3611,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawFlood (vips_draw_flood) failed".to_string()),
+            Error::OperationError("DrawFlood (vips_draw_flood) failed".into()),
         )
     }
 
@@ -3523,13 +3622,14 @@ impl VipsImage {
     /// x: `i32` -> Draw image here
     ///
     /// y: `i32` -> Draw image here
-    pub fn draw_image(&self, sub: &VipsImage, x: i32, y: i32) -> Result<()> {
+    pub fn draw_image(&mut self, sub: &VipsImage, x: i32, y: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_image",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("sub", sub)
                 .set("x", x)
@@ -3539,7 +3639,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawImage (vips_draw_image) failed".to_string()),
+            Error::OperationError("DrawImage (vips_draw_image) failed".into()),
         )
     }
 
@@ -3554,19 +3654,21 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// mode: [`CombineMode`] -> Combining mode
-    pub fn draw_image_with_opts(
-        &self,
+    pub fn draw_image_with_opts<'a>(
+        &mut self,
         sub: &VipsImage,
         x: i32,
         y: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<()> {
+        self.make_unique()?;
+        let option: VOption = option.into();
         let vips_op_response = call(
             "draw_image",
             option
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("sub", sub)
                 .set("x", x)
@@ -3576,7 +3678,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawImage (vips_draw_image) failed".to_string()),
+            Error::OperationError("DrawImage (vips_draw_image) failed".into()),
         )
     }
 
@@ -3591,13 +3693,14 @@ impl VipsImage {
     /// x2: `i32` -> End of draw_line
     ///
     /// y2: `i32` -> End of draw_line
-    pub fn draw_line(&self, ink: &[f64], x1: i32, y1: i32, x2: i32, y2: i32) -> Result<()> {
+    pub fn draw_line(&mut self, ink: &[f64], x1: i32, y1: i32, x2: i32, y2: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_line",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set("x1", x1)
@@ -3609,7 +3712,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawLine (vips_draw_line) failed".to_string()),
+            Error::OperationError("DrawLine (vips_draw_line) failed".into()),
         )
     }
 
@@ -3622,13 +3725,14 @@ impl VipsImage {
     /// x: `i32` -> Draw mask here
     ///
     /// y: `i32` -> Draw mask here
-    pub fn draw_mask(&self, ink: &[f64], mask: &VipsImage, x: i32, y: i32) -> Result<()> {
+    pub fn draw_mask(&mut self, ink: &[f64], mask: &VipsImage, x: i32, y: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_mask",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set(
@@ -3642,7 +3746,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawMask (vips_draw_mask) failed".to_string()),
+            Error::OperationError("DrawMask (vips_draw_mask) failed".into()),
         )
     }
 
@@ -3658,19 +3762,20 @@ impl VipsImage {
     ///
     /// height: `i32` -> Rect to fill
     pub fn draw_rect(
-        &self,
+        &mut self,
         ink: &[f64],
         left: i32,
         top: i32,
         width: i32,
         height: i32,
     ) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_rect",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set(
@@ -3691,7 +3796,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawRect (vips_draw_rect) failed".to_string()),
+            Error::OperationError("DrawRect (vips_draw_rect) failed".into()),
         )
     }
 
@@ -3710,21 +3815,23 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// fill: `bool` -> Draw a solid object
-    pub fn draw_rect_with_opts(
-        &self,
+    pub fn draw_rect_with_opts<'a>(
+        &mut self,
         ink: &[f64],
         left: i32,
         top: i32,
         width: i32,
         height: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<()> {
+        self.make_unique()?;
+        let option: VOption = option.into();
         let vips_op_response = call(
             "draw_rect",
             option
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set("ink", ink)
                 .set(
@@ -3745,7 +3852,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawRect (vips_draw_rect) failed".to_string()),
+            Error::OperationError("DrawRect (vips_draw_rect) failed".into()),
         )
     }
 
@@ -3758,13 +3865,14 @@ impl VipsImage {
     /// width: `i32` -> Rect to fill
     ///
     /// height: `i32` -> Rect to fill
-    pub fn draw_smudge(&self, left: i32, top: i32, width: i32, height: i32) -> Result<()> {
+    pub fn draw_smudge(&mut self, left: i32, top: i32, width: i32, height: i32) -> Result<()> {
+        self.make_unique()?;
         let vips_op_response = call(
             "draw_smudge",
             VOption::new()
                 .set(
                     "image",
-                    self,
+                    &*self,
                 )
                 .set(
                     "left",
@@ -3784,7 +3892,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DrawSmudge (vips_draw_smudge) failed".to_string()),
+            Error::OperationError("DrawSmudge (vips_draw_smudge) failed".into()),
         )
     }
 
@@ -3805,7 +3913,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Dzsave (vips_dzsave) failed".to_string()),
+            Error::OperationError("Dzsave (vips_dzsave) failed".into()),
         )
     }
 
@@ -3850,7 +3958,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn dzsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn dzsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "dzsave",
             option
@@ -3864,7 +3977,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Dzsave (vips_dzsave) failed".to_string()),
+            Error::OperationError("Dzsave (vips_dzsave) failed".into()),
         )
     }
 
@@ -3885,7 +3998,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("DzsaveBuffer (vips_dzsave_buffer) failed".to_string()),
+            Error::OperationError("DzsaveBuffer (vips_dzsave_buffer) failed".into()),
         )
     }
 
@@ -3929,7 +4042,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn dzsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn dzsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "dzsave_buffer",
@@ -3944,7 +4058,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("DzsaveBuffer (vips_dzsave_buffer) failed".to_string()),
+            Error::OperationError("DzsaveBuffer (vips_dzsave_buffer) failed".into()),
         )
     }
 
@@ -3965,7 +4079,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DzsaveTarget (vips_dzsave_target) failed".to_string()),
+            Error::OperationError("DzsaveTarget (vips_dzsave_target) failed".into()),
         )
     }
 
@@ -4010,7 +4124,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn dzsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn dzsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "dzsave_target",
             option
@@ -4024,7 +4143,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("DzsaveTarget (vips_dzsave_target) failed".to_string()),
+            Error::OperationError("DzsaveTarget (vips_dzsave_target) failed".into()),
         )
     }
 
@@ -4063,7 +4182,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Embed (vips_embed) failed".to_string()),
+            Error::OperationError("Embed (vips_embed) failed".into()),
         )
     }
 
@@ -4083,14 +4202,15 @@ impl VipsImage {
     /// extend: [`Extend`] -> How to generate the extra pixels
     ///
     /// background: `&[f64]` -> Color for background pixels
-    pub fn embed_with_opts(
+    pub fn embed_with_opts<'a>(
         &self,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "embed",
@@ -4115,7 +4235,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Embed (vips_embed) failed".to_string()),
+            Error::OperationError("Embed (vips_embed) failed".into()),
         )
     }
 
@@ -4160,7 +4280,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ExtractArea (vips_extract_area) failed".to_string()),
+            Error::OperationError("ExtractArea (vips_extract_area) failed".into()),
         )
     }
 
@@ -4205,7 +4325,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Crop (vips_crop) failed".to_string()),
+            Error::OperationError("Crop (vips_crop) failed".into()),
         )
     }
 
@@ -4232,7 +4352,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ExtractBand (vips_extract_band) failed".to_string()),
+            Error::OperationError("ExtractBand (vips_extract_band) failed".into()),
         )
     }
 
@@ -4244,7 +4364,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// n: `i32` -> Number of bands to extract
-    pub fn extract_band_with_opts(&self, band: i32, option: VOption) -> Result<VipsImage> {
+    pub fn extract_band_with_opts<'a>(
+        &self,
+        band: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "extract_band",
@@ -4263,7 +4388,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ExtractBand (vips_extract_band) failed".to_string()),
+            Error::OperationError("ExtractBand (vips_extract_band) failed".into()),
         )
     }
 
@@ -4295,7 +4420,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Eye (vips_eye) failed".to_string()),
+            Error::OperationError("Eye (vips_eye) failed".into()),
         )
     }
 
@@ -4311,7 +4436,12 @@ impl VipsImage {
     /// uchar: `bool` -> Output an unsigned char image
     ///
     /// factor: `f64` -> Maximum spatial frequency
-    pub fn eye_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn eye_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "eye",
@@ -4333,7 +4463,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Eye (vips_eye) failed".to_string()),
+            Error::OperationError("Eye (vips_eye) failed".into()),
         )
     }
 
@@ -4354,7 +4484,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Falsecolour (vips_falsecolour) failed".to_string()),
+            Error::OperationError("Falsecolour (vips_falsecolour) failed".into()),
         )
     }
 
@@ -4380,7 +4510,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Fastcor (vips_fastcor) failed".to_string()),
+            Error::OperationError("Fastcor (vips_fastcor) failed".into()),
         )
     }
 
@@ -4401,7 +4531,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("FillNearest (vips_fill_nearest) failed".to_string()),
+            Error::OperationError("FillNearest (vips_fill_nearest) failed".into()),
         )
     }
 
@@ -4411,7 +4541,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// distance: `&mut VipsImage` -> Distance to nearest non-zero pixel
-    pub fn fill_nearest_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn fill_nearest_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "fill_nearest",
@@ -4426,7 +4557,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("FillNearest (vips_fill_nearest) failed".to_string()),
+            Error::OperationError("FillNearest (vips_fill_nearest) failed".into()),
         )
     }
 
@@ -4479,7 +4610,7 @@ impl VipsImage {
                 width_out,
                 height_out,
             ),
-            Error::OperationError("FindTrim (vips_find_trim) failed".to_string()),
+            Error::OperationError("FindTrim (vips_find_trim) failed".into()),
         )
     }
 
@@ -4498,15 +4629,16 @@ impl VipsImage {
     /// background: `&[f64]` -> Color for background pixels
     ///
     /// line_art: `bool` -> Enable line art mode
-    pub fn find_trim_with_opts(
+    pub fn find_trim_with_opts<'a>(
         &self,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<(
         i32,
         i32,
         i32,
         i32,
     )> {
+        let option: VOption = option.into();
         let mut left_out: i32 = 1;
         let mut top_out: i32 = 0;
         let mut width_out: i32 = 1;
@@ -4541,7 +4673,7 @@ impl VipsImage {
                 width_out,
                 height_out,
             ),
-            Error::OperationError("FindTrim (vips_find_trim) failed".to_string()),
+            Error::OperationError("FindTrim (vips_find_trim) failed".into()),
         )
     }
 
@@ -4567,7 +4699,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Fitsload (vips_fitsload) failed".to_string()),
+            Error::OperationError("Fitsload (vips_fitsload) failed".into()),
         )
     }
 
@@ -4587,7 +4719,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn fitsload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn fitsload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "fitsload",
@@ -4605,7 +4741,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Fitsload (vips_fitsload) failed".to_string()),
+            Error::OperationError("Fitsload (vips_fitsload) failed".into()),
         )
     }
 
@@ -4631,7 +4767,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("FitsloadSource (vips_fitsload_source) failed".to_string()),
+            Error::OperationError("FitsloadSource (vips_fitsload_source) failed".into()),
         )
     }
 
@@ -4651,7 +4787,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn fitsload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn fitsload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "fitsload_source",
@@ -4669,7 +4809,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("FitsloadSource (vips_fitsload_source) failed".to_string()),
+            Error::OperationError("FitsloadSource (vips_fitsload_source) failed".into()),
         )
     }
 
@@ -4690,7 +4830,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Fitssave (vips_fitssave) failed".to_string()),
+            Error::OperationError("Fitssave (vips_fitssave) failed".into()),
         )
     }
 
@@ -4707,7 +4847,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn fitssave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn fitssave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "fitssave",
             option
@@ -4721,7 +4866,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Fitssave (vips_fitssave) failed".to_string()),
+            Error::OperationError("Fitssave (vips_fitssave) failed".into()),
         )
     }
 
@@ -4742,7 +4887,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Flatten (vips_flatten) failed".to_string()),
+            Error::OperationError("Flatten (vips_flatten) failed".into()),
         )
     }
 
@@ -4754,7 +4899,8 @@ impl VipsImage {
     /// background: `&[f64]` -> Background value
     ///
     /// max_alpha: `f64` -> Maximum value of alpha channel
-    pub fn flatten_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn flatten_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "flatten",
@@ -4769,7 +4915,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Flatten (vips_flatten) failed".to_string()),
+            Error::OperationError("Flatten (vips_flatten) failed".into()),
         )
     }
 
@@ -4796,7 +4942,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Flip (vips_flip) failed".to_string()),
+            Error::OperationError("Flip (vips_flip) failed".into()),
         )
     }
 
@@ -4817,7 +4963,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Float2Rad (vips_float2rad) failed".to_string()),
+            Error::OperationError("Float2Rad (vips_float2rad) failed".into()),
         )
     }
 
@@ -4855,7 +5001,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Fractsurf (vips_fractsurf) failed".to_string()),
+            Error::OperationError("Fractsurf (vips_fractsurf) failed".into()),
         )
     }
 
@@ -4882,7 +5028,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Freqmult (vips_freqmult) failed".to_string()),
+            Error::OperationError("Freqmult (vips_freqmult) failed".into()),
         )
     }
 
@@ -4903,7 +5049,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Fwfft (vips_fwfft) failed".to_string()),
+            Error::OperationError("Fwfft (vips_fwfft) failed".into()),
         )
     }
 
@@ -4924,7 +5070,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gamma (vips_gamma) failed".to_string()),
+            Error::OperationError("Gamma (vips_gamma) failed".into()),
         )
     }
 
@@ -4934,7 +5080,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// exponent: `f64` -> Gamma factor
-    pub fn gamma_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn gamma_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gamma",
@@ -4949,7 +5096,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gamma (vips_gamma) failed".to_string()),
+            Error::OperationError("Gamma (vips_gamma) failed".into()),
         )
     }
 
@@ -4976,7 +5123,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussblur (vips_gaussblur) failed".to_string()),
+            Error::OperationError("Gaussblur (vips_gaussblur) failed".into()),
         )
     }
 
@@ -4990,7 +5137,12 @@ impl VipsImage {
     /// min_ampl: `f64` -> Minimum amplitude of Gaussian
     ///
     /// precision: [`Precision`] -> Convolve with this precision
-    pub fn gaussblur_with_opts(&self, sigma: f64, option: VOption) -> Result<VipsImage> {
+    pub fn gaussblur_with_opts<'a>(
+        &self,
+        sigma: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gaussblur",
@@ -5009,7 +5161,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussblur (vips_gaussblur) failed".to_string()),
+            Error::OperationError("Gaussblur (vips_gaussblur) failed".into()),
         )
     }
 
@@ -5041,7 +5193,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussmat (vips_gaussmat) failed".to_string()),
+            Error::OperationError("Gaussmat (vips_gaussmat) failed".into()),
         )
     }
 
@@ -5057,7 +5209,12 @@ impl VipsImage {
     /// separable: `bool` -> Generate separable Gaussian
     ///
     /// precision: [`Precision`] -> Generate with this precision
-    pub fn gaussmat_with_opts(sigma: f64, min_ampl: f64, option: VOption) -> Result<VipsImage> {
+    pub fn gaussmat_with_opts<'a>(
+        sigma: f64,
+        min_ampl: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gaussmat",
@@ -5079,7 +5236,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussmat (vips_gaussmat) failed".to_string()),
+            Error::OperationError("Gaussmat (vips_gaussmat) failed".into()),
         )
     }
 
@@ -5111,7 +5268,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussnoise (vips_gaussnoise) failed".to_string()),
+            Error::OperationError("Gaussnoise (vips_gaussnoise) failed".into()),
         )
     }
 
@@ -5129,7 +5286,12 @@ impl VipsImage {
     /// mean: `f64` -> Mean of pixels in generated image
     ///
     /// seed: `i32` -> Random number seed
-    pub fn gaussnoise_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn gaussnoise_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gaussnoise",
@@ -5151,7 +5313,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gaussnoise (vips_gaussnoise) failed".to_string()),
+            Error::OperationError("Gaussnoise (vips_gaussnoise) failed".into()),
         )
     }
 
@@ -5178,7 +5340,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_array_out,
-            Error::OperationError("Getpoint (vips_getpoint) failed".to_string()),
+            Error::OperationError("Getpoint (vips_getpoint) failed".into()),
         )
     }
 
@@ -5192,7 +5354,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// unpack_complex: `bool` -> Complex pixels should be unpacked
-    pub fn getpoint_with_opts(&self, x: i32, y: i32, option: VOption) -> Result<Vec<f64>> {
+    pub fn getpoint_with_opts<'a>(
+        &self,
+        x: i32,
+        y: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<Vec<f64>> {
+        let option: VOption = option.into();
         let mut out_array_out: Vec<f64> = Vec::new();
         let vips_op_response = call(
             "getpoint",
@@ -5209,7 +5377,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_array_out,
-            Error::OperationError("Getpoint (vips_getpoint) failed".to_string()),
+            Error::OperationError("Getpoint (vips_getpoint) failed".into()),
         )
     }
 
@@ -5235,7 +5403,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gifload (vips_gifload) failed".to_string()),
+            Error::OperationError("Gifload (vips_gifload) failed".into()),
         )
     }
 
@@ -5259,7 +5427,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn gifload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn gifload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gifload",
@@ -5277,7 +5449,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gifload (vips_gifload) failed".to_string()),
+            Error::OperationError("Gifload (vips_gifload) failed".into()),
         )
     }
 
@@ -5287,8 +5459,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn gifload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -5307,11 +5478,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("GifloadBuffer (vips_gifload_buffer) failed".to_string()),
+            Error::OperationError("GifloadBuffer (vips_gifload_buffer) failed".into()),
         )
     }
 
@@ -5335,10 +5506,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn gifload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn gifload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -5357,11 +5531,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("GifloadBuffer (vips_gifload_buffer) failed".to_string()),
+            Error::OperationError("GifloadBuffer (vips_gifload_buffer) failed".into()),
         )
     }
 
@@ -5387,7 +5561,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("GifloadSource (vips_gifload_source) failed".to_string()),
+            Error::OperationError("GifloadSource (vips_gifload_source) failed".into()),
         )
     }
 
@@ -5411,7 +5585,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn gifload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn gifload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gifload_source",
@@ -5429,7 +5607,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("GifloadSource (vips_gifload_source) failed".to_string()),
+            Error::OperationError("GifloadSource (vips_gifload_source) failed".into()),
         )
     }
 
@@ -5450,7 +5628,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Gifsave (vips_gifsave) failed".to_string()),
+            Error::OperationError("Gifsave (vips_gifsave) failed".into()),
         )
     }
 
@@ -5483,7 +5661,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn gifsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn gifsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "gifsave",
             option
@@ -5497,7 +5680,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Gifsave (vips_gifsave) failed".to_string()),
+            Error::OperationError("Gifsave (vips_gifsave) failed".into()),
         )
     }
 
@@ -5518,7 +5701,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("GifsaveBuffer (vips_gifsave_buffer) failed".to_string()),
+            Error::OperationError("GifsaveBuffer (vips_gifsave_buffer) failed".into()),
         )
     }
 
@@ -5550,7 +5733,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn gifsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn gifsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "gifsave_buffer",
@@ -5565,7 +5749,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("GifsaveBuffer (vips_gifsave_buffer) failed".to_string()),
+            Error::OperationError("GifsaveBuffer (vips_gifsave_buffer) failed".into()),
         )
     }
 
@@ -5586,7 +5770,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("GifsaveTarget (vips_gifsave_target) failed".to_string()),
+            Error::OperationError("GifsaveTarget (vips_gifsave_target) failed".into()),
         )
     }
 
@@ -5619,7 +5803,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn gifsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn gifsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "gifsave_target",
             option
@@ -5633,7 +5822,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("GifsaveTarget (vips_gifsave_target) failed".to_string()),
+            Error::OperationError("GifsaveTarget (vips_gifsave_target) failed".into()),
         )
     }
 
@@ -5654,7 +5843,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Globalbalance (vips_globalbalance) failed".to_string()),
+            Error::OperationError("Globalbalance (vips_globalbalance) failed".into()),
         )
     }
 
@@ -5666,7 +5855,8 @@ impl VipsImage {
     /// gamma: `f64` -> Image gamma
     ///
     /// int_output: `bool` -> Integer output
-    pub fn globalbalance_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn globalbalance_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "globalbalance",
@@ -5681,7 +5871,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Globalbalance (vips_globalbalance) failed".to_string()),
+            Error::OperationError("Globalbalance (vips_globalbalance) failed".into()),
         )
     }
 
@@ -5725,7 +5915,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gravity (vips_gravity) failed".to_string()),
+            Error::OperationError("Gravity (vips_gravity) failed".into()),
         )
     }
 
@@ -5743,13 +5933,14 @@ impl VipsImage {
     /// extend: [`Extend`] -> How to generate the extra pixels
     ///
     /// background: `&[f64]` -> Color for background pixels
-    pub fn gravity_with_opts(
+    pub fn gravity_with_opts<'a>(
         &self,
         direction: CompassDirection,
         width: i32,
         height: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "gravity",
@@ -5776,7 +5967,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Gravity (vips_gravity) failed".to_string()),
+            Error::OperationError("Gravity (vips_gravity) failed".into()),
         )
     }
 
@@ -5808,7 +5999,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Grey (vips_grey) failed".to_string()),
+            Error::OperationError("Grey (vips_grey) failed".into()),
         )
     }
 
@@ -5822,7 +6013,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// uchar: `bool` -> Output an unsigned char image
-    pub fn grey_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn grey_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "grey",
@@ -5844,7 +6040,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Grey (vips_grey) failed".to_string()),
+            Error::OperationError("Grey (vips_grey) failed".into()),
         )
     }
 
@@ -5883,7 +6079,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Grid (vips_grid) failed".to_string()),
+            Error::OperationError("Grid (vips_grid) failed".into()),
         )
     }
 
@@ -5909,7 +6105,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Heifload (vips_heifload) failed".to_string()),
+            Error::OperationError("Heifload (vips_heifload) failed".into()),
         )
     }
 
@@ -5937,7 +6133,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn heifload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn heifload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "heifload",
@@ -5955,7 +6155,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Heifload (vips_heifload) failed".to_string()),
+            Error::OperationError("Heifload (vips_heifload) failed".into()),
         )
     }
 
@@ -5965,8 +6165,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn heifload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -5985,11 +6184,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HeifloadBuffer (vips_heifload_buffer) failed".to_string()),
+            Error::OperationError("HeifloadBuffer (vips_heifload_buffer) failed".into()),
         )
     }
 
@@ -6017,10 +6216,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn heifload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn heifload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -6039,11 +6241,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HeifloadBuffer (vips_heifload_buffer) failed".to_string()),
+            Error::OperationError("HeifloadBuffer (vips_heifload_buffer) failed".into()),
         )
     }
 
@@ -6069,7 +6271,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HeifloadSource (vips_heifload_source) failed".to_string()),
+            Error::OperationError("HeifloadSource (vips_heifload_source) failed".into()),
         )
     }
 
@@ -6097,7 +6299,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn heifload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn heifload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "heifload_source",
@@ -6115,7 +6321,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HeifloadSource (vips_heifload_source) failed".to_string()),
+            Error::OperationError("HeifloadSource (vips_heifload_source) failed".into()),
         )
     }
 
@@ -6136,7 +6342,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Heifsave (vips_heifsave) failed".to_string()),
+            Error::OperationError("Heifsave (vips_heifsave) failed".into()),
         )
     }
 
@@ -6167,7 +6373,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn heifsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn heifsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "heifsave",
             option
@@ -6181,7 +6392,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Heifsave (vips_heifsave) failed".to_string()),
+            Error::OperationError("Heifsave (vips_heifsave) failed".into()),
         )
     }
 
@@ -6202,7 +6413,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("HeifsaveBuffer (vips_heifsave_buffer) failed".to_string()),
+            Error::OperationError("HeifsaveBuffer (vips_heifsave_buffer) failed".into()),
         )
     }
 
@@ -6232,7 +6443,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn heifsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn heifsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "heifsave_buffer",
@@ -6247,7 +6459,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("HeifsaveBuffer (vips_heifsave_buffer) failed".to_string()),
+            Error::OperationError("HeifsaveBuffer (vips_heifsave_buffer) failed".into()),
         )
     }
 
@@ -6268,7 +6480,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("HeifsaveTarget (vips_heifsave_target) failed".to_string()),
+            Error::OperationError("HeifsaveTarget (vips_heifsave_target) failed".into()),
         )
     }
 
@@ -6299,7 +6511,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn heifsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn heifsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "heifsave_target",
             option
@@ -6313,7 +6530,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("HeifsaveTarget (vips_heifsave_target) failed".to_string()),
+            Error::OperationError("HeifsaveTarget (vips_heifsave_target) failed".into()),
         )
     }
 
@@ -6334,7 +6551,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistCum (vips_hist_cum) failed".to_string()),
+            Error::OperationError("HistCum (vips_hist_cum) failed".into()),
         )
     }
 
@@ -6355,7 +6572,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistEntropy (vips_hist_entropy) failed".to_string()),
+            Error::OperationError("HistEntropy (vips_hist_entropy) failed".into()),
         )
     }
 
@@ -6376,7 +6593,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistEqual (vips_hist_equal) failed".to_string()),
+            Error::OperationError("HistEqual (vips_hist_equal) failed".into()),
         )
     }
 
@@ -6386,7 +6603,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// band: `i32` -> Equalise with this band
-    pub fn hist_equal_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn hist_equal_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hist_equal",
@@ -6401,7 +6619,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistEqual (vips_hist_equal) failed".to_string()),
+            Error::OperationError("HistEqual (vips_hist_equal) failed".into()),
         )
     }
 
@@ -6422,7 +6640,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFind (vips_hist_find) failed".to_string()),
+            Error::OperationError("HistFind (vips_hist_find) failed".into()),
         )
     }
 
@@ -6432,7 +6650,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// band: `i32` -> Find histogram of band
-    pub fn hist_find_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn hist_find_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hist_find",
@@ -6447,7 +6666,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFind (vips_hist_find) failed".to_string()),
+            Error::OperationError("HistFind (vips_hist_find) failed".into()),
         )
     }
 
@@ -6474,7 +6693,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFindIndexed (vips_hist_find_indexed) failed".to_string()),
+            Error::OperationError("HistFindIndexed (vips_hist_find_indexed) failed".into()),
         )
     }
 
@@ -6486,11 +6705,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// combine: [`Combine`] -> Combine bins like this
-    pub fn hist_find_indexed_with_opts(
+    pub fn hist_find_indexed_with_opts<'a>(
         &self,
         index: &VipsImage,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hist_find_indexed",
@@ -6509,7 +6729,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFindIndexed (vips_hist_find_indexed) failed".to_string()),
+            Error::OperationError("HistFindIndexed (vips_hist_find_indexed) failed".into()),
         )
     }
 
@@ -6530,7 +6750,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFindNdim (vips_hist_find_ndim) failed".to_string()),
+            Error::OperationError("HistFindNdim (vips_hist_find_ndim) failed".into()),
         )
     }
 
@@ -6540,7 +6760,11 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// bins: `i32` -> Number of bins in each dimension
-    pub fn hist_find_ndim_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn hist_find_ndim_with_opts<'a>(
+        &self,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hist_find_ndim",
@@ -6555,7 +6779,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistFindNdim (vips_hist_find_ndim) failed".to_string()),
+            Error::OperationError("HistFindNdim (vips_hist_find_ndim) failed".into()),
         )
     }
 
@@ -6576,7 +6800,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             monotonic_out,
-            Error::OperationError("HistIsmonotonic (vips_hist_ismonotonic) failed".to_string()),
+            Error::OperationError("HistIsmonotonic (vips_hist_ismonotonic) failed".into()),
         )
     }
 
@@ -6609,7 +6833,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistLocal (vips_hist_local) failed".to_string()),
+            Error::OperationError("HistLocal (vips_hist_local) failed".into()),
         )
     }
 
@@ -6623,12 +6847,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// max_slope: `i32` -> Maximum slope (CLAHE)
-    pub fn hist_local_with_opts(
+    pub fn hist_local_with_opts<'a>(
         &self,
         width: i32,
         height: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hist_local",
@@ -6651,7 +6876,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistLocal (vips_hist_local) failed".to_string()),
+            Error::OperationError("HistLocal (vips_hist_local) failed".into()),
         )
     }
 
@@ -6677,7 +6902,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistMatch (vips_hist_match) failed".to_string()),
+            Error::OperationError("HistMatch (vips_hist_match) failed".into()),
         )
     }
 
@@ -6698,7 +6923,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistNorm (vips_hist_norm) failed".to_string()),
+            Error::OperationError("HistNorm (vips_hist_norm) failed".into()),
         )
     }
 
@@ -6719,7 +6944,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HistPlot (vips_hist_plot) failed".to_string()),
+            Error::OperationError("HistPlot (vips_hist_plot) failed".into()),
         )
     }
 
@@ -6740,7 +6965,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HoughCircle (vips_hough_circle) failed".to_string()),
+            Error::OperationError("HoughCircle (vips_hough_circle) failed".into()),
         )
     }
 
@@ -6754,7 +6979,8 @@ impl VipsImage {
     /// min_radius: `i32` -> Smallest radius to search for
     ///
     /// max_radius: `i32` -> Largest radius to search for
-    pub fn hough_circle_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn hough_circle_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hough_circle",
@@ -6769,7 +6995,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HoughCircle (vips_hough_circle) failed".to_string()),
+            Error::OperationError("HoughCircle (vips_hough_circle) failed".into()),
         )
     }
 
@@ -6790,7 +7016,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HoughLine (vips_hough_line) failed".to_string()),
+            Error::OperationError("HoughLine (vips_hough_line) failed".into()),
         )
     }
 
@@ -6802,7 +7028,8 @@ impl VipsImage {
     /// width: `i32` -> Horizontal size of parameter space
     ///
     /// height: `i32` -> Vertical size of parameter space
-    pub fn hough_line_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn hough_line_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "hough_line",
@@ -6817,7 +7044,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("HoughLine (vips_hough_line) failed".to_string()),
+            Error::OperationError("HoughLine (vips_hough_line) failed".into()),
         )
     }
 
@@ -6838,7 +7065,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccExport (vips_icc_export) failed".to_string()),
+            Error::OperationError("IccExport (vips_icc_export) failed".into()),
         )
     }
 
@@ -6856,7 +7083,8 @@ impl VipsImage {
     /// output_profile: `&str` -> Filename to load output profile from
     ///
     /// depth: `i32` -> Output device space depth in bits
-    pub fn icc_export_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn icc_export_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "icc_export",
@@ -6871,7 +7099,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccExport (vips_icc_export) failed".to_string()),
+            Error::OperationError("IccExport (vips_icc_export) failed".into()),
         )
     }
 
@@ -6892,7 +7120,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccImport (vips_icc_import) failed".to_string()),
+            Error::OperationError("IccImport (vips_icc_import) failed".into()),
         )
     }
 
@@ -6910,7 +7138,8 @@ impl VipsImage {
     /// embedded: `bool` -> Use embedded input profile, if available
     ///
     /// input_profile: `&str` -> Filename to load input profile from
-    pub fn icc_import_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn icc_import_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "icc_import",
@@ -6925,7 +7154,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccImport (vips_icc_import) failed".to_string()),
+            Error::OperationError("IccImport (vips_icc_import) failed".into()),
         )
     }
 
@@ -6952,7 +7181,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccTransform (vips_icc_transform) failed".to_string()),
+            Error::OperationError("IccTransform (vips_icc_transform) failed".into()),
         )
     }
 
@@ -6974,11 +7203,12 @@ impl VipsImage {
     /// input_profile: `&str` -> Filename to load input profile from
     ///
     /// depth: `i32` -> Output device space depth in bits
-    pub fn icc_transform_with_opts(
+    pub fn icc_transform_with_opts<'a>(
         &self,
         output_profile: &str,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "icc_transform",
@@ -6997,7 +7227,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("IccTransform (vips_icc_transform) failed".to_string()),
+            Error::OperationError("IccTransform (vips_icc_transform) failed".into()),
         )
     }
 
@@ -7016,7 +7246,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Identity (vips_identity) failed".to_string()),
+            Error::OperationError("Identity (vips_identity) failed".into()),
         )
     }
 
@@ -7030,7 +7260,8 @@ impl VipsImage {
     /// ushort: `bool` -> Create a 16-bit LUT
     ///
     /// size: `i32` -> Size of 16-bit LUT
-    pub fn identity_with_opts(option: VOption) -> Result<VipsImage> {
+    pub fn identity_with_opts<'a>(option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "identity",
@@ -7043,7 +7274,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Identity (vips_identity) failed".to_string()),
+            Error::OperationError("Identity (vips_identity) failed".into()),
         )
     }
 
@@ -7073,7 +7304,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Ifthenelse (vips_ifthenelse) failed".to_string()),
+            Error::OperationError("Ifthenelse (vips_ifthenelse) failed".into()),
         )
     }
 
@@ -7087,12 +7318,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// blend: `bool` -> Blend smoothly between then and else parts
-    pub fn ifthenelse_with_opts(
+    pub fn ifthenelse_with_opts<'a>(
         &self,
         in1: &VipsImage,
         in2: &VipsImage,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "ifthenelse",
@@ -7112,7 +7344,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Ifthenelse (vips_ifthenelse) failed".to_string()),
+            Error::OperationError("Ifthenelse (vips_ifthenelse) failed".into()),
         )
     }
 
@@ -7145,7 +7377,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Insert (vips_insert) failed".to_string()),
+            Error::OperationError("Insert (vips_insert) failed".into()),
         )
     }
 
@@ -7163,13 +7395,14 @@ impl VipsImage {
     /// expand: `bool` -> Expand output to hold all of both inputs
     ///
     /// background: `&[f64]` -> Color for new pixels
-    pub fn insert_with_opts(
+    pub fn insert_with_opts<'a>(
         &self,
         sub: &VipsImage,
         x: i32,
         y: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "insert",
@@ -7190,7 +7423,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Insert (vips_insert) failed".to_string()),
+            Error::OperationError("Insert (vips_insert) failed".into()),
         )
     }
 
@@ -7211,7 +7444,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Invert (vips_invert) failed".to_string()),
+            Error::OperationError("Invert (vips_invert) failed".into()),
         )
     }
 
@@ -7232,7 +7465,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Invertlut (vips_invertlut) failed".to_string()),
+            Error::OperationError("Invertlut (vips_invertlut) failed".into()),
         )
     }
 
@@ -7242,7 +7475,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// size: `i32` -> LUT size to generate
-    pub fn invertlut_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn invertlut_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "invertlut",
@@ -7257,7 +7491,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Invertlut (vips_invertlut) failed".to_string()),
+            Error::OperationError("Invertlut (vips_invertlut) failed".into()),
         )
     }
 
@@ -7278,7 +7512,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Invfft (vips_invfft) failed".to_string()),
+            Error::OperationError("Invfft (vips_invfft) failed".into()),
         )
     }
 
@@ -7288,7 +7522,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// real: `bool` -> Output only the real part of the transform
-    pub fn invfft_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn invfft_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "invfft",
@@ -7303,7 +7538,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Invfft (vips_invfft) failed".to_string()),
+            Error::OperationError("Invfft (vips_invfft) failed".into()),
         )
     }
 
@@ -7335,7 +7570,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Join (vips_join) failed".to_string()),
+            Error::OperationError("Join (vips_join) failed".into()),
         )
     }
 
@@ -7355,12 +7590,13 @@ impl VipsImage {
     /// background: `&[f64]` -> Colour for new pixels
     ///
     /// align: [`Align`] -> Align on the low, centre or high coordinate edge
-    pub fn join_with_opts(
+    pub fn join_with_opts<'a>(
         &self,
         in2: &VipsImage,
         direction: Direction,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "join",
@@ -7382,7 +7618,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Join (vips_join) failed".to_string()),
+            Error::OperationError("Join (vips_join) failed".into()),
         )
     }
 
@@ -7408,7 +7644,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2Kload (vips_jp2kload) failed".to_string()),
+            Error::OperationError("Jp2Kload (vips_jp2kload) failed".into()),
         )
     }
 
@@ -7432,7 +7668,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jp2kload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn jp2kload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jp2kload",
@@ -7450,7 +7690,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2Kload (vips_jp2kload) failed".to_string()),
+            Error::OperationError("Jp2Kload (vips_jp2kload) failed".into()),
         )
     }
 
@@ -7460,8 +7700,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn jp2kload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -7480,11 +7719,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2KloadBuffer (vips_jp2kload_buffer) failed".to_string()),
+            Error::OperationError("Jp2KloadBuffer (vips_jp2kload_buffer) failed".into()),
         )
     }
 
@@ -7508,10 +7747,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jp2kload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn jp2kload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -7530,11 +7772,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2KloadBuffer (vips_jp2kload_buffer) failed".to_string()),
+            Error::OperationError("Jp2KloadBuffer (vips_jp2kload_buffer) failed".into()),
         )
     }
 
@@ -7560,7 +7802,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2KloadSource (vips_jp2kload_source) failed".to_string()),
+            Error::OperationError("Jp2KloadSource (vips_jp2kload_source) failed".into()),
         )
     }
 
@@ -7584,7 +7826,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jp2kload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn jp2kload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jp2kload_source",
@@ -7602,7 +7848,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jp2KloadSource (vips_jp2kload_source) failed".to_string()),
+            Error::OperationError("Jp2KloadSource (vips_jp2kload_source) failed".into()),
         )
     }
 
@@ -7623,7 +7869,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jp2Ksave (vips_jp2ksave) failed".to_string()),
+            Error::OperationError("Jp2Ksave (vips_jp2ksave) failed".into()),
         )
     }
 
@@ -7650,7 +7896,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jp2ksave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn jp2ksave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jp2ksave",
             option
@@ -7664,7 +7915,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jp2Ksave (vips_jp2ksave) failed".to_string()),
+            Error::OperationError("Jp2Ksave (vips_jp2ksave) failed".into()),
         )
     }
 
@@ -7685,7 +7936,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("Jp2KsaveBuffer (vips_jp2ksave_buffer) failed".to_string()),
+            Error::OperationError("Jp2KsaveBuffer (vips_jp2ksave_buffer) failed".into()),
         )
     }
 
@@ -7711,7 +7962,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jp2ksave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn jp2ksave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "jp2ksave_buffer",
@@ -7726,7 +7978,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("Jp2KsaveBuffer (vips_jp2ksave_buffer) failed".to_string()),
+            Error::OperationError("Jp2KsaveBuffer (vips_jp2ksave_buffer) failed".into()),
         )
     }
 
@@ -7747,7 +7999,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jp2KsaveTarget (vips_jp2ksave_target) failed".to_string()),
+            Error::OperationError("Jp2KsaveTarget (vips_jp2ksave_target) failed".into()),
         )
     }
 
@@ -7774,7 +8026,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jp2ksave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn jp2ksave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jp2ksave_target",
             option
@@ -7788,7 +8045,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jp2KsaveTarget (vips_jp2ksave_target) failed".to_string()),
+            Error::OperationError("Jp2KsaveTarget (vips_jp2ksave_target) failed".into()),
         )
     }
 
@@ -7814,7 +8071,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jpegload (vips_jpegload) failed".to_string()),
+            Error::OperationError("Jpegload (vips_jpegload) failed".into()),
         )
     }
 
@@ -7840,7 +8097,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jpegload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn jpegload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jpegload",
@@ -7858,7 +8119,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jpegload (vips_jpegload) failed".to_string()),
+            Error::OperationError("Jpegload (vips_jpegload) failed".into()),
         )
     }
 
@@ -7868,8 +8129,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn jpegload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -7888,11 +8148,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JpegloadBuffer (vips_jpegload_buffer) failed".to_string()),
+            Error::OperationError("JpegloadBuffer (vips_jpegload_buffer) failed".into()),
         )
     }
 
@@ -7918,10 +8178,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jpegload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn jpegload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -7940,11 +8203,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JpegloadBuffer (vips_jpegload_buffer) failed".to_string()),
+            Error::OperationError("JpegloadBuffer (vips_jpegload_buffer) failed".into()),
         )
     }
 
@@ -7970,7 +8233,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JpegloadSource (vips_jpegload_source) failed".to_string()),
+            Error::OperationError("JpegloadSource (vips_jpegload_source) failed".into()),
         )
     }
 
@@ -7996,7 +8259,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jpegload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn jpegload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jpegload_source",
@@ -8014,7 +8281,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JpegloadSource (vips_jpegload_source) failed".to_string()),
+            Error::OperationError("JpegloadSource (vips_jpegload_source) failed".into()),
         )
     }
 
@@ -8035,7 +8302,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jpegsave (vips_jpegsave) failed".to_string()),
+            Error::OperationError("Jpegsave (vips_jpegsave) failed".into()),
         )
     }
 
@@ -8070,7 +8337,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jpegsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn jpegsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jpegsave",
             option
@@ -8084,7 +8356,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jpegsave (vips_jpegsave) failed".to_string()),
+            Error::OperationError("Jpegsave (vips_jpegsave) failed".into()),
         )
     }
 
@@ -8105,7 +8377,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("JpegsaveBuffer (vips_jpegsave_buffer) failed".to_string()),
+            Error::OperationError("JpegsaveBuffer (vips_jpegsave_buffer) failed".into()),
         )
     }
 
@@ -8139,7 +8411,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jpegsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn jpegsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "jpegsave_buffer",
@@ -8154,7 +8427,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("JpegsaveBuffer (vips_jpegsave_buffer) failed".to_string()),
+            Error::OperationError("JpegsaveBuffer (vips_jpegsave_buffer) failed".into()),
         )
     }
 
@@ -8168,7 +8441,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JpegsaveMime (vips_jpegsave_mime) failed".to_string()),
+            Error::OperationError("JpegsaveMime (vips_jpegsave_mime) failed".into()),
         )
     }
 
@@ -8201,7 +8474,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jpegsave_mime_with_opts(&self, option: VOption) -> Result<()> {
+    pub fn jpegsave_mime_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jpegsave_mime",
             option.set("in", self),
@@ -8210,7 +8484,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JpegsaveMime (vips_jpegsave_mime) failed".to_string()),
+            Error::OperationError("JpegsaveMime (vips_jpegsave_mime) failed".into()),
         )
     }
 
@@ -8231,7 +8505,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JpegsaveTarget (vips_jpegsave_target) failed".to_string()),
+            Error::OperationError("JpegsaveTarget (vips_jpegsave_target) failed".into()),
         )
     }
 
@@ -8266,7 +8540,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jpegsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn jpegsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jpegsave_target",
             option
@@ -8280,7 +8559,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JpegsaveTarget (vips_jpegsave_target) failed".to_string()),
+            Error::OperationError("JpegsaveTarget (vips_jpegsave_target) failed".into()),
         )
     }
 
@@ -8306,7 +8585,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jxlload (vips_jxlload) failed".to_string()),
+            Error::OperationError("Jxlload (vips_jxlload) failed".into()),
         )
     }
 
@@ -8330,7 +8609,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jxlload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn jxlload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jxlload",
@@ -8348,7 +8631,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Jxlload (vips_jxlload) failed".to_string()),
+            Error::OperationError("Jxlload (vips_jxlload) failed".into()),
         )
     }
 
@@ -8358,8 +8641,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn jxlload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -8378,11 +8660,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JxlloadBuffer (vips_jxlload_buffer) failed".to_string()),
+            Error::OperationError("JxlloadBuffer (vips_jxlload_buffer) failed".into()),
         )
     }
 
@@ -8406,10 +8688,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jxlload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn jxlload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -8428,11 +8713,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JxlloadBuffer (vips_jxlload_buffer) failed".to_string()),
+            Error::OperationError("JxlloadBuffer (vips_jxlload_buffer) failed".into()),
         )
     }
 
@@ -8458,7 +8743,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JxlloadSource (vips_jxlload_source) failed".to_string()),
+            Error::OperationError("JxlloadSource (vips_jxlload_source) failed".into()),
         )
     }
 
@@ -8482,7 +8767,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn jxlload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn jxlload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "jxlload_source",
@@ -8500,7 +8789,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("JxlloadSource (vips_jxlload_source) failed".to_string()),
+            Error::OperationError("JxlloadSource (vips_jxlload_source) failed".into()),
         )
     }
 
@@ -8521,7 +8810,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jxlsave (vips_jxlsave) failed".to_string()),
+            Error::OperationError("Jxlsave (vips_jxlsave) failed".into()),
         )
     }
 
@@ -8548,7 +8837,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jxlsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn jxlsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jxlsave",
             option
@@ -8562,7 +8856,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Jxlsave (vips_jxlsave) failed".to_string()),
+            Error::OperationError("Jxlsave (vips_jxlsave) failed".into()),
         )
     }
 
@@ -8583,7 +8877,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("JxlsaveBuffer (vips_jxlsave_buffer) failed".to_string()),
+            Error::OperationError("JxlsaveBuffer (vips_jxlsave_buffer) failed".into()),
         )
     }
 
@@ -8609,7 +8903,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jxlsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn jxlsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "jxlsave_buffer",
@@ -8624,7 +8919,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("JxlsaveBuffer (vips_jxlsave_buffer) failed".to_string()),
+            Error::OperationError("JxlsaveBuffer (vips_jxlsave_buffer) failed".into()),
         )
     }
 
@@ -8645,7 +8940,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JxlsaveTarget (vips_jxlsave_target) failed".to_string()),
+            Error::OperationError("JxlsaveTarget (vips_jxlsave_target) failed".into()),
         )
     }
 
@@ -8672,7 +8967,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn jxlsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn jxlsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "jxlsave_target",
             option
@@ -8686,7 +8986,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("JxlsaveTarget (vips_jxlsave_target) failed".to_string()),
+            Error::OperationError("JxlsaveTarget (vips_jxlsave_target) failed".into()),
         )
     }
 
@@ -8707,7 +9007,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             mask_out,
-            Error::OperationError("Labelregions (vips_labelregions) failed".to_string()),
+            Error::OperationError("Labelregions (vips_labelregions) failed".into()),
         )
     }
 
@@ -8717,7 +9017,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// segments: `&mut i32` -> Number of discrete contiguous regions
-    pub fn labelregions_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn labelregions_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut mask_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "labelregions",
@@ -8732,7 +9033,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             mask_out,
-            Error::OperationError("Labelregions (vips_labelregions) failed".to_string()),
+            Error::OperationError("Labelregions (vips_labelregions) failed".into()),
         )
     }
 
@@ -8759,7 +9060,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Linear (vips_linear) failed".to_string()),
+            Error::OperationError("Linear (vips_linear) failed".into()),
         )
     }
 
@@ -8773,7 +9074,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// uchar: `bool` -> Output should be uchar
-    pub fn linear_with_opts(&self, a: &[f64], b: &[f64], option: VOption) -> Result<VipsImage> {
+    pub fn linear_with_opts<'a>(
+        &self,
+        a: &[f64],
+        b: &[f64],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "linear",
@@ -8790,7 +9097,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Linear (vips_linear) failed".to_string()),
+            Error::OperationError("Linear (vips_linear) failed".into()),
         )
     }
 
@@ -8811,7 +9118,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Linecache (vips_linecache) failed".to_string()),
+            Error::OperationError("Linecache (vips_linecache) failed".into()),
         )
     }
 
@@ -8827,7 +9134,8 @@ impl VipsImage {
     /// threaded: `bool` -> Allow threaded access
     ///
     /// persistent: `bool` -> Keep cache between evaluations
-    pub fn linecache_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn linecache_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "linecache",
@@ -8842,7 +9150,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Linecache (vips_linecache) failed".to_string()),
+            Error::OperationError("Linecache (vips_linecache) failed".into()),
         )
     }
 
@@ -8874,7 +9182,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Logmat (vips_logmat) failed".to_string()),
+            Error::OperationError("Logmat (vips_logmat) failed".into()),
         )
     }
 
@@ -8890,7 +9198,12 @@ impl VipsImage {
     /// separable: `bool` -> Generate separable Gaussian
     ///
     /// precision: [`Precision`] -> Generate with this precision
-    pub fn logmat_with_opts(sigma: f64, min_ampl: f64, option: VOption) -> Result<VipsImage> {
+    pub fn logmat_with_opts<'a>(
+        sigma: f64,
+        min_ampl: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "logmat",
@@ -8912,7 +9225,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Logmat (vips_logmat) failed".to_string()),
+            Error::OperationError("Logmat (vips_logmat) failed".into()),
         )
     }
 
@@ -8938,7 +9251,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Magickload (vips_magickload) failed".to_string()),
+            Error::OperationError("Magickload (vips_magickload) failed".into()),
         )
     }
 
@@ -8964,7 +9277,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn magickload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn magickload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "magickload",
@@ -8982,7 +9299,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Magickload (vips_magickload) failed".to_string()),
+            Error::OperationError("Magickload (vips_magickload) failed".into()),
         )
     }
 
@@ -8992,8 +9309,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn magickload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -9012,11 +9328,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MagickloadBuffer (vips_magickload_buffer) failed".to_string()),
+            Error::OperationError("MagickloadBuffer (vips_magickload_buffer) failed".into()),
         )
     }
 
@@ -9042,10 +9358,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn magickload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn magickload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -9064,11 +9383,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MagickloadBuffer (vips_magickload_buffer) failed".to_string()),
+            Error::OperationError("MagickloadBuffer (vips_magickload_buffer) failed".into()),
         )
     }
 
@@ -9089,7 +9408,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Magicksave (vips_magicksave) failed".to_string()),
+            Error::OperationError("Magicksave (vips_magicksave) failed".into()),
         )
     }
 
@@ -9116,7 +9435,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn magicksave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn magicksave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "magicksave",
             option
@@ -9130,7 +9454,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Magicksave (vips_magicksave) failed".to_string()),
+            Error::OperationError("Magicksave (vips_magicksave) failed".into()),
         )
     }
 
@@ -9151,7 +9475,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("MagicksaveBuffer (vips_magicksave_buffer) failed".to_string()),
+            Error::OperationError("MagicksaveBuffer (vips_magicksave_buffer) failed".into()),
         )
     }
 
@@ -9177,7 +9501,11 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn magicksave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn magicksave_buffer_with_opts<'a>(
+        &self,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "magicksave_buffer",
@@ -9192,7 +9520,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("MagicksaveBuffer (vips_magicksave_buffer) failed".to_string()),
+            Error::OperationError("MagicksaveBuffer (vips_magicksave_buffer) failed".into()),
         )
     }
 
@@ -9219,7 +9547,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mapim (vips_mapim) failed".to_string()),
+            Error::OperationError("Mapim (vips_mapim) failed".into()),
         )
     }
 
@@ -9237,7 +9565,12 @@ impl VipsImage {
     /// premultiplied: `bool` -> Images have premultiplied alpha
     ///
     /// extend: [`Extend`] -> How to generate the extra pixels
-    pub fn mapim_with_opts(&self, index: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn mapim_with_opts<'a>(
+        &self,
+        index: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mapim",
@@ -9256,7 +9589,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mapim (vips_mapim) failed".to_string()),
+            Error::OperationError("Mapim (vips_mapim) failed".into()),
         )
     }
 
@@ -9280,7 +9613,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Maplut (vips_maplut) failed".to_string()),
+            Error::OperationError("Maplut (vips_maplut) failed".into()),
         )
     }
 
@@ -9292,7 +9625,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// band: `i32` -> Apply one-band lut to this band of in
-    pub fn maplut_with_opts(&self, lut: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn maplut_with_opts<'a>(
+        &self,
+        lut: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "maplut",
@@ -9308,7 +9646,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Maplut (vips_maplut) failed".to_string()),
+            Error::OperationError("Maplut (vips_maplut) failed".into()),
         )
     }
 
@@ -9364,7 +9702,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskButterworth (vips_mask_butterworth) failed".to_string()),
+            Error::OperationError("MaskButterworth (vips_mask_butterworth) failed".into()),
         )
     }
 
@@ -9390,14 +9728,15 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_butterworth_with_opts(
+    pub fn mask_butterworth_with_opts<'a>(
         width: i32,
         height: i32,
         order: f64,
         frequency_cutoff: f64,
         amplitude_cutoff: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_butterworth",
@@ -9431,7 +9770,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskButterworth (vips_mask_butterworth) failed".to_string()),
+            Error::OperationError("MaskButterworth (vips_mask_butterworth) failed".into()),
         )
     }
 
@@ -9501,9 +9840,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "MaskButterworthBand (vips_mask_butterworth_band) failed".to_string(),
-            ),
+            Error::OperationError("MaskButterworthBand (vips_mask_butterworth_band) failed".into()),
         )
     }
 
@@ -9533,7 +9870,7 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_butterworth_band_with_opts(
+    pub fn mask_butterworth_band_with_opts<'a>(
         width: i32,
         height: i32,
         order: f64,
@@ -9541,8 +9878,9 @@ impl VipsImage {
         frequency_cutoff_y: f64,
         radius: f64,
         amplitude_cutoff: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_butterworth_band",
@@ -9584,9 +9922,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "MaskButterworthBand (vips_mask_butterworth_band) failed".to_string(),
-            ),
+            Error::OperationError("MaskButterworthBand (vips_mask_butterworth_band) failed".into()),
         )
     }
 
@@ -9649,9 +9985,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "MaskButterworthRing (vips_mask_butterworth_ring) failed".to_string(),
-            ),
+            Error::OperationError("MaskButterworthRing (vips_mask_butterworth_ring) failed".into()),
         )
     }
 
@@ -9679,15 +10013,16 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_butterworth_ring_with_opts(
+    pub fn mask_butterworth_ring_with_opts<'a>(
         width: i32,
         height: i32,
         order: f64,
         frequency_cutoff: f64,
         amplitude_cutoff: f64,
         ringwidth: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_butterworth_ring",
@@ -9725,9 +10060,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "MaskButterworthRing (vips_mask_butterworth_ring) failed".to_string(),
-            ),
+            Error::OperationError("MaskButterworthRing (vips_mask_butterworth_ring) failed".into()),
         )
     }
 
@@ -9765,7 +10098,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskFractal (vips_mask_fractal) failed".to_string()),
+            Error::OperationError("MaskFractal (vips_mask_fractal) failed".into()),
         )
     }
 
@@ -9787,12 +10120,13 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_fractal_with_opts(
+    pub fn mask_fractal_with_opts<'a>(
         width: i32,
         height: i32,
         fractal_dimension: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_fractal",
@@ -9818,7 +10152,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskFractal (vips_mask_fractal) failed".to_string()),
+            Error::OperationError("MaskFractal (vips_mask_fractal) failed".into()),
         )
     }
 
@@ -9867,7 +10201,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussian (vips_mask_gaussian) failed".to_string()),
+            Error::OperationError("MaskGaussian (vips_mask_gaussian) failed".into()),
         )
     }
 
@@ -9891,13 +10225,14 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_gaussian_with_opts(
+    pub fn mask_gaussian_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff: f64,
         amplitude_cutoff: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_gaussian",
@@ -9927,7 +10262,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussian (vips_mask_gaussian) failed".to_string()),
+            Error::OperationError("MaskGaussian (vips_mask_gaussian) failed".into()),
         )
     }
 
@@ -9990,7 +10325,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussianBand (vips_mask_gaussian_band) failed".to_string()),
+            Error::OperationError("MaskGaussianBand (vips_mask_gaussian_band) failed".into()),
         )
     }
 
@@ -10018,15 +10353,16 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_gaussian_band_with_opts(
+    pub fn mask_gaussian_band_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff_x: f64,
         frequency_cutoff_y: f64,
         radius: f64,
         amplitude_cutoff: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_gaussian_band",
@@ -10064,7 +10400,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussianBand (vips_mask_gaussian_band) failed".to_string()),
+            Error::OperationError("MaskGaussianBand (vips_mask_gaussian_band) failed".into()),
         )
     }
 
@@ -10120,7 +10456,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussianRing (vips_mask_gaussian_ring) failed".to_string()),
+            Error::OperationError("MaskGaussianRing (vips_mask_gaussian_ring) failed".into()),
         )
     }
 
@@ -10146,14 +10482,15 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_gaussian_ring_with_opts(
+    pub fn mask_gaussian_ring_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff: f64,
         amplitude_cutoff: f64,
         ringwidth: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_gaussian_ring",
@@ -10187,7 +10524,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskGaussianRing (vips_mask_gaussian_ring) failed".to_string()),
+            Error::OperationError("MaskGaussianRing (vips_mask_gaussian_ring) failed".into()),
         )
     }
 
@@ -10225,7 +10562,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdeal (vips_mask_ideal) failed".to_string()),
+            Error::OperationError("MaskIdeal (vips_mask_ideal) failed".into()),
         )
     }
 
@@ -10247,12 +10584,13 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_ideal_with_opts(
+    pub fn mask_ideal_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_ideal",
@@ -10278,7 +10616,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdeal (vips_mask_ideal) failed".to_string()),
+            Error::OperationError("MaskIdeal (vips_mask_ideal) failed".into()),
         )
     }
 
@@ -10334,7 +10672,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdealBand (vips_mask_ideal_band) failed".to_string()),
+            Error::OperationError("MaskIdealBand (vips_mask_ideal_band) failed".into()),
         )
     }
 
@@ -10360,14 +10698,15 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_ideal_band_with_opts(
+    pub fn mask_ideal_band_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff_x: f64,
         frequency_cutoff_y: f64,
         radius: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_ideal_band",
@@ -10401,7 +10740,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdealBand (vips_mask_ideal_band) failed".to_string()),
+            Error::OperationError("MaskIdealBand (vips_mask_ideal_band) failed".into()),
         )
     }
 
@@ -10450,7 +10789,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdealRing (vips_mask_ideal_ring) failed".to_string()),
+            Error::OperationError("MaskIdealRing (vips_mask_ideal_ring) failed".into()),
         )
     }
 
@@ -10474,13 +10813,14 @@ impl VipsImage {
     /// reject: `bool` -> Invert the sense of the filter
     ///
     /// optical: `bool` -> Rotate quadrants to optical space
-    pub fn mask_ideal_ring_with_opts(
+    pub fn mask_ideal_ring_with_opts<'a>(
         width: i32,
         height: i32,
         frequency_cutoff: f64,
         ringwidth: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mask_ideal_ring",
@@ -10510,7 +10850,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MaskIdealRing (vips_mask_ideal_ring) failed".to_string()),
+            Error::OperationError("MaskIdealRing (vips_mask_ideal_ring) failed".into()),
         )
     }
 
@@ -10571,7 +10911,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matchs (vips_match) failed".to_string()),
+            Error::OperationError("Matchs (vips_match) failed".into()),
         )
     }
 
@@ -10605,7 +10945,7 @@ impl VipsImage {
     /// search: `bool` -> Search to improve tie-points
     ///
     /// interpolate: `&VipsInterpolate` -> Interpolate pixels with this
-    pub fn matches_with_opts(
+    pub fn matches_with_opts<'a>(
         &self,
         sec: &VipsImage,
         xr1: i32,
@@ -10616,8 +10956,9 @@ impl VipsImage {
         yr2: i32,
         xs2: i32,
         ys2: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "match",
@@ -10643,7 +10984,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matchs (vips_match) failed".to_string()),
+            Error::OperationError("Matchs (vips_match) failed".into()),
         )
     }
 
@@ -10679,7 +11020,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Math2 (vips_math2) failed".to_string()),
+            Error::OperationError("Math2 (vips_math2) failed".into()),
         )
     }
 
@@ -10709,7 +11050,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Math2Const (vips_math2_const) failed".to_string()),
+            Error::OperationError("Math2Const (vips_math2_const) failed".into()),
         )
     }
 
@@ -10736,7 +11077,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Math (vips_math) failed".to_string()),
+            Error::OperationError("Math (vips_math) failed".into()),
         )
     }
 
@@ -10762,7 +11103,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matload (vips_matload) failed".to_string()),
+            Error::OperationError("Matload (vips_matload) failed".into()),
         )
     }
 
@@ -10782,7 +11123,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn matload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn matload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "matload",
@@ -10800,7 +11145,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matload (vips_matload) failed".to_string()),
+            Error::OperationError("Matload (vips_matload) failed".into()),
         )
     }
 
@@ -10821,7 +11166,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matrixinvert (vips_matrixinvert) failed".to_string()),
+            Error::OperationError("Matrixinvert (vips_matrixinvert) failed".into()),
         )
     }
 
@@ -10847,7 +11192,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matrixload (vips_matrixload) failed".to_string()),
+            Error::OperationError("Matrixload (vips_matrixload) failed".into()),
         )
     }
 
@@ -10867,7 +11212,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn matrixload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn matrixload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "matrixload",
@@ -10885,7 +11234,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matrixload (vips_matrixload) failed".to_string()),
+            Error::OperationError("Matrixload (vips_matrixload) failed".into()),
         )
     }
 
@@ -10911,7 +11260,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MatrixloadSource (vips_matrixload_source) failed".to_string()),
+            Error::OperationError("MatrixloadSource (vips_matrixload_source) failed".into()),
         )
     }
 
@@ -10931,7 +11280,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn matrixload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn matrixload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "matrixload_source",
@@ -10949,7 +11302,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("MatrixloadSource (vips_matrixload_source) failed".to_string()),
+            Error::OperationError("MatrixloadSource (vips_matrixload_source) failed".into()),
         )
     }
 
@@ -10979,7 +11332,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Matrixmultiply (vips_matrixmultiply) failed".to_string()),
+            Error::OperationError("Matrixmultiply (vips_matrixmultiply) failed".into()),
         )
     }
 
@@ -10993,7 +11346,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Matrixprint (vips_matrixprint) failed".to_string()),
+            Error::OperationError("Matrixprint (vips_matrixprint) failed".into()),
         )
     }
 
@@ -11008,7 +11361,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn matrixprint_with_opts(&self, option: VOption) -> Result<()> {
+    pub fn matrixprint_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "matrixprint",
             option.set("in", self),
@@ -11017,7 +11371,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Matrixprint (vips_matrixprint) failed".to_string()),
+            Error::OperationError("Matrixprint (vips_matrixprint) failed".into()),
         )
     }
 
@@ -11038,7 +11392,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Matrixsave (vips_matrixsave) failed".to_string()),
+            Error::OperationError("Matrixsave (vips_matrixsave) failed".into()),
         )
     }
 
@@ -11055,7 +11409,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn matrixsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn matrixsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "matrixsave",
             option
@@ -11069,7 +11428,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Matrixsave (vips_matrixsave) failed".to_string()),
+            Error::OperationError("Matrixsave (vips_matrixsave) failed".into()),
         )
     }
 
@@ -11090,7 +11449,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("MatrixsaveTarget (vips_matrixsave_target) failed".to_string()),
+            Error::OperationError("MatrixsaveTarget (vips_matrixsave_target) failed".into()),
         )
     }
 
@@ -11107,7 +11466,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn matrixsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn matrixsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "matrixsave_target",
             option
@@ -11121,7 +11485,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("MatrixsaveTarget (vips_matrixsave_target) failed".to_string()),
+            Error::OperationError("MatrixsaveTarget (vips_matrixsave_target) failed".into()),
         )
     }
 
@@ -11142,7 +11506,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Max (vips_max) failed".to_string()),
+            Error::OperationError("Max (vips_max) failed".into()),
         )
     }
 
@@ -11159,10 +11523,11 @@ impl VipsImage {
     ///
     /// out_array: `&mut Vec<f64>` -> Array of output values
     ///
-    /// x_array: `&[i32]` -> Array of horizontal positions
+    /// x_array: `&mut Vec<i32>` -> Array of horizontal positions
     ///
-    /// y_array: `&[i32]` -> Array of vertical positions
-    pub fn max_with_opts(&self, option: VOption) -> Result<f64> {
+    /// y_array: `&mut Vec<i32>` -> Array of vertical positions
+    pub fn max_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<f64> {
+        let option: VOption = option.into();
         let mut out_out: f64 = 0.0;
         let vips_op_response = call(
             "max",
@@ -11177,7 +11542,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Max (vips_max) failed".to_string()),
+            Error::OperationError("Max (vips_max) failed".into()),
         )
     }
 
@@ -11207,7 +11572,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Maxpair (vips_maxpair) failed".to_string()),
+            Error::OperationError("Maxpair (vips_maxpair) failed".into()),
         )
     }
 
@@ -11234,7 +11599,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Measure (vips_measure) failed".to_string()),
+            Error::OperationError("Measure (vips_measure) failed".into()),
         )
     }
 
@@ -11254,7 +11619,13 @@ impl VipsImage {
     /// width: `i32` -> Width of extract area
     ///
     /// height: `i32` -> Height of extract area
-    pub fn measure_with_opts(&self, h: i32, v: i32, option: VOption) -> Result<VipsImage> {
+    pub fn measure_with_opts<'a>(
+        &self,
+        h: i32,
+        v: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "measure",
@@ -11271,7 +11642,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Measure (vips_measure) failed".to_string()),
+            Error::OperationError("Measure (vips_measure) failed".into()),
         )
     }
 
@@ -11315,7 +11686,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Merge (vips_merge) failed".to_string()),
+            Error::OperationError("Merge (vips_merge) failed".into()),
         )
     }
 
@@ -11333,14 +11704,15 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// mblend: `i32` -> Maximum blend size
-    pub fn merge_with_opts(
+    pub fn merge_with_opts<'a>(
         &self,
         sec: &VipsImage,
         direction: Direction,
         dx: i32,
         dy: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "merge",
@@ -11364,7 +11736,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Merge (vips_merge) failed".to_string()),
+            Error::OperationError("Merge (vips_merge) failed".into()),
         )
     }
 
@@ -11385,7 +11757,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Min (vips_min) failed".to_string()),
+            Error::OperationError("Min (vips_min) failed".into()),
         )
     }
 
@@ -11402,10 +11774,11 @@ impl VipsImage {
     ///
     /// out_array: `&mut Vec<f64>` -> Array of output values
     ///
-    /// x_array: `&[i32]` -> Array of horizontal positions
+    /// x_array: `&mut Vec<i32>` -> Array of horizontal positions
     ///
-    /// y_array: `&[i32]` -> Array of vertical positions
-    pub fn min_with_opts(&self, option: VOption) -> Result<f64> {
+    /// y_array: `&mut Vec<i32>` -> Array of vertical positions
+    pub fn min_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<f64> {
+        let option: VOption = option.into();
         let mut out_out: f64 = 0.0;
         let vips_op_response = call(
             "min",
@@ -11420,7 +11793,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Min (vips_min) failed".to_string()),
+            Error::OperationError("Min (vips_min) failed".into()),
         )
     }
 
@@ -11450,7 +11823,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Minpair (vips_minpair) failed".to_string()),
+            Error::OperationError("Minpair (vips_minpair) failed".into()),
         )
     }
 
@@ -11483,7 +11856,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Morph (vips_morph) failed".to_string()),
+            Error::OperationError("Morph (vips_morph) failed".into()),
         )
     }
 
@@ -11551,7 +11924,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mosaic1 (vips_mosaic1) failed".to_string()),
+            Error::OperationError("Mosaic1 (vips_mosaic1) failed".into()),
         )
     }
 
@@ -11589,7 +11962,7 @@ impl VipsImage {
     /// interpolate: `&VipsInterpolate` -> Interpolate pixels with this
     ///
     /// mblend: `i32` -> Maximum blend size
-    pub fn mosaic1_with_opts(
+    pub fn mosaic1_with_opts<'a>(
         &self,
         sec: &VipsImage,
         direction: Direction,
@@ -11601,8 +11974,9 @@ impl VipsImage {
         yr2: i32,
         xs2: i32,
         ys2: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mosaic1",
@@ -11632,7 +12006,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mosaic1 (vips_mosaic1) failed".to_string()),
+            Error::OperationError("Mosaic1 (vips_mosaic1) failed".into()),
         )
     }
 
@@ -11696,7 +12070,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mosaic (vips_mosaic) failed".to_string()),
+            Error::OperationError("Mosaic (vips_mosaic) failed".into()),
         )
     }
 
@@ -11736,7 +12110,7 @@ impl VipsImage {
     /// dy1: `&mut f64` -> Detected first-order displacement
     ///
     /// dx1: `&mut f64` -> Detected first-order displacement
-    pub fn mosaic_with_opts(
+    pub fn mosaic_with_opts<'a>(
         &self,
         sec: &VipsImage,
         direction: Direction,
@@ -11744,8 +12118,9 @@ impl VipsImage {
         yref: i32,
         xsec: i32,
         ysec: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "mosaic",
@@ -11783,7 +12158,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Mosaic (vips_mosaic) failed".to_string()),
+            Error::OperationError("Mosaic (vips_mosaic) failed".into()),
         )
     }
 
@@ -11804,7 +12179,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Msb (vips_msb) failed".to_string()),
+            Error::OperationError("Msb (vips_msb) failed".into()),
         )
     }
 
@@ -11814,7 +12189,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// band: `i32` -> Band to msb
-    pub fn msb_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn msb_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "msb",
@@ -11829,7 +12205,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Msb (vips_msb) failed".to_string()),
+            Error::OperationError("Msb (vips_msb) failed".into()),
         )
     }
 
@@ -11859,7 +12235,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Multiply (vips_multiply) failed".to_string()),
+            Error::OperationError("Multiply (vips_multiply) failed".into()),
         )
     }
 
@@ -11885,7 +12261,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Openexrload (vips_openexrload) failed".to_string()),
+            Error::OperationError("Openexrload (vips_openexrload) failed".into()),
         )
     }
 
@@ -11905,7 +12281,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn openexrload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn openexrload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "openexrload",
@@ -11923,7 +12303,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Openexrload (vips_openexrload) failed".to_string()),
+            Error::OperationError("Openexrload (vips_openexrload) failed".into()),
         )
     }
 
@@ -11949,7 +12329,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Openslideload (vips_openslideload) failed".to_string()),
+            Error::OperationError("Openslideload (vips_openslideload) failed".into()),
         )
     }
 
@@ -11979,7 +12359,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn openslideload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn openslideload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "openslideload",
@@ -11997,7 +12381,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Openslideload (vips_openslideload) failed".to_string()),
+            Error::OperationError("Openslideload (vips_openslideload) failed".into()),
         )
     }
 
@@ -12023,9 +12407,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "OpenslideloadSource (vips_openslideload_source) failed".to_string(),
-            ),
+            Error::OperationError("OpenslideloadSource (vips_openslideload_source) failed".into()),
         )
     }
 
@@ -12055,10 +12437,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn openslideload_source_with_opts(
+    pub fn openslideload_source_with_opts<'a>(
         source: &VipsSource,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "openslideload_source",
@@ -12076,9 +12459,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError(
-                "OpenslideloadSource (vips_openslideload_source) failed".to_string(),
-            ),
+            Error::OperationError("OpenslideloadSource (vips_openslideload_source) failed".into()),
         )
     }
 
@@ -12104,7 +12485,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Pdfload (vips_pdfload) failed".to_string()),
+            Error::OperationError("Pdfload (vips_pdfload) failed".into()),
         )
     }
 
@@ -12136,7 +12517,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pdfload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn pdfload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "pdfload",
@@ -12154,7 +12539,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Pdfload (vips_pdfload) failed".to_string()),
+            Error::OperationError("Pdfload (vips_pdfload) failed".into()),
         )
     }
 
@@ -12164,8 +12549,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn pdfload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -12184,11 +12568,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PdfloadBuffer (vips_pdfload_buffer) failed".to_string()),
+            Error::OperationError("PdfloadBuffer (vips_pdfload_buffer) failed".into()),
         )
     }
 
@@ -12220,10 +12604,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pdfload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn pdfload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -12242,11 +12629,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PdfloadBuffer (vips_pdfload_buffer) failed".to_string()),
+            Error::OperationError("PdfloadBuffer (vips_pdfload_buffer) failed".into()),
         )
     }
 
@@ -12272,7 +12659,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PdfloadSource (vips_pdfload_source) failed".to_string()),
+            Error::OperationError("PdfloadSource (vips_pdfload_source) failed".into()),
         )
     }
 
@@ -12304,7 +12691,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pdfload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn pdfload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "pdfload_source",
@@ -12322,7 +12713,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PdfloadSource (vips_pdfload_source) failed".to_string()),
+            Error::OperationError("PdfloadSource (vips_pdfload_source) failed".into()),
         )
     }
 
@@ -12349,7 +12740,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             threshold_out,
-            Error::OperationError("Percent (vips_percent) failed".to_string()),
+            Error::OperationError("Percent (vips_percent) failed".into()),
         )
     }
 
@@ -12381,7 +12772,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Perlin (vips_perlin) failed".to_string()),
+            Error::OperationError("Perlin (vips_perlin) failed".into()),
         )
     }
 
@@ -12399,7 +12790,12 @@ impl VipsImage {
     /// uchar: `bool` -> Output an unsigned char image
     ///
     /// seed: `i32` -> Random number seed
-    pub fn perlin_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn perlin_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "perlin",
@@ -12421,7 +12817,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Perlin (vips_perlin) failed".to_string()),
+            Error::OperationError("Perlin (vips_perlin) failed".into()),
         )
     }
 
@@ -12445,7 +12841,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Phasecor (vips_phasecor) failed".to_string()),
+            Error::OperationError("Phasecor (vips_phasecor) failed".into()),
         )
     }
 
@@ -12471,7 +12867,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Pngload (vips_pngload) failed".to_string()),
+            Error::OperationError("Pngload (vips_pngload) failed".into()),
         )
     }
 
@@ -12493,7 +12889,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pngload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn pngload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "pngload",
@@ -12511,7 +12911,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Pngload (vips_pngload) failed".to_string()),
+            Error::OperationError("Pngload (vips_pngload) failed".into()),
         )
     }
 
@@ -12521,8 +12921,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn pngload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -12541,11 +12940,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PngloadBuffer (vips_pngload_buffer) failed".to_string()),
+            Error::OperationError("PngloadBuffer (vips_pngload_buffer) failed".into()),
         )
     }
 
@@ -12567,10 +12966,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pngload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn pngload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -12589,11 +12991,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PngloadBuffer (vips_pngload_buffer) failed".to_string()),
+            Error::OperationError("PngloadBuffer (vips_pngload_buffer) failed".into()),
         )
     }
 
@@ -12619,7 +13021,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PngloadSource (vips_pngload_source) failed".to_string()),
+            Error::OperationError("PngloadSource (vips_pngload_source) failed".into()),
         )
     }
 
@@ -12641,7 +13043,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn pngload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn pngload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "pngload_source",
@@ -12659,7 +13065,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PngloadSource (vips_pngload_source) failed".to_string()),
+            Error::OperationError("PngloadSource (vips_pngload_source) failed".into()),
         )
     }
 
@@ -12680,7 +13086,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Pngsave (vips_pngsave) failed".to_string()),
+            Error::OperationError("Pngsave (vips_pngsave) failed".into()),
         )
     }
 
@@ -12713,7 +13119,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn pngsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn pngsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "pngsave",
             option
@@ -12727,7 +13138,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Pngsave (vips_pngsave) failed".to_string()),
+            Error::OperationError("Pngsave (vips_pngsave) failed".into()),
         )
     }
 
@@ -12748,7 +13159,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("PngsaveBuffer (vips_pngsave_buffer) failed".to_string()),
+            Error::OperationError("PngsaveBuffer (vips_pngsave_buffer) failed".into()),
         )
     }
 
@@ -12780,7 +13191,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn pngsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn pngsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "pngsave_buffer",
@@ -12795,7 +13207,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("PngsaveBuffer (vips_pngsave_buffer) failed".to_string()),
+            Error::OperationError("PngsaveBuffer (vips_pngsave_buffer) failed".into()),
         )
     }
 
@@ -12816,7 +13228,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("PngsaveTarget (vips_pngsave_target) failed".to_string()),
+            Error::OperationError("PngsaveTarget (vips_pngsave_target) failed".into()),
         )
     }
 
@@ -12849,7 +13261,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn pngsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn pngsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "pngsave_target",
             option
@@ -12863,7 +13280,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("PngsaveTarget (vips_pngsave_target) failed".to_string()),
+            Error::OperationError("PngsaveTarget (vips_pngsave_target) failed".into()),
         )
     }
 
@@ -12889,7 +13306,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Ppmload (vips_ppmload) failed".to_string()),
+            Error::OperationError("Ppmload (vips_ppmload) failed".into()),
         )
     }
 
@@ -12909,7 +13326,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn ppmload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn ppmload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "ppmload",
@@ -12927,7 +13348,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Ppmload (vips_ppmload) failed".to_string()),
+            Error::OperationError("Ppmload (vips_ppmload) failed".into()),
         )
     }
 
@@ -12937,8 +13358,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn ppmload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -12957,11 +13377,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PpmloadBuffer (vips_ppmload_buffer) failed".to_string()),
+            Error::OperationError("PpmloadBuffer (vips_ppmload_buffer) failed".into()),
         )
     }
 
@@ -12981,10 +13401,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn ppmload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn ppmload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -13003,11 +13426,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PpmloadBuffer (vips_ppmload_buffer) failed".to_string()),
+            Error::OperationError("PpmloadBuffer (vips_ppmload_buffer) failed".into()),
         )
     }
 
@@ -13033,7 +13456,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PpmloadSource (vips_ppmload_source) failed".to_string()),
+            Error::OperationError("PpmloadSource (vips_ppmload_source) failed".into()),
         )
     }
 
@@ -13053,7 +13476,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn ppmload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn ppmload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "ppmload_source",
@@ -13071,7 +13498,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("PpmloadSource (vips_ppmload_source) failed".to_string()),
+            Error::OperationError("PpmloadSource (vips_ppmload_source) failed".into()),
         )
     }
 
@@ -13092,7 +13519,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Ppmsave (vips_ppmsave) failed".to_string()),
+            Error::OperationError("Ppmsave (vips_ppmsave) failed".into()),
         )
     }
 
@@ -13115,7 +13542,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn ppmsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn ppmsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "ppmsave",
             option
@@ -13129,7 +13561,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Ppmsave (vips_ppmsave) failed".to_string()),
+            Error::OperationError("Ppmsave (vips_ppmsave) failed".into()),
         )
     }
 
@@ -13150,7 +13582,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("PpmsaveTarget (vips_ppmsave_target) failed".to_string()),
+            Error::OperationError("PpmsaveTarget (vips_ppmsave_target) failed".into()),
         )
     }
 
@@ -13173,7 +13605,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn ppmsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn ppmsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "ppmsave_target",
             option
@@ -13187,7 +13624,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("PpmsaveTarget (vips_ppmsave_target) failed".to_string()),
+            Error::OperationError("PpmsaveTarget (vips_ppmsave_target) failed".into()),
         )
     }
 
@@ -13208,7 +13645,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Premultiply (vips_premultiply) failed".to_string()),
+            Error::OperationError("Premultiply (vips_premultiply) failed".into()),
         )
     }
 
@@ -13218,7 +13655,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// max_alpha: `f64` -> Maximum value of alpha channel
-    pub fn premultiply_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn premultiply_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "premultiply",
@@ -13233,7 +13671,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Premultiply (vips_premultiply) failed".to_string()),
+            Error::OperationError("Premultiply (vips_premultiply) failed".into()),
         )
     }
 
@@ -13254,7 +13692,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Prewitt (vips_prewitt) failed".to_string()),
+            Error::OperationError("Prewitt (vips_prewitt) failed".into()),
         )
     }
 
@@ -13291,7 +13729,7 @@ impl VipsImage {
                 columns_out,
                 rows_out,
             ),
-            Error::OperationError("Profile (vips_profile) failed".to_string()),
+            Error::OperationError("Profile (vips_profile) failed".into()),
         )
     }
 
@@ -13317,7 +13755,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             profile_out.into(),
-            Error::OperationError("ProfileLoad (vips_profile_load) failed".to_string()),
+            Error::OperationError("ProfileLoad (vips_profile_load) failed".into()),
         )
     }
 
@@ -13354,7 +13792,7 @@ impl VipsImage {
                 columns_out,
                 rows_out,
             ),
-            Error::OperationError("Project (vips_project) failed".to_string()),
+            Error::OperationError("Project (vips_project) failed".into()),
         )
     }
 
@@ -13381,7 +13819,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Quadratic (vips_quadratic) failed".to_string()),
+            Error::OperationError("Quadratic (vips_quadratic) failed".into()),
         )
     }
 
@@ -13393,7 +13831,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// interpolate: `&VipsInterpolate` -> Interpolate values with this
-    pub fn quadratic_with_opts(&self, coeff: &VipsImage, option: VOption) -> Result<VipsImage> {
+    pub fn quadratic_with_opts<'a>(
+        &self,
+        coeff: &VipsImage,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "quadratic",
@@ -13412,7 +13855,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Quadratic (vips_quadratic) failed".to_string()),
+            Error::OperationError("Quadratic (vips_quadratic) failed".into()),
         )
     }
 
@@ -13433,7 +13876,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rad2Float (vips_rad2float) failed".to_string()),
+            Error::OperationError("Rad2Float (vips_rad2float) failed".into()),
         )
     }
 
@@ -13459,7 +13902,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Radload (vips_radload) failed".to_string()),
+            Error::OperationError("Radload (vips_radload) failed".into()),
         )
     }
 
@@ -13479,7 +13922,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn radload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn radload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "radload",
@@ -13497,7 +13944,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Radload (vips_radload) failed".to_string()),
+            Error::OperationError("Radload (vips_radload) failed".into()),
         )
     }
 
@@ -13507,8 +13954,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn radload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -13527,11 +13973,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RadloadBuffer (vips_radload_buffer) failed".to_string()),
+            Error::OperationError("RadloadBuffer (vips_radload_buffer) failed".into()),
         )
     }
 
@@ -13551,10 +13997,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn radload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn radload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -13573,11 +14022,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RadloadBuffer (vips_radload_buffer) failed".to_string()),
+            Error::OperationError("RadloadBuffer (vips_radload_buffer) failed".into()),
         )
     }
 
@@ -13603,7 +14052,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RadloadSource (vips_radload_source) failed".to_string()),
+            Error::OperationError("RadloadSource (vips_radload_source) failed".into()),
         )
     }
 
@@ -13623,7 +14072,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn radload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn radload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "radload_source",
@@ -13641,7 +14094,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RadloadSource (vips_radload_source) failed".to_string()),
+            Error::OperationError("RadloadSource (vips_radload_source) failed".into()),
         )
     }
 
@@ -13662,7 +14115,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Radsave (vips_radsave) failed".to_string()),
+            Error::OperationError("Radsave (vips_radsave) failed".into()),
         )
     }
 
@@ -13679,7 +14132,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn radsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn radsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "radsave",
             option
@@ -13693,7 +14151,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Radsave (vips_radsave) failed".to_string()),
+            Error::OperationError("Radsave (vips_radsave) failed".into()),
         )
     }
 
@@ -13714,7 +14172,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("RadsaveBuffer (vips_radsave_buffer) failed".to_string()),
+            Error::OperationError("RadsaveBuffer (vips_radsave_buffer) failed".into()),
         )
     }
 
@@ -13730,7 +14188,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn radsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn radsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "radsave_buffer",
@@ -13745,7 +14204,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("RadsaveBuffer (vips_radsave_buffer) failed".to_string()),
+            Error::OperationError("RadsaveBuffer (vips_radsave_buffer) failed".into()),
         )
     }
 
@@ -13766,7 +14225,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("RadsaveTarget (vips_radsave_target) failed".to_string()),
+            Error::OperationError("RadsaveTarget (vips_radsave_target) failed".into()),
         )
     }
 
@@ -13783,7 +14242,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn radsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn radsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "radsave_target",
             option
@@ -13797,7 +14261,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("RadsaveTarget (vips_radsave_target) failed".to_string()),
+            Error::OperationError("RadsaveTarget (vips_radsave_target) failed".into()),
         )
     }
 
@@ -13836,7 +14300,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rank (vips_rank) failed".to_string()),
+            Error::OperationError("Rank (vips_rank) failed".into()),
         )
     }
 
@@ -13880,7 +14344,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rawload (vips_rawload) failed".to_string()),
+            Error::OperationError("Rawload (vips_rawload) failed".into()),
         )
     }
 
@@ -13912,13 +14376,14 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn rawload_with_opts(
+    pub fn rawload_with_opts<'a>(
         filename: &str,
         width: i32,
         height: i32,
         bands: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "rawload",
@@ -13948,7 +14413,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rawload (vips_rawload) failed".to_string()),
+            Error::OperationError("Rawload (vips_rawload) failed".into()),
         )
     }
 
@@ -13969,7 +14434,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Rawsave (vips_rawsave) failed".to_string()),
+            Error::OperationError("Rawsave (vips_rawsave) failed".into()),
         )
     }
 
@@ -13986,7 +14451,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn rawsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn rawsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "rawsave",
             option
@@ -14000,7 +14470,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Rawsave (vips_rawsave) failed".to_string()),
+            Error::OperationError("Rawsave (vips_rawsave) failed".into()),
         )
     }
 
@@ -14021,7 +14491,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("RawsaveBuffer (vips_rawsave_buffer) failed".to_string()),
+            Error::OperationError("RawsaveBuffer (vips_rawsave_buffer) failed".into()),
         )
     }
 
@@ -14037,7 +14507,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn rawsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn rawsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "rawsave_buffer",
@@ -14052,7 +14523,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("RawsaveBuffer (vips_rawsave_buffer) failed".to_string()),
+            Error::OperationError("RawsaveBuffer (vips_rawsave_buffer) failed".into()),
         )
     }
 
@@ -14073,7 +14544,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("RawsaveTarget (vips_rawsave_target) failed".to_string()),
+            Error::OperationError("RawsaveTarget (vips_rawsave_target) failed".into()),
         )
     }
 
@@ -14090,7 +14561,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn rawsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn rawsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "rawsave_target",
             option
@@ -14104,7 +14580,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("RawsaveTarget (vips_rawsave_target) failed".to_string()),
+            Error::OperationError("RawsaveTarget (vips_rawsave_target) failed".into()),
         )
     }
 
@@ -14128,7 +14604,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Recomb (vips_recomb) failed".to_string()),
+            Error::OperationError("Recomb (vips_recomb) failed".into()),
         )
     }
 
@@ -14161,7 +14637,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reduce (vips_reduce) failed".to_string()),
+            Error::OperationError("Reduce (vips_reduce) failed".into()),
         )
     }
 
@@ -14177,12 +14653,13 @@ impl VipsImage {
     /// kernel: [`Kernel`] -> Resampling kernel
     ///
     /// gap: `f64` -> Reducing gap
-    pub fn reduce_with_opts(
+    pub fn reduce_with_opts<'a>(
         &self,
         hshrink: f64,
         vshrink: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "reduce",
@@ -14205,7 +14682,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reduce (vips_reduce) failed".to_string()),
+            Error::OperationError("Reduce (vips_reduce) failed".into()),
         )
     }
 
@@ -14232,7 +14709,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reduceh (vips_reduceh) failed".to_string()),
+            Error::OperationError("Reduceh (vips_reduceh) failed".into()),
         )
     }
 
@@ -14246,7 +14723,12 @@ impl VipsImage {
     /// kernel: [`Kernel`] -> Resampling kernel
     ///
     /// gap: `f64` -> Reducing gap
-    pub fn reduceh_with_opts(&self, hshrink: f64, option: VOption) -> Result<VipsImage> {
+    pub fn reduceh_with_opts<'a>(
+        &self,
+        hshrink: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "reduceh",
@@ -14265,7 +14747,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reduceh (vips_reduceh) failed".to_string()),
+            Error::OperationError("Reduceh (vips_reduceh) failed".into()),
         )
     }
 
@@ -14292,7 +14774,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reducev (vips_reducev) failed".to_string()),
+            Error::OperationError("Reducev (vips_reducev) failed".into()),
         )
     }
 
@@ -14306,7 +14788,12 @@ impl VipsImage {
     /// kernel: [`Kernel`] -> Resampling kernel
     ///
     /// gap: `f64` -> Reducing gap
-    pub fn reducev_with_opts(&self, vshrink: f64, option: VOption) -> Result<VipsImage> {
+    pub fn reducev_with_opts<'a>(
+        &self,
+        vshrink: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "reducev",
@@ -14325,7 +14812,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Reducev (vips_reducev) failed".to_string()),
+            Error::OperationError("Reducev (vips_reducev) failed".into()),
         )
     }
 
@@ -14365,7 +14852,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Relational (vips_relational) failed".to_string()),
+            Error::OperationError("Relational (vips_relational) failed".into()),
         )
     }
 
@@ -14399,7 +14886,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RelationalConst (vips_relational_const) failed".to_string()),
+            Error::OperationError("RelationalConst (vips_relational_const) failed".into()),
         )
     }
 
@@ -14429,7 +14916,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Remainder (vips_remainder) failed".to_string()),
+            Error::OperationError("Remainder (vips_remainder) failed".into()),
         )
     }
 
@@ -14453,7 +14940,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("RemainderConst (vips_remainder_const) failed".to_string()),
+            Error::OperationError("RemainderConst (vips_remainder_const) failed".into()),
         )
     }
 
@@ -14486,7 +14973,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Remosaic (vips_remosaic) failed".to_string()),
+            Error::OperationError("Remosaic (vips_remosaic) failed".into()),
         )
     }
 
@@ -14519,7 +15006,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Replicate (vips_replicate) failed".to_string()),
+            Error::OperationError("Replicate (vips_replicate) failed".into()),
         )
     }
 
@@ -14546,7 +15033,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Resize (vips_resize) failed".to_string()),
+            Error::OperationError("Resize (vips_resize) failed".into()),
         )
     }
 
@@ -14562,7 +15049,12 @@ impl VipsImage {
     /// gap: `f64` -> Reducing gap
     ///
     /// vscale: `f64` -> Vertical scale image by this factor
-    pub fn resize_with_opts(&self, scale: f64, option: VOption) -> Result<VipsImage> {
+    pub fn resize_with_opts<'a>(
+        &self,
+        scale: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "resize",
@@ -14581,7 +15073,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Resize (vips_resize) failed".to_string()),
+            Error::OperationError("Resize (vips_resize) failed".into()),
         )
     }
 
@@ -14602,7 +15094,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rot45 (vips_rot45) failed".to_string()),
+            Error::OperationError("Rot45 (vips_rot45) failed".into()),
         )
     }
 
@@ -14612,7 +15104,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// angle: [`Angle45`] -> Angle to rotate image
-    pub fn rot45_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn rot45_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "rot45",
@@ -14627,7 +15120,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rot45 (vips_rot45) failed".to_string()),
+            Error::OperationError("Rot45 (vips_rot45) failed".into()),
         )
     }
 
@@ -14654,7 +15147,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rot (vips_rot) failed".to_string()),
+            Error::OperationError("Rot (vips_rot) failed".into()),
         )
     }
 
@@ -14681,7 +15174,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rotate (vips_rotate) failed".to_string()),
+            Error::OperationError("Rotate (vips_rotate) failed".into()),
         )
     }
 
@@ -14703,7 +15196,12 @@ impl VipsImage {
     /// idx: `f64` -> Horizontal input displacement
     ///
     /// idy: `f64` -> Vertical input displacement
-    pub fn rotate_with_opts(&self, angle: f64, option: VOption) -> Result<VipsImage> {
+    pub fn rotate_with_opts<'a>(
+        &self,
+        angle: f64,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "rotate",
@@ -14722,7 +15220,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Rotate (vips_rotate) failed".to_string()),
+            Error::OperationError("Rotate (vips_rotate) failed".into()),
         )
     }
 
@@ -14749,7 +15247,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Round (vips_round) failed".to_string()),
+            Error::OperationError("Round (vips_round) failed".into()),
         )
     }
 
@@ -14770,7 +15268,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SRgb2Hsv (vips_sRGB2HSV) failed".to_string()),
+            Error::OperationError("SRgb2Hsv (vips_sRGB2HSV) failed".into()),
         )
     }
 
@@ -14791,7 +15289,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SRgb2ScRgb (vips_sRGB2scRGB) failed".to_string()),
+            Error::OperationError("SRgb2ScRgb (vips_sRGB2scRGB) failed".into()),
         )
     }
 
@@ -14812,7 +15310,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ScRgb2Bw (vips_scRGB2BW) failed".to_string()),
+            Error::OperationError("ScRgb2Bw (vips_scRGB2BW) failed".into()),
         )
     }
 
@@ -14822,7 +15320,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// depth: `i32` -> Output device space depth in bits
-    pub fn scRGB2BW_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn scRGB2BW_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "scRGB2BW",
@@ -14837,7 +15336,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ScRgb2Bw (vips_scRGB2BW) failed".to_string()),
+            Error::OperationError("ScRgb2Bw (vips_scRGB2BW) failed".into()),
         )
     }
 
@@ -14858,7 +15357,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ScRgb2Xyz (vips_scRGB2XYZ) failed".to_string()),
+            Error::OperationError("ScRgb2Xyz (vips_scRGB2XYZ) failed".into()),
         )
     }
 
@@ -14879,7 +15378,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ScRgb2SRgb (vips_scRGB2sRGB) failed".to_string()),
+            Error::OperationError("ScRgb2SRgb (vips_scRGB2sRGB) failed".into()),
         )
     }
 
@@ -14889,7 +15388,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// depth: `i32` -> Output device space depth in bits
-    pub fn scRGB2sRGB_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn scRGB2sRGB_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "scRGB2sRGB",
@@ -14904,7 +15404,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ScRgb2SRgb (vips_scRGB2sRGB) failed".to_string()),
+            Error::OperationError("ScRgb2SRgb (vips_scRGB2sRGB) failed".into()),
         )
     }
 
@@ -14925,7 +15425,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Scale (vips_scale) failed".to_string()),
+            Error::OperationError("Scale (vips_scale) failed".into()),
         )
     }
 
@@ -14937,7 +15437,8 @@ impl VipsImage {
     /// exp: `f64` -> Exponent for log scale
     ///
     /// log: `bool` -> Log scale
-    pub fn scale_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn scale_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "scale",
@@ -14952,7 +15453,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Scale (vips_scale) failed".to_string()),
+            Error::OperationError("Scale (vips_scale) failed".into()),
         )
     }
 
@@ -14973,7 +15474,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Scharr (vips_scharr) failed".to_string()),
+            Error::OperationError("Scharr (vips_scharr) failed".into()),
         )
     }
 
@@ -15011,7 +15512,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sdf (vips_sdf) failed".to_string()),
+            Error::OperationError("Sdf (vips_sdf) failed".into()),
         )
     }
 
@@ -15033,12 +15534,13 @@ impl VipsImage {
     /// b: `&[f64]` -> Point b
     ///
     /// corners: `&[f64]` -> Corner radii
-    pub fn sdf_with_opts(
+    pub fn sdf_with_opts<'a>(
         width: i32,
         height: i32,
         shape: SdfShape,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "sdf",
@@ -15064,7 +15566,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sdf (vips_sdf) failed".to_string()),
+            Error::OperationError("Sdf (vips_sdf) failed".into()),
         )
     }
 
@@ -15085,7 +15587,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sequential (vips_sequential) failed".to_string()),
+            Error::OperationError("Sequential (vips_sequential) failed".into()),
         )
     }
 
@@ -15095,7 +15597,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// tile_height: `i32` -> Tile height in pixels
-    pub fn sequential_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn sequential_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "sequential",
@@ -15110,7 +15613,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sequential (vips_sequential) failed".to_string()),
+            Error::OperationError("Sequential (vips_sequential) failed".into()),
         )
     }
 
@@ -15131,7 +15634,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sharpen (vips_sharpen) failed".to_string()),
+            Error::OperationError("Sharpen (vips_sharpen) failed".into()),
         )
     }
 
@@ -15151,7 +15654,8 @@ impl VipsImage {
     /// m1: `f64` -> Slope for flat areas
     ///
     /// m2: `f64` -> Slope for jaggy areas
-    pub fn sharpen_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn sharpen_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "sharpen",
@@ -15166,7 +15670,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sharpen (vips_sharpen) failed".to_string()),
+            Error::OperationError("Sharpen (vips_sharpen) failed".into()),
         )
     }
 
@@ -15199,7 +15703,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrink (vips_shrink) failed".to_string()),
+            Error::OperationError("Shrink (vips_shrink) failed".into()),
         )
     }
 
@@ -15213,12 +15717,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// ceil: `bool` -> Round-up output dimensions
-    pub fn shrink_with_opts(
+    pub fn shrink_with_opts<'a>(
         &self,
         hshrink: f64,
         vshrink: f64,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "shrink",
@@ -15241,7 +15746,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrink (vips_shrink) failed".to_string()),
+            Error::OperationError("Shrink (vips_shrink) failed".into()),
         )
     }
 
@@ -15268,7 +15773,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrinkh (vips_shrinkh) failed".to_string()),
+            Error::OperationError("Shrinkh (vips_shrinkh) failed".into()),
         )
     }
 
@@ -15280,7 +15785,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// ceil: `bool` -> Round-up output dimensions
-    pub fn shrinkh_with_opts(&self, hshrink: i32, option: VOption) -> Result<VipsImage> {
+    pub fn shrinkh_with_opts<'a>(
+        &self,
+        hshrink: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "shrinkh",
@@ -15299,7 +15809,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrinkh (vips_shrinkh) failed".to_string()),
+            Error::OperationError("Shrinkh (vips_shrinkh) failed".into()),
         )
     }
 
@@ -15326,7 +15836,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrinkv (vips_shrinkv) failed".to_string()),
+            Error::OperationError("Shrinkv (vips_shrinkv) failed".into()),
         )
     }
 
@@ -15338,7 +15848,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// ceil: `bool` -> Round-up output dimensions
-    pub fn shrinkv_with_opts(&self, vshrink: i32, option: VOption) -> Result<VipsImage> {
+    pub fn shrinkv_with_opts<'a>(
+        &self,
+        vshrink: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "shrinkv",
@@ -15357,7 +15872,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Shrinkv (vips_shrinkv) failed".to_string()),
+            Error::OperationError("Shrinkv (vips_shrinkv) failed".into()),
         )
     }
 
@@ -15378,7 +15893,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sign (vips_sign) failed".to_string()),
+            Error::OperationError("Sign (vips_sign) failed".into()),
         )
     }
 
@@ -15399,7 +15914,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Similarity (vips_similarity) failed".to_string()),
+            Error::OperationError("Similarity (vips_similarity) failed".into()),
         )
     }
 
@@ -15423,7 +15938,8 @@ impl VipsImage {
     /// idx: `f64` -> Horizontal input displacement
     ///
     /// idy: `f64` -> Vertical input displacement
-    pub fn similarity_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn similarity_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "similarity",
@@ -15438,7 +15954,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Similarity (vips_similarity) failed".to_string()),
+            Error::OperationError("Similarity (vips_similarity) failed".into()),
         )
     }
 
@@ -15470,7 +15986,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sines (vips_sines) failed".to_string()),
+            Error::OperationError("Sines (vips_sines) failed".into()),
         )
     }
 
@@ -15488,7 +16004,12 @@ impl VipsImage {
     /// hfreq: `f64` -> Horizontal spatial frequency
     ///
     /// vfreq: `f64` -> Vertical spatial frequency
-    pub fn sines_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn sines_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "sines",
@@ -15510,7 +16031,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sines (vips_sines) failed".to_string()),
+            Error::OperationError("Sines (vips_sines) failed".into()),
         )
     }
 
@@ -15546,7 +16067,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Smartcrop (vips_smartcrop) failed".to_string()),
+            Error::OperationError("Smartcrop (vips_smartcrop) failed".into()),
         )
     }
 
@@ -15566,12 +16087,13 @@ impl VipsImage {
     /// interesting: [`Interesting`] -> How to measure interestingness
     ///
     /// premultiplied: `bool` -> Input image already has premultiplied alpha
-    pub fn smartcrop_with_opts(
+    pub fn smartcrop_with_opts<'a>(
         &self,
         width: i32,
         height: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "smartcrop",
@@ -15597,7 +16119,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Smartcrop (vips_smartcrop) failed".to_string()),
+            Error::OperationError("Smartcrop (vips_smartcrop) failed".into()),
         )
     }
 
@@ -15618,7 +16140,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sobel (vips_sobel) failed".to_string()),
+            Error::OperationError("Sobel (vips_sobel) failed".into()),
         )
     }
 
@@ -15644,7 +16166,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Spcor (vips_spcor) failed".to_string()),
+            Error::OperationError("Spcor (vips_spcor) failed".into()),
         )
     }
 
@@ -15665,7 +16187,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Spectrum (vips_spectrum) failed".to_string()),
+            Error::OperationError("Spectrum (vips_spectrum) failed".into()),
         )
     }
 
@@ -15686,7 +16208,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Stats (vips_stats) failed".to_string()),
+            Error::OperationError("Stats (vips_stats) failed".into()),
         )
     }
 
@@ -15719,7 +16241,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Stdif (vips_stdif) failed".to_string()),
+            Error::OperationError("Stdif (vips_stdif) failed".into()),
         )
     }
 
@@ -15739,7 +16261,13 @@ impl VipsImage {
     /// m0: `f64` -> New mean
     ///
     /// a: `f64` -> Weight of new mean
-    pub fn stdif_with_opts(&self, width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn stdif_with_opts<'a>(
+        &self,
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "stdif",
@@ -15762,7 +16290,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Stdif (vips_stdif) failed".to_string()),
+            Error::OperationError("Stdif (vips_stdif) failed".into()),
         )
     }
 
@@ -15798,7 +16326,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Subsample (vips_subsample) failed".to_string()),
+            Error::OperationError("Subsample (vips_subsample) failed".into()),
         )
     }
 
@@ -15812,7 +16340,13 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// point: `bool` -> Point sample
-    pub fn subsample_with_opts(&self, xfac: i32, yfac: i32, option: VOption) -> Result<VipsImage> {
+    pub fn subsample_with_opts<'a>(
+        &self,
+        xfac: i32,
+        yfac: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "subsample",
@@ -15838,7 +16372,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Subsample (vips_subsample) failed".to_string()),
+            Error::OperationError("Subsample (vips_subsample) failed".into()),
         )
     }
 
@@ -15868,7 +16402,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Subtract (vips_subtract) failed".to_string()),
+            Error::OperationError("Subtract (vips_subtract) failed".into()),
         )
     }
 
@@ -15891,7 +16425,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Sum (vips_sum) failed".to_string()),
+            Error::OperationError("Sum (vips_sum) failed".into()),
         )
     }
 
@@ -15917,7 +16451,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Svgload (vips_svgload) failed".to_string()),
+            Error::OperationError("Svgload (vips_svgload) failed".into()),
         )
     }
 
@@ -15947,7 +16481,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn svgload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn svgload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "svgload",
@@ -15965,7 +16503,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Svgload (vips_svgload) failed".to_string()),
+            Error::OperationError("Svgload (vips_svgload) failed".into()),
         )
     }
 
@@ -15975,8 +16513,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn svgload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -15995,11 +16532,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SvgloadBuffer (vips_svgload_buffer) failed".to_string()),
+            Error::OperationError("SvgloadBuffer (vips_svgload_buffer) failed".into()),
         )
     }
 
@@ -16029,10 +16566,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn svgload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn svgload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -16051,11 +16591,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SvgloadBuffer (vips_svgload_buffer) failed".to_string()),
+            Error::OperationError("SvgloadBuffer (vips_svgload_buffer) failed".into()),
         )
     }
 
@@ -16081,7 +16621,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SvgloadSource (vips_svgload_source) failed".to_string()),
+            Error::OperationError("SvgloadSource (vips_svgload_source) failed".into()),
         )
     }
 
@@ -16111,7 +16651,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn svgload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn svgload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "svgload_source",
@@ -16129,7 +16673,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("SvgloadSource (vips_svgload_source) failed".to_string()),
+            Error::OperationError("SvgloadSource (vips_svgload_source) failed".into()),
         )
     }
 
@@ -16155,7 +16699,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Switch (vips_switch) failed".to_string()),
+            Error::OperationError("Switch (vips_switch) failed".into()),
         )
     }
 
@@ -16174,7 +16718,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("System (vips_system) failed".to_string()),
+            Error::OperationError("System (vips_system) failed".into()),
         )
     }
 
@@ -16188,12 +16732,13 @@ impl VipsImage {
     ///
     /// out: `&mut VipsImage` -> Output image
     ///
-    /// log: `&str` -> Command log
+    /// log: `&mut String` -> Command log
     ///
     /// out_format: `&str` -> Format for output filename
     ///
     /// in_format: `&str` -> Format for input filename
-    pub fn system_with_opts(cmd_format: &str, option: VOption) -> Result<()> {
+    pub fn system_with_opts<'a>(cmd_format: &str, option: impl Into<VOption<'a>>) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "system",
             option.set(
@@ -16205,7 +16750,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("System (vips_system) failed".to_string()),
+            Error::OperationError("System (vips_system) failed".into()),
         )
     }
 
@@ -16231,7 +16776,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Text (vips_text) failed".to_string()),
+            Error::OperationError("Text (vips_text) failed".into()),
         )
     }
 
@@ -16263,7 +16808,8 @@ impl VipsImage {
     /// rgba: `bool` -> Enable RGBA output
     ///
     /// wrap: [`TextWrap`] -> Wrap lines on word or character boundaries
-    pub fn text_with_opts(text: &str, option: VOption) -> Result<VipsImage> {
+    pub fn text_with_opts<'a>(text: &str, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "text",
@@ -16281,7 +16827,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Text (vips_text) failed".to_string()),
+            Error::OperationError("Text (vips_text) failed".into()),
         )
     }
 
@@ -16313,7 +16859,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Thumbnail (vips_thumbnail) failed".to_string()),
+            Error::OperationError("Thumbnail (vips_thumbnail) failed".into()),
         )
     }
 
@@ -16343,7 +16889,12 @@ impl VipsImage {
     /// intent: [`Intent`] -> Rendering intent
     ///
     /// fail_on: [`FailOn`] -> Error level to fail on
-    pub fn thumbnail_with_opts(filename: &str, width: i32, option: VOption) -> Result<VipsImage> {
+    pub fn thumbnail_with_opts<'a>(
+        filename: &str,
+        width: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "thumbnail",
@@ -16365,7 +16916,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Thumbnail (vips_thumbnail) failed".to_string()),
+            Error::OperationError("Thumbnail (vips_thumbnail) failed".into()),
         )
     }
 
@@ -16377,8 +16928,7 @@ impl VipsImage {
     /// width: `i32` -> Size to this width
     pub fn thumbnail_buffer(buffer: &[u8], width: i32) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -16401,11 +16951,11 @@ impl VipsImage {
                     width,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailBuffer (vips_thumbnail_buffer) failed".to_string()),
+            Error::OperationError("ThumbnailBuffer (vips_thumbnail_buffer) failed".into()),
         )
     }
 
@@ -16437,14 +16987,14 @@ impl VipsImage {
     /// intent: [`Intent`] -> Rendering intent
     ///
     /// fail_on: [`FailOn`] -> Error level to fail on
-    pub fn thumbnail_buffer_with_opts(
+    pub fn thumbnail_buffer_with_opts<'a>(
         buffer: &[u8],
         width: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -16467,11 +17017,11 @@ impl VipsImage {
                     width,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailBuffer (vips_thumbnail_buffer) failed".to_string()),
+            Error::OperationError("ThumbnailBuffer (vips_thumbnail_buffer) failed".into()),
         )
     }
 
@@ -16498,7 +17048,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailImage (vips_thumbnail_image) failed".to_string()),
+            Error::OperationError("ThumbnailImage (vips_thumbnail_image) failed".into()),
         )
     }
 
@@ -16526,7 +17076,12 @@ impl VipsImage {
     /// intent: [`Intent`] -> Rendering intent
     ///
     /// fail_on: [`FailOn`] -> Error level to fail on
-    pub fn thumbnail_image_with_opts(&self, width: i32, option: VOption) -> Result<VipsImage> {
+    pub fn thumbnail_image_with_opts<'a>(
+        &self,
+        width: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "thumbnail_image",
@@ -16545,7 +17100,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailImage (vips_thumbnail_image) failed".to_string()),
+            Error::OperationError("ThumbnailImage (vips_thumbnail_image) failed".into()),
         )
     }
 
@@ -16577,7 +17132,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailSource (vips_thumbnail_source) failed".to_string()),
+            Error::OperationError("ThumbnailSource (vips_thumbnail_source) failed".into()),
         )
     }
 
@@ -16609,11 +17164,12 @@ impl VipsImage {
     /// intent: [`Intent`] -> Rendering intent
     ///
     /// fail_on: [`FailOn`] -> Error level to fail on
-    pub fn thumbnail_source_with_opts(
+    pub fn thumbnail_source_with_opts<'a>(
         source: &VipsSource,
         width: i32,
-        option: VOption,
+        option: impl Into<VOption<'a>>,
     ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "thumbnail_source",
@@ -16635,7 +17191,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("ThumbnailSource (vips_thumbnail_source) failed".to_string()),
+            Error::OperationError("ThumbnailSource (vips_thumbnail_source) failed".into()),
         )
     }
 
@@ -16661,7 +17217,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tiffload (vips_tiffload) failed".to_string()),
+            Error::OperationError("Tiffload (vips_tiffload) failed".into()),
         )
     }
 
@@ -16691,7 +17247,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn tiffload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn tiffload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "tiffload",
@@ -16709,7 +17269,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tiffload (vips_tiffload) failed".to_string()),
+            Error::OperationError("Tiffload (vips_tiffload) failed".into()),
         )
     }
 
@@ -16719,8 +17279,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn tiffload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -16739,11 +17298,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("TiffloadBuffer (vips_tiffload_buffer) failed".to_string()),
+            Error::OperationError("TiffloadBuffer (vips_tiffload_buffer) failed".into()),
         )
     }
 
@@ -16773,10 +17332,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn tiffload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn tiffload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -16795,11 +17357,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("TiffloadBuffer (vips_tiffload_buffer) failed".to_string()),
+            Error::OperationError("TiffloadBuffer (vips_tiffload_buffer) failed".into()),
         )
     }
 
@@ -16825,7 +17387,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("TiffloadSource (vips_tiffload_source) failed".to_string()),
+            Error::OperationError("TiffloadSource (vips_tiffload_source) failed".into()),
         )
     }
 
@@ -16855,7 +17417,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn tiffload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn tiffload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "tiffload_source",
@@ -16873,7 +17439,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("TiffloadSource (vips_tiffload_source) failed".to_string()),
+            Error::OperationError("TiffloadSource (vips_tiffload_source) failed".into()),
         )
     }
 
@@ -16894,7 +17460,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Tiffsave (vips_tiffsave) failed".to_string()),
+            Error::OperationError("Tiffsave (vips_tiffsave) failed".into()),
         )
     }
 
@@ -16951,7 +17517,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn tiffsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn tiffsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "tiffsave",
             option
@@ -16965,7 +17536,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Tiffsave (vips_tiffsave) failed".to_string()),
+            Error::OperationError("Tiffsave (vips_tiffsave) failed".into()),
         )
     }
 
@@ -16986,7 +17557,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("TiffsaveBuffer (vips_tiffsave_buffer) failed".to_string()),
+            Error::OperationError("TiffsaveBuffer (vips_tiffsave_buffer) failed".into()),
         )
     }
 
@@ -17042,7 +17613,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn tiffsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn tiffsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "tiffsave_buffer",
@@ -17057,7 +17629,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("TiffsaveBuffer (vips_tiffsave_buffer) failed".to_string()),
+            Error::OperationError("TiffsaveBuffer (vips_tiffsave_buffer) failed".into()),
         )
     }
 
@@ -17078,7 +17650,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("TiffsaveTarget (vips_tiffsave_target) failed".to_string()),
+            Error::OperationError("TiffsaveTarget (vips_tiffsave_target) failed".into()),
         )
     }
 
@@ -17135,7 +17707,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn tiffsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn tiffsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "tiffsave_target",
             option
@@ -17149,7 +17726,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("TiffsaveTarget (vips_tiffsave_target) failed".to_string()),
+            Error::OperationError("TiffsaveTarget (vips_tiffsave_target) failed".into()),
         )
     }
 
@@ -17170,7 +17747,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tilecache (vips_tilecache) failed".to_string()),
+            Error::OperationError("Tilecache (vips_tilecache) failed".into()),
         )
     }
 
@@ -17190,7 +17767,8 @@ impl VipsImage {
     /// threaded: `bool` -> Allow threaded access
     ///
     /// persistent: `bool` -> Keep cache between evaluations
-    pub fn tilecache_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn tilecache_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "tilecache",
@@ -17205,7 +17783,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tilecache (vips_tilecache) failed".to_string()),
+            Error::OperationError("Tilecache (vips_tilecache) failed".into()),
         )
     }
 
@@ -17224,7 +17802,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tonelut (vips_tonelut) failed".to_string()),
+            Error::OperationError("Tonelut (vips_tonelut) failed".into()),
         )
     }
 
@@ -17252,7 +17830,8 @@ impl VipsImage {
     /// M: `f64` -> Adjust mid-tones by this much
     ///
     /// H: `f64` -> Adjust highlights by this much
-    pub fn tonelut_with_opts(option: VOption) -> Result<VipsImage> {
+    pub fn tonelut_with_opts<'a>(option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "tonelut",
@@ -17265,7 +17844,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Tonelut (vips_tonelut) failed".to_string()),
+            Error::OperationError("Tonelut (vips_tonelut) failed".into()),
         )
     }
 
@@ -17286,7 +17865,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Transpose3D (vips_transpose3d) failed".to_string()),
+            Error::OperationError("Transpose3D (vips_transpose3d) failed".into()),
         )
     }
 
@@ -17296,7 +17875,8 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// page_height: `i32` -> Height of each input page
-    pub fn transpose3d_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn transpose3d_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "transpose3d",
@@ -17311,7 +17891,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Transpose3D (vips_transpose3d) failed".to_string()),
+            Error::OperationError("Transpose3D (vips_transpose3d) failed".into()),
         )
     }
 
@@ -17332,7 +17912,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Unpremultiply (vips_unpremultiply) failed".to_string()),
+            Error::OperationError("Unpremultiply (vips_unpremultiply) failed".into()),
         )
     }
 
@@ -17344,7 +17924,8 @@ impl VipsImage {
     /// max_alpha: `f64` -> Maximum value of alpha channel
     ///
     /// alpha_band: `i32` -> Unpremultiply with this alpha
-    pub fn unpremultiply_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn unpremultiply_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "unpremultiply",
@@ -17359,7 +17940,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Unpremultiply (vips_unpremultiply) failed".to_string()),
+            Error::OperationError("Unpremultiply (vips_unpremultiply) failed".into()),
         )
     }
 
@@ -17385,7 +17966,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Vipsload (vips_vipsload) failed".to_string()),
+            Error::OperationError("Vipsload (vips_vipsload) failed".into()),
         )
     }
 
@@ -17405,7 +17986,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn vipsload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn vipsload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "vipsload",
@@ -17423,7 +18008,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Vipsload (vips_vipsload) failed".to_string()),
+            Error::OperationError("Vipsload (vips_vipsload) failed".into()),
         )
     }
 
@@ -17449,7 +18034,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("VipsloadSource (vips_vipsload_source) failed".to_string()),
+            Error::OperationError("VipsloadSource (vips_vipsload_source) failed".into()),
         )
     }
 
@@ -17469,7 +18054,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn vipsload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn vipsload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "vipsload_source",
@@ -17487,7 +18076,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("VipsloadSource (vips_vipsload_source) failed".to_string()),
+            Error::OperationError("VipsloadSource (vips_vipsload_source) failed".into()),
         )
     }
 
@@ -17508,7 +18097,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Vipssave (vips_vipssave) failed".to_string()),
+            Error::OperationError("Vipssave (vips_vipssave) failed".into()),
         )
     }
 
@@ -17525,7 +18114,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn vipssave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn vipssave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "vipssave",
             option
@@ -17539,7 +18133,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Vipssave (vips_vipssave) failed".to_string()),
+            Error::OperationError("Vipssave (vips_vipssave) failed".into()),
         )
     }
 
@@ -17560,7 +18154,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("VipssaveTarget (vips_vipssave_target) failed".to_string()),
+            Error::OperationError("VipssaveTarget (vips_vipssave_target) failed".into()),
         )
     }
 
@@ -17577,7 +18171,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn vipssave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn vipssave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "vipssave_target",
             option
@@ -17591,7 +18190,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("VipssaveTarget (vips_vipssave_target) failed".to_string()),
+            Error::OperationError("VipssaveTarget (vips_vipssave_target) failed".into()),
         )
     }
 
@@ -17617,7 +18216,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Webpload (vips_webpload) failed".to_string()),
+            Error::OperationError("Webpload (vips_webpload) failed".into()),
         )
     }
 
@@ -17643,7 +18242,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn webpload_with_opts(filename: &str, option: VOption) -> Result<VipsImage> {
+    pub fn webpload_with_opts<'a>(
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "webpload",
@@ -17661,7 +18264,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Webpload (vips_webpload) failed".to_string()),
+            Error::OperationError("Webpload (vips_webpload) failed".into()),
         )
     }
 
@@ -17671,8 +18274,7 @@ impl VipsImage {
     /// buffer: `&[u8]` -> Buffer to load from
     pub fn webpload_buffer(buffer: &[u8]) -> Result<VipsImage> {
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -17691,11 +18293,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("WebploadBuffer (vips_webpload_buffer) failed".to_string()),
+            Error::OperationError("WebploadBuffer (vips_webpload_buffer) failed".into()),
         )
     }
 
@@ -17721,10 +18323,13 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn webpload_buffer_with_opts(buffer: &[u8], option: VOption) -> Result<VipsImage> {
+    pub fn webpload_buffer_with_opts<'a>(
+        buffer: &[u8],
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let vips_blob = unsafe {
-            vips_blob_new(
-                None,
+            vips_blob_copy(
                 buffer.as_ptr() as _,
                 buffer.len() as _,
             )
@@ -17743,11 +18348,11 @@ impl VipsImage {
                     &mut out_out,
                 ),
         );
-        blob.area_unref();
+        drop(blob);
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("WebploadBuffer (vips_webpload_buffer) failed".to_string()),
+            Error::OperationError("WebploadBuffer (vips_webpload_buffer) failed".into()),
         )
     }
 
@@ -17773,7 +18378,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("WebploadSource (vips_webpload_source) failed".to_string()),
+            Error::OperationError("WebploadSource (vips_webpload_source) failed".into()),
         )
     }
 
@@ -17799,7 +18404,11 @@ impl VipsImage {
     /// fail_on: [`FailOn`] -> Error level to fail on
     ///
     /// revalidate: `bool` -> Don't use a cached result for this operation
-    pub fn webpload_source_with_opts(source: &VipsSource, option: VOption) -> Result<VipsImage> {
+    pub fn webpload_source_with_opts<'a>(
+        source: &VipsSource,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "webpload_source",
@@ -17817,7 +18426,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("WebploadSource (vips_webpload_source) failed".to_string()),
+            Error::OperationError("WebploadSource (vips_webpload_source) failed".into()),
         )
     }
 
@@ -17838,7 +18447,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Webpsave (vips_webpsave) failed".to_string()),
+            Error::OperationError("Webpsave (vips_webpsave) failed".into()),
         )
     }
 
@@ -17883,7 +18492,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn webpsave_with_opts(&self, filename: &str, option: VOption) -> Result<()> {
+    pub fn webpsave_with_opts<'a>(
+        &self,
+        filename: &str,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "webpsave",
             option
@@ -17897,7 +18511,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("Webpsave (vips_webpsave) failed".to_string()),
+            Error::OperationError("Webpsave (vips_webpsave) failed".into()),
         )
     }
 
@@ -17918,7 +18532,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("WebpsaveBuffer (vips_webpsave_buffer) failed".to_string()),
+            Error::OperationError("WebpsaveBuffer (vips_webpsave_buffer) failed".into()),
         )
     }
 
@@ -17962,7 +18576,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn webpsave_buffer_with_opts(&self, option: VOption) -> Result<Vec<u8>> {
+    pub fn webpsave_buffer_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<Vec<u8>> {
+        let option: VOption = option.into();
         let mut buffer_out = VipsBlob::from(null_mut());
         let vips_op_response = call(
             "webpsave_buffer",
@@ -17977,7 +18592,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             buffer_out.into(),
-            Error::OperationError("WebpsaveBuffer (vips_webpsave_buffer) failed".to_string()),
+            Error::OperationError("WebpsaveBuffer (vips_webpsave_buffer) failed".into()),
         )
     }
 
@@ -17991,7 +18606,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("WebpsaveMime (vips_webpsave_mime) failed".to_string()),
+            Error::OperationError("WebpsaveMime (vips_webpsave_mime) failed".into()),
         )
     }
 
@@ -18034,7 +18649,8 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn webpsave_mime_with_opts(&self, option: VOption) -> Result<()> {
+    pub fn webpsave_mime_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "webpsave_mime",
             option.set("in", self),
@@ -18043,7 +18659,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("WebpsaveMime (vips_webpsave_mime) failed".to_string()),
+            Error::OperationError("WebpsaveMime (vips_webpsave_mime) failed".into()),
         )
     }
 
@@ -18064,7 +18680,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("WebpsaveTarget (vips_webpsave_target) failed".to_string()),
+            Error::OperationError("WebpsaveTarget (vips_webpsave_target) failed".into()),
         )
     }
 
@@ -18109,7 +18725,12 @@ impl VipsImage {
     /// page_height: `i32` -> Set page height for multipage save
     ///
     /// profile: `&str` -> Filename of ICC profile to embed
-    pub fn webpsave_target_with_opts(&self, target: &VipsTarget, option: VOption) -> Result<()> {
+    pub fn webpsave_target_with_opts<'a>(
+        &self,
+        target: &VipsTarget,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<()> {
+        let option: VOption = option.into();
         let vips_op_response = call(
             "webpsave_target",
             option
@@ -18123,7 +18744,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             (),
-            Error::OperationError("WebpsaveTarget (vips_webpsave_target) failed".to_string()),
+            Error::OperationError("WebpsaveTarget (vips_webpsave_target) failed".into()),
         )
     }
 
@@ -18155,7 +18776,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Worley (vips_worley) failed".to_string()),
+            Error::OperationError("Worley (vips_worley) failed".into()),
         )
     }
 
@@ -18171,7 +18792,12 @@ impl VipsImage {
     /// cell_size: `i32` -> Size of Worley cells
     ///
     /// seed: `i32` -> Random number seed
-    pub fn worley_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn worley_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "worley",
@@ -18193,7 +18819,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Worley (vips_worley) failed".to_string()),
+            Error::OperationError("Worley (vips_worley) failed".into()),
         )
     }
 
@@ -18214,7 +18840,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Wrap (vips_wrap) failed".to_string()),
+            Error::OperationError("Wrap (vips_wrap) failed".into()),
         )
     }
 
@@ -18226,7 +18852,8 @@ impl VipsImage {
     /// x: `i32` -> Left edge of input in output
     ///
     /// y: `i32` -> Top edge of input in output
-    pub fn wrap_with_opts(&self, option: VOption) -> Result<VipsImage> {
+    pub fn wrap_with_opts<'a>(&self, option: impl Into<VOption<'a>>) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "wrap",
@@ -18241,7 +18868,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Wrap (vips_wrap) failed".to_string()),
+            Error::OperationError("Wrap (vips_wrap) failed".into()),
         )
     }
 
@@ -18273,7 +18900,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz (vips_xyz) failed".to_string()),
+            Error::OperationError("Xyz (vips_xyz) failed".into()),
         )
     }
 
@@ -18291,7 +18918,12 @@ impl VipsImage {
     /// dsize: `i32` -> Size of fourth dimension
     ///
     /// esize: `i32` -> Size of fifth dimension
-    pub fn xyz_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn xyz_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "xyz",
@@ -18313,7 +18945,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Xyz (vips_xyz) failed".to_string()),
+            Error::OperationError("Xyz (vips_xyz) failed".into()),
         )
     }
 
@@ -18345,7 +18977,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Zone (vips_zone) failed".to_string()),
+            Error::OperationError("Zone (vips_zone) failed".into()),
         )
     }
 
@@ -18359,7 +18991,12 @@ impl VipsImage {
     /// <ins>Optional arguments</ins>
     ///
     /// uchar: `bool` -> Output an unsigned char image
-    pub fn zone_with_opts(width: i32, height: i32, option: VOption) -> Result<VipsImage> {
+    pub fn zone_with_opts<'a>(
+        width: i32,
+        height: i32,
+        option: impl Into<VOption<'a>>,
+    ) -> Result<VipsImage> {
+        let option: VOption = option.into();
         let mut out_out = VipsImage::from(null_mut());
         let vips_op_response = call(
             "zone",
@@ -18381,7 +19018,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Zone (vips_zone) failed".to_string()),
+            Error::OperationError("Zone (vips_zone) failed".into()),
         )
     }
 
@@ -18417,7 +19054,7 @@ impl VipsImage {
         utils::result(
             vips_op_response,
             out_out,
-            Error::OperationError("Zoom (vips_zoom) failed".to_string()),
+            Error::OperationError("Zoom (vips_zoom) failed".into()),
         )
     }
 
@@ -0,0 +1,20723 @@
+// (c) Copyright 2025 mrdkprj
+// Generated by generator/options.py from the docs in ops.rs, do not edit by hand.
+use crate::{
+    ops::{
+        Access, Align, Angle, Angle45, BandFormat, Coding, Combine, CombineMode, Extend, FailOn,
+        ForeignDzContainer, ForeignDzDepth, ForeignDzLayout, ForeignFlags, ForeignHeifCompression,
+        ForeignHeifEncoder, ForeignKeep, ForeignPngFilter, ForeignPpmFormat, ForeignSubsample,
+        ForeignTiffCompression, ForeignTiffPredictor, ForeignTiffResunit, ForeignWebpPreset,
+        Intent, Interesting, Interpretation, Kernel, Precision, RegionShrink, Size, TextWrap, PCS,
+    },
+    voption::{Setter, VOption},
+    VipsImage, VipsInterpolate,
+};
+
+/// Optional arguments of Lab2XYZ, for [`VipsImage::Lab2XYZ_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Lab2XYZOptions {
+    /// Color temperature
+    pub temp: Option<Vec<f64>>,
+}
+
+impl Lab2XYZOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color temperature
+    pub fn temp(mut self, temp: &[f64]) -> Self {
+        self.temp = Some(temp.to_vec());
+        self
+    }
+}
+
+impl From<Lab2XYZOptions> for VOption<'_> {
+    fn from(options: Lab2XYZOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.temp {
+            option.add(
+                "temp",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of XYZ2Lab, for [`VipsImage::XYZ2Lab_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct XYZ2LabOptions {
+    /// Colour temperature
+    pub temp: Option<Vec<f64>>,
+}
+
+impl XYZ2LabOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Colour temperature
+    pub fn temp(mut self, temp: &[f64]) -> Self {
+        self.temp = Some(temp.to_vec());
+        self
+    }
+}
+
+impl From<XYZ2LabOptions> for VOption<'_> {
+    fn from(options: XYZ2LabOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.temp {
+            option.add(
+                "temp",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of affine, for [`VipsImage::affine_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AffineOptions {
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+    /// Area of output to generate
+    pub oarea: Option<Vec<i32>>,
+    /// Horizontal output displacement
+    pub odx: Option<f64>,
+    /// Vertical output displacement
+    pub ody: Option<f64>,
+    /// Horizontal input displacement
+    pub idx: Option<f64>,
+    /// Vertical input displacement
+    pub idy: Option<f64>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Images have premultiplied alpha
+    pub premultiplied: Option<bool>,
+    /// How to generate the extra pixels
+    pub extend: Option<Extend>,
+}
+
+impl AffineOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+
+    /// Area of output to generate
+    pub fn oarea(mut self, oarea: &[i32]) -> Self {
+        self.oarea = Some(oarea.to_vec());
+        self
+    }
+
+    /// Horizontal output displacement
+    pub fn odx(mut self, odx: f64) -> Self {
+        self.odx = Some(odx);
+        self
+    }
+
+    /// Vertical output displacement
+    pub fn ody(mut self, ody: f64) -> Self {
+        self.ody = Some(ody);
+        self
+    }
+
+    /// Horizontal input displacement
+    pub fn idx(mut self, idx: f64) -> Self {
+        self.idx = Some(idx);
+        self
+    }
+
+    /// Vertical input displacement
+    pub fn idy(mut self, idy: f64) -> Self {
+        self.idy = Some(idy);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Images have premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = Some(premultiplied);
+        self
+    }
+
+    /// How to generate the extra pixels
+    pub fn extend(mut self, extend: Extend) -> Self {
+        self.extend = Some(extend);
+        self
+    }
+}
+
+impl From<AffineOptions> for VOption<'_> {
+    fn from(options: AffineOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        if let Some(value) = options.oarea {
+            option.add(
+                "oarea",
+                value,
+            );
+        }
+        if let Some(value) = options.odx {
+            option.add(
+                "odx", value,
+            );
+        }
+        if let Some(value) = options.ody {
+            option.add(
+                "ody", value,
+            );
+        }
+        if let Some(value) = options.idx {
+            option.add(
+                "idx", value,
+            );
+        }
+        if let Some(value) = options.idy {
+            option.add(
+                "idy", value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiplied {
+            option.add(
+                "premultiplied",
+                value,
+            );
+        }
+        if let Some(value) = options.extend {
+            option.add(
+                "extend",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of analyzeload, for [`VipsImage::analyzeload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzeloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl AnalyzeloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<AnalyzeloadOptions> for VOption<'_> {
+    fn from(options: AnalyzeloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of arrayjoin, for [`VipsImage::arrayjoin_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ArrayjoinOptions {
+    /// Number of images across grid
+    pub across: Option<i32>,
+    /// Pixels between images
+    pub shim: Option<i32>,
+    /// Colour for new pixels
+    pub background: Option<Vec<f64>>,
+    /// Align on the left, centre or right
+    pub halign: Option<Align>,
+    /// Align on the top, centre or bottom
+    pub valign: Option<Align>,
+    /// Horizontal spacing between images
+    pub hspacing: Option<i32>,
+    /// Vertical spacing between images
+    pub vspacing: Option<i32>,
+}
+
+impl ArrayjoinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of images across grid
+    pub fn across(mut self, across: i32) -> Self {
+        self.across = Some(across);
+        self
+    }
+
+    /// Pixels between images
+    pub fn shim(mut self, shim: i32) -> Self {
+        self.shim = Some(shim);
+        self
+    }
+
+    /// Colour for new pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Align on the left, centre or right
+    pub fn halign(mut self, halign: Align) -> Self {
+        self.halign = Some(halign);
+        self
+    }
+
+    /// Align on the top, centre or bottom
+    pub fn valign(mut self, valign: Align) -> Self {
+        self.valign = Some(valign);
+        self
+    }
+
+    /// Horizontal spacing between images
+    pub fn hspacing(mut self, hspacing: i32) -> Self {
+        self.hspacing = Some(hspacing);
+        self
+    }
+
+    /// Vertical spacing between images
+    pub fn vspacing(mut self, vspacing: i32) -> Self {
+        self.vspacing = Some(vspacing);
+        self
+    }
+}
+
+impl From<ArrayjoinOptions> for VOption<'_> {
+    fn from(options: ArrayjoinOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.across {
+            option.add(
+                "across",
+                value,
+            );
+        }
+        if let Some(value) = options.shim {
+            option.add(
+                "shim",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.halign {
+            option.add(
+                "halign",
+                value,
+            );
+        }
+        if let Some(value) = options.valign {
+            option.add(
+                "valign",
+                value,
+            );
+        }
+        if let Some(value) = options.hspacing {
+            option.add(
+                "hspacing",
+                value,
+            );
+        }
+        if let Some(value) = options.vspacing {
+            option.add(
+                "vspacing",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of autorot, for [`VipsImage::autorot_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AutorotOptions {
+    /// Angle image was rotated by
+    pub angle: Option<Angle>,
+}
+
+impl AutorotOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Angle image was rotated by
+    pub fn angle(mut self, angle: Angle) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+}
+
+impl From<AutorotOptions> for VOption<'_> {
+    fn from(options: AutorotOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of bandfold, for [`VipsImage::bandfold_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct BandfoldOptions {
+    /// Fold by this factor
+    pub factor: Option<i32>,
+}
+
+impl BandfoldOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold by this factor
+    pub fn factor(mut self, factor: i32) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+}
+
+impl From<BandfoldOptions> for VOption<'_> {
+    fn from(options: BandfoldOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.factor {
+            option.add(
+                "factor",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of bandrank, for [`VipsImage::bandrank_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct BandrankOptions {
+    /// Select this band element from sorted list
+    pub index: Option<i32>,
+}
+
+impl BandrankOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select this band element from sorted list
+    pub fn index(mut self, index: i32) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+impl From<BandrankOptions> for VOption<'_> {
+    fn from(options: BandrankOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.index {
+            option.add(
+                "index",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of bandunfold, for [`VipsImage::bandunfold_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct BandunfoldOptions {
+    /// Unfold by this factor
+    pub factor: Option<i32>,
+}
+
+impl BandunfoldOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unfold by this factor
+    pub fn factor(mut self, factor: i32) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+}
+
+impl From<BandunfoldOptions> for VOption<'_> {
+    fn from(options: BandunfoldOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.factor {
+            option.add(
+                "factor",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of black, for [`VipsImage::black_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct BlackOptions {
+    /// Number of bands in image
+    pub bands: Option<i32>,
+}
+
+impl BlackOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bands in image
+    pub fn bands(mut self, bands: i32) -> Self {
+        self.bands = Some(bands);
+        self
+    }
+}
+
+impl From<BlackOptions> for VOption<'_> {
+    fn from(options: BlackOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.bands {
+            option.add(
+                "bands",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of canny, for [`VipsImage::canny_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CannyOptions {
+    /// Sigma of Gaussian
+    pub sigma: Option<f64>,
+    /// Convolve with this precision
+    pub precision: Option<Precision>,
+}
+
+impl CannyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sigma of Gaussian
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = Some(sigma);
+        self
+    }
+
+    /// Convolve with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl From<CannyOptions> for VOption<'_> {
+    fn from(options: CannyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.sigma {
+            option.add(
+                "sigma",
+                value,
+            );
+        }
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of cast, for [`VipsImage::cast_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CastOptions {
+    /// Shift integer values up and down
+    pub shift: Option<bool>,
+}
+
+impl CastOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shift integer values up and down
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = Some(shift);
+        self
+    }
+}
+
+impl From<CastOptions> for VOption<'_> {
+    fn from(options: CastOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.shift {
+            option.add(
+                "shift",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of clamp, for [`VipsImage::clamp_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ClampOptions {
+    /// Minimum value
+    pub min: Option<f64>,
+    /// Maximum value
+    pub max: Option<f64>,
+}
+
+impl ClampOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum value
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Maximum value
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl From<ClampOptions> for VOption<'_> {
+    fn from(options: ClampOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.min {
+            option.add(
+                "min", value,
+            );
+        }
+        if let Some(value) = options.max {
+            option.add(
+                "max", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of colourspace, for [`VipsImage::colourspace_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ColourspaceOptions {
+    /// Source color space
+    pub source_space: Option<Interpretation>,
+}
+
+impl ColourspaceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Source color space
+    pub fn source_space(mut self, source_space: Interpretation) -> Self {
+        self.source_space = Some(source_space);
+        self
+    }
+}
+
+impl From<ColourspaceOptions> for VOption<'_> {
+    fn from(options: ColourspaceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.source_space {
+            option.add(
+                "source_space",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of compass, for [`VipsImage::compass_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CompassOptions {
+    /// Rotate and convolve this many times
+    pub times: Option<i32>,
+    /// Rotate mask by this much between convolutions
+    pub angle: Option<Angle45>,
+    /// Combine convolution results like this
+    pub combine: Option<Combine>,
+    /// Convolve with this precision
+    pub precision: Option<Precision>,
+    /// Use this many layers in approximation
+    pub layers: Option<i32>,
+    /// Cluster lines closer than this in approximation
+    pub cluster: Option<i32>,
+}
+
+impl CompassOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rotate and convolve this many times
+    pub fn times(mut self, times: i32) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Rotate mask by this much between convolutions
+    pub fn angle(mut self, angle: Angle45) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Combine convolution results like this
+    pub fn combine(mut self, combine: Combine) -> Self {
+        self.combine = Some(combine);
+        self
+    }
+
+    /// Convolve with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Use this many layers in approximation
+    pub fn layers(mut self, layers: i32) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Cluster lines closer than this in approximation
+    pub fn cluster(mut self, cluster: i32) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+}
+
+impl From<CompassOptions> for VOption<'_> {
+    fn from(options: CompassOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.times {
+            option.add(
+                "times",
+                value,
+            );
+        }
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        if let Some(value) = options.combine {
+            option.add(
+                "combine",
+                value,
+            );
+        }
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        if let Some(value) = options.layers {
+            option.add(
+                "layers",
+                value,
+            );
+        }
+        if let Some(value) = options.cluster {
+            option.add(
+                "cluster",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of composite2, for [`VipsImage::composite2_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Composite2Options {
+    /// x position of overlay
+    pub x: Option<i32>,
+    /// y position of overlay
+    pub y: Option<i32>,
+    /// Composite images in this colour space
+    pub compositing_space: Option<Interpretation>,
+    /// Images have premultiplied alpha
+    pub premultiplied: Option<bool>,
+}
+
+impl Composite2Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// x position of overlay
+    pub fn x(mut self, x: i32) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// y position of overlay
+    pub fn y(mut self, y: i32) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Composite images in this colour space
+    pub fn compositing_space(mut self, compositing_space: Interpretation) -> Self {
+        self.compositing_space = Some(compositing_space);
+        self
+    }
+
+    /// Images have premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = Some(premultiplied);
+        self
+    }
+}
+
+impl From<Composite2Options> for VOption<'_> {
+    fn from(options: Composite2Options) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.x {
+            option.add("x", value);
+        }
+        if let Some(value) = options.y {
+            option.add("y", value);
+        }
+        if let Some(value) = options.compositing_space {
+            option.add(
+                "compositing_space",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiplied {
+            option.add(
+                "premultiplied",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of composite, for [`VipsImage::composite_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CompositeOptions {
+    /// Array of x coordinates to join at
+    pub x: Option<Vec<i32>>,
+    /// Array of y coordinates to join at
+    pub y: Option<Vec<i32>>,
+    /// Composite images in this colour space
+    pub compositing_space: Option<Interpretation>,
+    /// Images have premultiplied alpha
+    pub premultiplied: Option<bool>,
+}
+
+impl CompositeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Array of x coordinates to join at
+    pub fn x(mut self, x: &[i32]) -> Self {
+        self.x = Some(x.to_vec());
+        self
+    }
+
+    /// Array of y coordinates to join at
+    pub fn y(mut self, y: &[i32]) -> Self {
+        self.y = Some(y.to_vec());
+        self
+    }
+
+    /// Composite images in this colour space
+    pub fn compositing_space(mut self, compositing_space: Interpretation) -> Self {
+        self.compositing_space = Some(compositing_space);
+        self
+    }
+
+    /// Images have premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = Some(premultiplied);
+        self
+    }
+}
+
+impl From<CompositeOptions> for VOption<'_> {
+    fn from(options: CompositeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.x {
+            option.add("x", value);
+        }
+        if let Some(value) = options.y {
+            option.add("y", value);
+        }
+        if let Some(value) = options.compositing_space {
+            option.add(
+                "compositing_space",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiplied {
+            option.add(
+                "premultiplied",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of conv, for [`VipsImage::conv_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConvOptions {
+    /// Convolve with this precision
+    pub precision: Option<Precision>,
+    /// Use this many layers in approximation
+    pub layers: Option<i32>,
+    /// Cluster lines closer than this in approximation
+    pub cluster: Option<i32>,
+}
+
+impl ConvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convolve with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Use this many layers in approximation
+    pub fn layers(mut self, layers: i32) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Cluster lines closer than this in approximation
+    pub fn cluster(mut self, cluster: i32) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+}
+
+impl From<ConvOptions> for VOption<'_> {
+    fn from(options: ConvOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        if let Some(value) = options.layers {
+            option.add(
+                "layers",
+                value,
+            );
+        }
+        if let Some(value) = options.cluster {
+            option.add(
+                "cluster",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of conva, for [`VipsImage::conva_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConvaOptions {
+    /// Use this many layers in approximation
+    pub layers: Option<i32>,
+    /// Cluster lines closer than this in approximation
+    pub cluster: Option<i32>,
+}
+
+impl ConvaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use this many layers in approximation
+    pub fn layers(mut self, layers: i32) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Cluster lines closer than this in approximation
+    pub fn cluster(mut self, cluster: i32) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+}
+
+impl From<ConvaOptions> for VOption<'_> {
+    fn from(options: ConvaOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.layers {
+            option.add(
+                "layers",
+                value,
+            );
+        }
+        if let Some(value) = options.cluster {
+            option.add(
+                "cluster",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of convasep, for [`VipsImage::convasep_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConvasepOptions {
+    /// Use this many layers in approximation
+    pub layers: Option<i32>,
+}
+
+impl ConvasepOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use this many layers in approximation
+    pub fn layers(mut self, layers: i32) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+}
+
+impl From<ConvasepOptions> for VOption<'_> {
+    fn from(options: ConvasepOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.layers {
+            option.add(
+                "layers",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of convsep, for [`VipsImage::convsep_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConvsepOptions {
+    /// Convolve with this precision
+    pub precision: Option<Precision>,
+    /// Use this many layers in approximation
+    pub layers: Option<i32>,
+    /// Cluster lines closer than this in approximation
+    pub cluster: Option<i32>,
+}
+
+impl ConvsepOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convolve with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Use this many layers in approximation
+    pub fn layers(mut self, layers: i32) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Cluster lines closer than this in approximation
+    pub fn cluster(mut self, cluster: i32) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+}
+
+impl From<ConvsepOptions> for VOption<'_> {
+    fn from(options: ConvsepOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        if let Some(value) = options.layers {
+            option.add(
+                "layers",
+                value,
+            );
+        }
+        if let Some(value) = options.cluster {
+            option.add(
+                "cluster",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of copy, for [`VipsImage::copy_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CopyOptions {
+    /// Image width in pixels
+    pub width: Option<i32>,
+    /// Image height in pixels
+    pub height: Option<i32>,
+    /// Number of bands in image
+    pub bands: Option<i32>,
+    /// Pixel format in image
+    pub format: Option<BandFormat>,
+    /// Pixel coding
+    pub coding: Option<Coding>,
+    /// Pixel interpretation
+    pub interpretation: Option<Interpretation>,
+    /// Horizontal resolution in pixels/mm
+    pub xres: Option<f64>,
+    /// Vertical resolution in pixels/mm
+    pub yres: Option<f64>,
+    /// Horizontal offset of origin
+    pub xoffset: Option<i32>,
+    /// Vertical offset of origin
+    pub yoffset: Option<i32>,
+}
+
+impl CopyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Image width in pixels
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Image height in pixels
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Number of bands in image
+    pub fn bands(mut self, bands: i32) -> Self {
+        self.bands = Some(bands);
+        self
+    }
+
+    /// Pixel format in image
+    pub fn format(mut self, format: BandFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Pixel coding
+    pub fn coding(mut self, coding: Coding) -> Self {
+        self.coding = Some(coding);
+        self
+    }
+
+    /// Pixel interpretation
+    pub fn interpretation(mut self, interpretation: Interpretation) -> Self {
+        self.interpretation = Some(interpretation);
+        self
+    }
+
+    /// Horizontal resolution in pixels/mm
+    pub fn xres(mut self, xres: f64) -> Self {
+        self.xres = Some(xres);
+        self
+    }
+
+    /// Vertical resolution in pixels/mm
+    pub fn yres(mut self, yres: f64) -> Self {
+        self.yres = Some(yres);
+        self
+    }
+
+    /// Horizontal offset of origin
+    pub fn xoffset(mut self, xoffset: i32) -> Self {
+        self.xoffset = Some(xoffset);
+        self
+    }
+
+    /// Vertical offset of origin
+    pub fn yoffset(mut self, yoffset: i32) -> Self {
+        self.yoffset = Some(yoffset);
+        self
+    }
+}
+
+impl From<CopyOptions> for VOption<'_> {
+    fn from(options: CopyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.width {
+            option.add(
+                "width",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.bands {
+            option.add(
+                "bands",
+                value,
+            );
+        }
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.coding {
+            option.add(
+                "coding",
+                value,
+            );
+        }
+        if let Some(value) = options.interpretation {
+            option.add(
+                "interpretation",
+                value,
+            );
+        }
+        if let Some(value) = options.xres {
+            option.add(
+                "xres",
+                value,
+            );
+        }
+        if let Some(value) = options.yres {
+            option.add(
+                "yres",
+                value,
+            );
+        }
+        if let Some(value) = options.xoffset {
+            option.add(
+                "xoffset",
+                value,
+            );
+        }
+        if let Some(value) = options.yoffset {
+            option.add(
+                "yoffset",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of csvload, for [`VipsImage::csvload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CsvloadOptions {
+    /// Skip this many lines at the start of the file
+    pub skip: Option<i32>,
+    /// Read this many lines from the file
+    pub lines: Option<i32>,
+    /// Set of whitespace characters
+    pub whitespace: Option<String>,
+    /// Set of separator characters
+    pub separator: Option<String>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl CsvloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip this many lines at the start of the file
+    pub fn skip(mut self, skip: i32) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Read this many lines from the file
+    pub fn lines(mut self, lines: i32) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// Set of whitespace characters
+    pub fn whitespace(mut self, whitespace: &str) -> Self {
+        self.whitespace = Some(whitespace.to_string());
+        self
+    }
+
+    /// Set of separator characters
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<CsvloadOptions> for VOption<'_> {
+    fn from(options: CsvloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.skip {
+            option.add(
+                "skip",
+                value,
+            );
+        }
+        if let Some(value) = options.lines {
+            option.add(
+                "lines",
+                value,
+            );
+        }
+        if let Some(value) = options.whitespace {
+            option.add(
+                "whitespace",
+                value,
+            );
+        }
+        if let Some(value) = options.separator {
+            option.add(
+                "separator",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of csvload_source, for [`VipsImage::csvload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CsvloadSourceOptions {
+    /// Skip this many lines at the start of the file
+    pub skip: Option<i32>,
+    /// Read this many lines from the file
+    pub lines: Option<i32>,
+    /// Set of whitespace characters
+    pub whitespace: Option<String>,
+    /// Set of separator characters
+    pub separator: Option<String>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl CsvloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip this many lines at the start of the file
+    pub fn skip(mut self, skip: i32) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Read this many lines from the file
+    pub fn lines(mut self, lines: i32) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// Set of whitespace characters
+    pub fn whitespace(mut self, whitespace: &str) -> Self {
+        self.whitespace = Some(whitespace.to_string());
+        self
+    }
+
+    /// Set of separator characters
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<CsvloadSourceOptions> for VOption<'_> {
+    fn from(options: CsvloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.skip {
+            option.add(
+                "skip",
+                value,
+            );
+        }
+        if let Some(value) = options.lines {
+            option.add(
+                "lines",
+                value,
+            );
+        }
+        if let Some(value) = options.whitespace {
+            option.add(
+                "whitespace",
+                value,
+            );
+        }
+        if let Some(value) = options.separator {
+            option.add(
+                "separator",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of csvsave, for [`VipsImage::csvsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CsvsaveOptions {
+    /// Separator characters
+    pub separator: Option<String>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl CsvsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separator characters
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<CsvsaveOptions> for VOption<'_> {
+    fn from(options: CsvsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.separator {
+            option.add(
+                "separator",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of csvsave_target, for [`VipsImage::csvsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CsvsaveTargetOptions {
+    /// Separator characters
+    pub separator: Option<String>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl CsvsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separator characters
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<CsvsaveTargetOptions> for VOption<'_> {
+    fn from(options: CsvsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.separator {
+            option.add(
+                "separator",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of draw_circle, for [`VipsImage::draw_circle_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DrawCircleOptions {
+    /// Draw a solid object
+    pub fill: Option<bool>,
+}
+
+impl DrawCircleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw a solid object
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+impl From<DrawCircleOptions> for VOption<'_> {
+    fn from(options: DrawCircleOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.fill {
+            option.add(
+                "fill",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of draw_flood, for [`VipsImage::draw_flood_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DrawFloodOptions {
+    /// Test pixels in this image
+    pub test: Option<VipsImage>,
+    /// DrawFlood while equal to edge
+    pub equal: Option<bool>,
+}
+
+impl DrawFloodOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Test pixels in this image
+    pub fn test(mut self, test: &VipsImage) -> Self {
+        self.test = Some(test.clone());
+        self
+    }
+
+    /// DrawFlood while equal to edge
+    pub fn equal(mut self, equal: bool) -> Self {
+        self.equal = Some(equal);
+        self
+    }
+}
+
+impl From<DrawFloodOptions> for VOption<'_> {
+    fn from(options: DrawFloodOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.test {
+            option.add(
+                "test",
+                value,
+            );
+        }
+        if let Some(value) = options.equal {
+            option.add(
+                "equal",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of draw_image, for [`VipsImage::draw_image_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DrawImageOptions {
+    /// Combining mode
+    pub mode: Option<CombineMode>,
+}
+
+impl DrawImageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combining mode
+    pub fn mode(mut self, mode: CombineMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+}
+
+impl From<DrawImageOptions> for VOption<'_> {
+    fn from(options: DrawImageOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.mode {
+            option.add(
+                "mode",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of draw_rect, for [`VipsImage::draw_rect_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DrawRectOptions {
+    /// Draw a solid object
+    pub fill: Option<bool>,
+}
+
+impl DrawRectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw a solid object
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+impl From<DrawRectOptions> for VOption<'_> {
+    fn from(options: DrawRectOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.fill {
+            option.add(
+                "fill",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of dzsave, for [`VipsImage::dzsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DzsaveOptions {
+    /// Image name
+    pub imagename: Option<String>,
+    /// Directory layout
+    pub layout: Option<ForeignDzLayout>,
+    /// Filename suffix for tiles
+    pub suffix: Option<String>,
+    /// Tile overlap in pixels
+    pub overlap: Option<i32>,
+    /// Tile size in pixels
+    pub tile_size: Option<i32>,
+    /// Center image in tile
+    pub centre: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Rotate image during save
+    pub angle: Option<Angle>,
+    /// Pyramid container type
+    pub container: Option<ForeignDzContainer>,
+    /// ZIP deflate compression level
+    pub compression: Option<i32>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Skip tiles which are nearly equal to the background
+    pub skip_blanks: Option<i32>,
+    /// Resource ID
+    pub id: Option<String>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl DzsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Image name
+    pub fn imagename(mut self, imagename: &str) -> Self {
+        self.imagename = Some(imagename.to_string());
+        self
+    }
+
+    /// Directory layout
+    pub fn layout(mut self, layout: ForeignDzLayout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Filename suffix for tiles
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Tile overlap in pixels
+    pub fn overlap(mut self, overlap: i32) -> Self {
+        self.overlap = Some(overlap);
+        self
+    }
+
+    /// Tile size in pixels
+    pub fn tile_size(mut self, tile_size: i32) -> Self {
+        self.tile_size = Some(tile_size);
+        self
+    }
+
+    /// Center image in tile
+    pub fn centre(mut self, centre: bool) -> Self {
+        self.centre = Some(centre);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Rotate image during save
+    pub fn angle(mut self, angle: Angle) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Pyramid container type
+    pub fn container(mut self, container: ForeignDzContainer) -> Self {
+        self.container = Some(container);
+        self
+    }
+
+    /// ZIP deflate compression level
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Skip tiles which are nearly equal to the background
+    pub fn skip_blanks(mut self, skip_blanks: i32) -> Self {
+        self.skip_blanks = Some(skip_blanks);
+        self
+    }
+
+    /// Resource ID
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<DzsaveOptions> for VOption<'_> {
+    fn from(options: DzsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.imagename {
+            option.add(
+                "imagename",
+                value,
+            );
+        }
+        if let Some(value) = options.layout {
+            option.add(
+                "layout",
+                value,
+            );
+        }
+        if let Some(value) = options.suffix {
+            option.add(
+                "suffix",
+                value,
+            );
+        }
+        if let Some(value) = options.overlap {
+            option.add(
+                "overlap",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_size {
+            option.add(
+                "tile_size",
+                value,
+            );
+        }
+        if let Some(value) = options.centre {
+            option.add(
+                "centre",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        if let Some(value) = options.container {
+            option.add(
+                "container",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.skip_blanks {
+            option.add(
+                "skip_blanks",
+                value,
+            );
+        }
+        if let Some(value) = options.id {
+            option.add(
+                "id", value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of dzsave_buffer, for [`VipsImage::dzsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DzsaveBufferOptions {
+    /// Image name
+    pub imagename: Option<String>,
+    /// Directory layout
+    pub layout: Option<ForeignDzLayout>,
+    /// Filename suffix for tiles
+    pub suffix: Option<String>,
+    /// Tile overlap in pixels
+    pub overlap: Option<i32>,
+    /// Tile size in pixels
+    pub tile_size: Option<i32>,
+    /// Center image in tile
+    pub centre: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Rotate image during save
+    pub angle: Option<Angle>,
+    /// Pyramid container type
+    pub container: Option<ForeignDzContainer>,
+    /// ZIP deflate compression level
+    pub compression: Option<i32>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Skip tiles which are nearly equal to the background
+    pub skip_blanks: Option<i32>,
+    /// Resource ID
+    pub id: Option<String>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl DzsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Image name
+    pub fn imagename(mut self, imagename: &str) -> Self {
+        self.imagename = Some(imagename.to_string());
+        self
+    }
+
+    /// Directory layout
+    pub fn layout(mut self, layout: ForeignDzLayout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Filename suffix for tiles
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Tile overlap in pixels
+    pub fn overlap(mut self, overlap: i32) -> Self {
+        self.overlap = Some(overlap);
+        self
+    }
+
+    /// Tile size in pixels
+    pub fn tile_size(mut self, tile_size: i32) -> Self {
+        self.tile_size = Some(tile_size);
+        self
+    }
+
+    /// Center image in tile
+    pub fn centre(mut self, centre: bool) -> Self {
+        self.centre = Some(centre);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Rotate image during save
+    pub fn angle(mut self, angle: Angle) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Pyramid container type
+    pub fn container(mut self, container: ForeignDzContainer) -> Self {
+        self.container = Some(container);
+        self
+    }
+
+    /// ZIP deflate compression level
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Skip tiles which are nearly equal to the background
+    pub fn skip_blanks(mut self, skip_blanks: i32) -> Self {
+        self.skip_blanks = Some(skip_blanks);
+        self
+    }
+
+    /// Resource ID
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<DzsaveBufferOptions> for VOption<'_> {
+    fn from(options: DzsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.imagename {
+            option.add(
+                "imagename",
+                value,
+            );
+        }
+        if let Some(value) = options.layout {
+            option.add(
+                "layout",
+                value,
+            );
+        }
+        if let Some(value) = options.suffix {
+            option.add(
+                "suffix",
+                value,
+            );
+        }
+        if let Some(value) = options.overlap {
+            option.add(
+                "overlap",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_size {
+            option.add(
+                "tile_size",
+                value,
+            );
+        }
+        if let Some(value) = options.centre {
+            option.add(
+                "centre",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        if let Some(value) = options.container {
+            option.add(
+                "container",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.skip_blanks {
+            option.add(
+                "skip_blanks",
+                value,
+            );
+        }
+        if let Some(value) = options.id {
+            option.add(
+                "id", value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of dzsave_target, for [`VipsImage::dzsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct DzsaveTargetOptions {
+    /// Image name
+    pub imagename: Option<String>,
+    /// Directory layout
+    pub layout: Option<ForeignDzLayout>,
+    /// Filename suffix for tiles
+    pub suffix: Option<String>,
+    /// Tile overlap in pixels
+    pub overlap: Option<i32>,
+    /// Tile size in pixels
+    pub tile_size: Option<i32>,
+    /// Center image in tile
+    pub centre: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Rotate image during save
+    pub angle: Option<Angle>,
+    /// Pyramid container type
+    pub container: Option<ForeignDzContainer>,
+    /// ZIP deflate compression level
+    pub compression: Option<i32>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Skip tiles which are nearly equal to the background
+    pub skip_blanks: Option<i32>,
+    /// Resource ID
+    pub id: Option<String>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl DzsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Image name
+    pub fn imagename(mut self, imagename: &str) -> Self {
+        self.imagename = Some(imagename.to_string());
+        self
+    }
+
+    /// Directory layout
+    pub fn layout(mut self, layout: ForeignDzLayout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Filename suffix for tiles
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Tile overlap in pixels
+    pub fn overlap(mut self, overlap: i32) -> Self {
+        self.overlap = Some(overlap);
+        self
+    }
+
+    /// Tile size in pixels
+    pub fn tile_size(mut self, tile_size: i32) -> Self {
+        self.tile_size = Some(tile_size);
+        self
+    }
+
+    /// Center image in tile
+    pub fn centre(mut self, centre: bool) -> Self {
+        self.centre = Some(centre);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Rotate image during save
+    pub fn angle(mut self, angle: Angle) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Pyramid container type
+    pub fn container(mut self, container: ForeignDzContainer) -> Self {
+        self.container = Some(container);
+        self
+    }
+
+    /// ZIP deflate compression level
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Skip tiles which are nearly equal to the background
+    pub fn skip_blanks(mut self, skip_blanks: i32) -> Self {
+        self.skip_blanks = Some(skip_blanks);
+        self
+    }
+
+    /// Resource ID
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<DzsaveTargetOptions> for VOption<'_> {
+    fn from(options: DzsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.imagename {
+            option.add(
+                "imagename",
+                value,
+            );
+        }
+        if let Some(value) = options.layout {
+            option.add(
+                "layout",
+                value,
+            );
+        }
+        if let Some(value) = options.suffix {
+            option.add(
+                "suffix",
+                value,
+            );
+        }
+        if let Some(value) = options.overlap {
+            option.add(
+                "overlap",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_size {
+            option.add(
+                "tile_size",
+                value,
+            );
+        }
+        if let Some(value) = options.centre {
+            option.add(
+                "centre",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        if let Some(value) = options.container {
+            option.add(
+                "container",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.skip_blanks {
+            option.add(
+                "skip_blanks",
+                value,
+            );
+        }
+        if let Some(value) = options.id {
+            option.add(
+                "id", value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of embed, for [`VipsImage::embed_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct EmbedOptions {
+    /// How to generate the extra pixels
+    pub extend: Option<Extend>,
+    /// Color for background pixels
+    pub background: Option<Vec<f64>>,
+}
+
+impl EmbedOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How to generate the extra pixels
+    pub fn extend(mut self, extend: Extend) -> Self {
+        self.extend = Some(extend);
+        self
+    }
+
+    /// Color for background pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+}
+
+impl From<EmbedOptions> for VOption<'_> {
+    fn from(options: EmbedOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.extend {
+            option.add(
+                "extend",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of extract_band, for [`VipsImage::extract_band_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractBandOptions {
+    /// Number of bands to extract
+    pub n: Option<i32>,
+}
+
+impl ExtractBandOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bands to extract
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+}
+
+impl From<ExtractBandOptions> for VOption<'_> {
+    fn from(options: ExtractBandOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        option
+    }
+}
+
+/// Optional arguments of eye, for [`VipsImage::eye_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct EyeOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Maximum spatial frequency
+    pub factor: Option<f64>,
+}
+
+impl EyeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Maximum spatial frequency
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+}
+
+impl From<EyeOptions> for VOption<'_> {
+    fn from(options: EyeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.factor {
+            option.add(
+                "factor",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of find_trim, for [`VipsImage::find_trim_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FindTrimOptions {
+    /// Object threshold
+    pub threshold: Option<f64>,
+    /// Color for background pixels
+    pub background: Option<Vec<f64>>,
+    /// Enable line art mode
+    pub line_art: Option<bool>,
+}
+
+impl FindTrimOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Object threshold
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Color for background pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Enable line art mode
+    pub fn line_art(mut self, line_art: bool) -> Self {
+        self.line_art = Some(line_art);
+        self
+    }
+}
+
+impl From<FindTrimOptions> for VOption<'_> {
+    fn from(options: FindTrimOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.threshold {
+            option.add(
+                "threshold",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.line_art {
+            option.add(
+                "line_art",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of fitsload, for [`VipsImage::fitsload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FitsloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl FitsloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<FitsloadOptions> for VOption<'_> {
+    fn from(options: FitsloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of fitsload_source, for [`VipsImage::fitsload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FitsloadSourceOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl FitsloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<FitsloadSourceOptions> for VOption<'_> {
+    fn from(options: FitsloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of fitssave, for [`VipsImage::fitssave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FitssaveOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl FitssaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<FitssaveOptions> for VOption<'_> {
+    fn from(options: FitssaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of flatten, for [`VipsImage::flatten_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FlattenOptions {
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Maximum value of alpha channel
+    pub max_alpha: Option<f64>,
+}
+
+impl FlattenOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Maximum value of alpha channel
+    pub fn max_alpha(mut self, max_alpha: f64) -> Self {
+        self.max_alpha = Some(max_alpha);
+        self
+    }
+}
+
+impl From<FlattenOptions> for VOption<'_> {
+    fn from(options: FlattenOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.max_alpha {
+            option.add(
+                "max_alpha",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gamma, for [`VipsImage::gamma_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GammaOptions {
+    /// Gamma factor
+    pub exponent: Option<f64>,
+}
+
+impl GammaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gamma factor
+    pub fn exponent(mut self, exponent: f64) -> Self {
+        self.exponent = Some(exponent);
+        self
+    }
+}
+
+impl From<GammaOptions> for VOption<'_> {
+    fn from(options: GammaOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.exponent {
+            option.add(
+                "exponent",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gaussblur, for [`VipsImage::gaussblur_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GaussblurOptions {
+    /// Minimum amplitude of Gaussian
+    pub min_ampl: Option<f64>,
+    /// Convolve with this precision
+    pub precision: Option<Precision>,
+}
+
+impl GaussblurOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum amplitude of Gaussian
+    pub fn min_ampl(mut self, min_ampl: f64) -> Self {
+        self.min_ampl = Some(min_ampl);
+        self
+    }
+
+    /// Convolve with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl From<GaussblurOptions> for VOption<'_> {
+    fn from(options: GaussblurOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.min_ampl {
+            option.add(
+                "min_ampl",
+                value,
+            );
+        }
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gaussmat, for [`VipsImage::gaussmat_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GaussmatOptions {
+    /// Generate separable Gaussian
+    pub separable: Option<bool>,
+    /// Generate with this precision
+    pub precision: Option<Precision>,
+}
+
+impl GaussmatOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate separable Gaussian
+    pub fn separable(mut self, separable: bool) -> Self {
+        self.separable = Some(separable);
+        self
+    }
+
+    /// Generate with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl From<GaussmatOptions> for VOption<'_> {
+    fn from(options: GaussmatOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.separable {
+            option.add(
+                "separable",
+                value,
+            );
+        }
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gaussnoise, for [`VipsImage::gaussnoise_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GaussnoiseOptions {
+    /// Standard deviation of pixels in generated image
+    pub sigma: Option<f64>,
+    /// Mean of pixels in generated image
+    pub mean: Option<f64>,
+    /// Random number seed
+    pub seed: Option<i32>,
+}
+
+impl GaussnoiseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Standard deviation of pixels in generated image
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = Some(sigma);
+        self
+    }
+
+    /// Mean of pixels in generated image
+    pub fn mean(mut self, mean: f64) -> Self {
+        self.mean = Some(mean);
+        self
+    }
+
+    /// Random number seed
+    pub fn seed(mut self, seed: i32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+impl From<GaussnoiseOptions> for VOption<'_> {
+    fn from(options: GaussnoiseOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.sigma {
+            option.add(
+                "sigma",
+                value,
+            );
+        }
+        if let Some(value) = options.mean {
+            option.add(
+                "mean",
+                value,
+            );
+        }
+        if let Some(value) = options.seed {
+            option.add(
+                "seed",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of getpoint, for [`VipsImage::getpoint_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GetpointOptions {
+    /// Complex pixels should be unpacked
+    pub unpack_complex: Option<bool>,
+}
+
+impl GetpointOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Complex pixels should be unpacked
+    pub fn unpack_complex(mut self, unpack_complex: bool) -> Self {
+        self.unpack_complex = Some(unpack_complex);
+        self
+    }
+}
+
+impl From<GetpointOptions> for VOption<'_> {
+    fn from(options: GetpointOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.unpack_complex {
+            option.add(
+                "unpack_complex",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifload, for [`VipsImage::gifload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifloadOptions {
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// First page to load
+    pub page: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl GifloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<GifloadOptions> for VOption<'_> {
+    fn from(options: GifloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifload_buffer, for [`VipsImage::gifload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifloadBufferOptions {
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// First page to load
+    pub page: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl GifloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<GifloadBufferOptions> for VOption<'_> {
+    fn from(options: GifloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifload_source, for [`VipsImage::gifload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifloadSourceOptions {
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// First page to load
+    pub page: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl GifloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<GifloadSourceOptions> for VOption<'_> {
+    fn from(options: GifloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifsave, for [`VipsImage::gifsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifsaveOptions {
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Quantisation effort
+    pub effort: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Maximum inter-frame error for transparency
+    pub interframe_maxerror: Option<f64>,
+    /// Reuse palette from input
+    pub reuse: Option<bool>,
+    /// Maximum inter-palette error for palette reusage
+    pub interpalette_maxerror: Option<f64>,
+    /// Generate an interlaced (progressive) GIF
+    pub interlace: Option<bool>,
+    /// Keep duplicate frames in the output instead of combining them
+    pub keep_duplicate_frames: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl GifsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Quantisation effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Maximum inter-frame error for transparency
+    pub fn interframe_maxerror(mut self, interframe_maxerror: f64) -> Self {
+        self.interframe_maxerror = Some(interframe_maxerror);
+        self
+    }
+
+    /// Reuse palette from input
+    pub fn reuse(mut self, reuse: bool) -> Self {
+        self.reuse = Some(reuse);
+        self
+    }
+
+    /// Maximum inter-palette error for palette reusage
+    pub fn interpalette_maxerror(mut self, interpalette_maxerror: f64) -> Self {
+        self.interpalette_maxerror = Some(interpalette_maxerror);
+        self
+    }
+
+    /// Generate an interlaced (progressive) GIF
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Keep duplicate frames in the output instead of combining them
+    pub fn keep_duplicate_frames(mut self, keep_duplicate_frames: bool) -> Self {
+        self.keep_duplicate_frames = Some(keep_duplicate_frames);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<GifsaveOptions> for VOption<'_> {
+    fn from(options: GifsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.interframe_maxerror {
+            option.add(
+                "interframe_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.reuse {
+            option.add(
+                "reuse",
+                value,
+            );
+        }
+        if let Some(value) = options.interpalette_maxerror {
+            option.add(
+                "interpalette_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.keep_duplicate_frames {
+            option.add(
+                "keep_duplicate_frames",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifsave_buffer, for [`VipsImage::gifsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifsaveBufferOptions {
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Quantisation effort
+    pub effort: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Maximum inter-frame error for transparency
+    pub interframe_maxerror: Option<f64>,
+    /// Reuse palette from input
+    pub reuse: Option<bool>,
+    /// Maximum inter-palette error for palette reusage
+    pub interpalette_maxerror: Option<f64>,
+    /// Generate an interlaced (progressive) GIF
+    pub interlace: Option<bool>,
+    /// Keep duplicate frames in the output instead of combining them
+    pub keep_duplicate_frames: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl GifsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Quantisation effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Maximum inter-frame error for transparency
+    pub fn interframe_maxerror(mut self, interframe_maxerror: f64) -> Self {
+        self.interframe_maxerror = Some(interframe_maxerror);
+        self
+    }
+
+    /// Reuse palette from input
+    pub fn reuse(mut self, reuse: bool) -> Self {
+        self.reuse = Some(reuse);
+        self
+    }
+
+    /// Maximum inter-palette error for palette reusage
+    pub fn interpalette_maxerror(mut self, interpalette_maxerror: f64) -> Self {
+        self.interpalette_maxerror = Some(interpalette_maxerror);
+        self
+    }
+
+    /// Generate an interlaced (progressive) GIF
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Keep duplicate frames in the output instead of combining them
+    pub fn keep_duplicate_frames(mut self, keep_duplicate_frames: bool) -> Self {
+        self.keep_duplicate_frames = Some(keep_duplicate_frames);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<GifsaveBufferOptions> for VOption<'_> {
+    fn from(options: GifsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.interframe_maxerror {
+            option.add(
+                "interframe_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.reuse {
+            option.add(
+                "reuse",
+                value,
+            );
+        }
+        if let Some(value) = options.interpalette_maxerror {
+            option.add(
+                "interpalette_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.keep_duplicate_frames {
+            option.add(
+                "keep_duplicate_frames",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gifsave_target, for [`VipsImage::gifsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GifsaveTargetOptions {
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Quantisation effort
+    pub effort: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Maximum inter-frame error for transparency
+    pub interframe_maxerror: Option<f64>,
+    /// Reuse palette from input
+    pub reuse: Option<bool>,
+    /// Maximum inter-palette error for palette reusage
+    pub interpalette_maxerror: Option<f64>,
+    /// Generate an interlaced (progressive) GIF
+    pub interlace: Option<bool>,
+    /// Keep duplicate frames in the output instead of combining them
+    pub keep_duplicate_frames: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl GifsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Quantisation effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Maximum inter-frame error for transparency
+    pub fn interframe_maxerror(mut self, interframe_maxerror: f64) -> Self {
+        self.interframe_maxerror = Some(interframe_maxerror);
+        self
+    }
+
+    /// Reuse palette from input
+    pub fn reuse(mut self, reuse: bool) -> Self {
+        self.reuse = Some(reuse);
+        self
+    }
+
+    /// Maximum inter-palette error for palette reusage
+    pub fn interpalette_maxerror(mut self, interpalette_maxerror: f64) -> Self {
+        self.interpalette_maxerror = Some(interpalette_maxerror);
+        self
+    }
+
+    /// Generate an interlaced (progressive) GIF
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Keep duplicate frames in the output instead of combining them
+    pub fn keep_duplicate_frames(mut self, keep_duplicate_frames: bool) -> Self {
+        self.keep_duplicate_frames = Some(keep_duplicate_frames);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<GifsaveTargetOptions> for VOption<'_> {
+    fn from(options: GifsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.interframe_maxerror {
+            option.add(
+                "interframe_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.reuse {
+            option.add(
+                "reuse",
+                value,
+            );
+        }
+        if let Some(value) = options.interpalette_maxerror {
+            option.add(
+                "interpalette_maxerror",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.keep_duplicate_frames {
+            option.add(
+                "keep_duplicate_frames",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of globalbalance, for [`VipsImage::globalbalance_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GlobalbalanceOptions {
+    /// Image gamma
+    pub gamma: Option<f64>,
+    /// Integer output
+    pub int_output: Option<bool>,
+}
+
+impl GlobalbalanceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Image gamma
+    pub fn gamma(mut self, gamma: f64) -> Self {
+        self.gamma = Some(gamma);
+        self
+    }
+
+    /// Integer output
+    pub fn int_output(mut self, int_output: bool) -> Self {
+        self.int_output = Some(int_output);
+        self
+    }
+}
+
+impl From<GlobalbalanceOptions> for VOption<'_> {
+    fn from(options: GlobalbalanceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.gamma {
+            option.add(
+                "gamma",
+                value,
+            );
+        }
+        if let Some(value) = options.int_output {
+            option.add(
+                "int_output",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of gravity, for [`VipsImage::gravity_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GravityOptions {
+    /// How to generate the extra pixels
+    pub extend: Option<Extend>,
+    /// Color for background pixels
+    pub background: Option<Vec<f64>>,
+}
+
+impl GravityOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How to generate the extra pixels
+    pub fn extend(mut self, extend: Extend) -> Self {
+        self.extend = Some(extend);
+        self
+    }
+
+    /// Color for background pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+}
+
+impl From<GravityOptions> for VOption<'_> {
+    fn from(options: GravityOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.extend {
+            option.add(
+                "extend",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of grey, for [`VipsImage::grey_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct GreyOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+}
+
+impl GreyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+}
+
+impl From<GreyOptions> for VOption<'_> {
+    fn from(options: GreyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifload, for [`VipsImage::heifload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifloadOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Fetch thumbnail image
+    pub thumbnail: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl HeifloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Fetch thumbnail image
+    pub fn thumbnail(mut self, thumbnail: bool) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<HeifloadOptions> for VOption<'_> {
+    fn from(options: HeifloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.thumbnail {
+            option.add(
+                "thumbnail",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifload_buffer, for [`VipsImage::heifload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifloadBufferOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Fetch thumbnail image
+    pub thumbnail: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl HeifloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Fetch thumbnail image
+    pub fn thumbnail(mut self, thumbnail: bool) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<HeifloadBufferOptions> for VOption<'_> {
+    fn from(options: HeifloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.thumbnail {
+            option.add(
+                "thumbnail",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifload_source, for [`VipsImage::heifload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifloadSourceOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Fetch thumbnail image
+    pub thumbnail: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl HeifloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Fetch thumbnail image
+    pub fn thumbnail(mut self, thumbnail: bool) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<HeifloadSourceOptions> for VOption<'_> {
+    fn from(options: HeifloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.thumbnail {
+            option.add(
+                "thumbnail",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifsave, for [`VipsImage::heifsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifsaveOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Compression format
+    pub compression: Option<ForeignHeifCompression>,
+    /// CPU effort
+    pub effort: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Select encoder to use
+    pub encoder: Option<ForeignHeifEncoder>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl HeifsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Compression format
+    pub fn compression(mut self, compression: ForeignHeifCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Select encoder to use
+    pub fn encoder(mut self, encoder: ForeignHeifEncoder) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<HeifsaveOptions> for VOption<'_> {
+    fn from(options: HeifsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.encoder {
+            option.add(
+                "encoder",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifsave_buffer, for [`VipsImage::heifsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifsaveBufferOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Compression format
+    pub compression: Option<ForeignHeifCompression>,
+    /// CPU effort
+    pub effort: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Select encoder to use
+    pub encoder: Option<ForeignHeifEncoder>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl HeifsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Compression format
+    pub fn compression(mut self, compression: ForeignHeifCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Select encoder to use
+    pub fn encoder(mut self, encoder: ForeignHeifEncoder) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<HeifsaveBufferOptions> for VOption<'_> {
+    fn from(options: HeifsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.encoder {
+            option.add(
+                "encoder",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of heifsave_target, for [`VipsImage::heifsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HeifsaveTargetOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Compression format
+    pub compression: Option<ForeignHeifCompression>,
+    /// CPU effort
+    pub effort: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Select encoder to use
+    pub encoder: Option<ForeignHeifEncoder>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl HeifsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Compression format
+    pub fn compression(mut self, compression: ForeignHeifCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Select encoder to use
+    pub fn encoder(mut self, encoder: ForeignHeifEncoder) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<HeifsaveTargetOptions> for VOption<'_> {
+    fn from(options: HeifsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.encoder {
+            option.add(
+                "encoder",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hist_equal, for [`VipsImage::hist_equal_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HistEqualOptions {
+    /// Equalise with this band
+    pub band: Option<i32>,
+}
+
+impl HistEqualOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Equalise with this band
+    pub fn band(mut self, band: i32) -> Self {
+        self.band = Some(band);
+        self
+    }
+}
+
+impl From<HistEqualOptions> for VOption<'_> {
+    fn from(options: HistEqualOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.band {
+            option.add(
+                "band",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hist_find, for [`VipsImage::hist_find_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HistFindOptions {
+    /// Find histogram of band
+    pub band: Option<i32>,
+}
+
+impl HistFindOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find histogram of band
+    pub fn band(mut self, band: i32) -> Self {
+        self.band = Some(band);
+        self
+    }
+}
+
+impl From<HistFindOptions> for VOption<'_> {
+    fn from(options: HistFindOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.band {
+            option.add(
+                "band",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hist_find_indexed, for [`VipsImage::hist_find_indexed_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HistFindIndexedOptions {
+    /// Combine bins like this
+    pub combine: Option<Combine>,
+}
+
+impl HistFindIndexedOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combine bins like this
+    pub fn combine(mut self, combine: Combine) -> Self {
+        self.combine = Some(combine);
+        self
+    }
+}
+
+impl From<HistFindIndexedOptions> for VOption<'_> {
+    fn from(options: HistFindIndexedOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.combine {
+            option.add(
+                "combine",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hist_find_ndim, for [`VipsImage::hist_find_ndim_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HistFindNdimOptions {
+    /// Number of bins in each dimension
+    pub bins: Option<i32>,
+}
+
+impl HistFindNdimOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bins in each dimension
+    pub fn bins(mut self, bins: i32) -> Self {
+        self.bins = Some(bins);
+        self
+    }
+}
+
+impl From<HistFindNdimOptions> for VOption<'_> {
+    fn from(options: HistFindNdimOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.bins {
+            option.add(
+                "bins",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hist_local, for [`VipsImage::hist_local_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HistLocalOptions {
+    /// Maximum slope (CLAHE)
+    pub max_slope: Option<i32>,
+}
+
+impl HistLocalOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum slope (CLAHE)
+    pub fn max_slope(mut self, max_slope: i32) -> Self {
+        self.max_slope = Some(max_slope);
+        self
+    }
+}
+
+impl From<HistLocalOptions> for VOption<'_> {
+    fn from(options: HistLocalOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.max_slope {
+            option.add(
+                "max_slope",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hough_circle, for [`VipsImage::hough_circle_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HoughCircleOptions {
+    /// Scale down dimensions by this factor
+    pub scale: Option<i32>,
+    /// Smallest radius to search for
+    pub min_radius: Option<i32>,
+    /// Largest radius to search for
+    pub max_radius: Option<i32>,
+}
+
+impl HoughCircleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scale down dimensions by this factor
+    pub fn scale(mut self, scale: i32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Smallest radius to search for
+    pub fn min_radius(mut self, min_radius: i32) -> Self {
+        self.min_radius = Some(min_radius);
+        self
+    }
+
+    /// Largest radius to search for
+    pub fn max_radius(mut self, max_radius: i32) -> Self {
+        self.max_radius = Some(max_radius);
+        self
+    }
+}
+
+impl From<HoughCircleOptions> for VOption<'_> {
+    fn from(options: HoughCircleOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.min_radius {
+            option.add(
+                "min_radius",
+                value,
+            );
+        }
+        if let Some(value) = options.max_radius {
+            option.add(
+                "max_radius",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of hough_line, for [`VipsImage::hough_line_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct HoughLineOptions {
+    /// Horizontal size of parameter space
+    pub width: Option<i32>,
+    /// Vertical size of parameter space
+    pub height: Option<i32>,
+}
+
+impl HoughLineOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Horizontal size of parameter space
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Vertical size of parameter space
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl From<HoughLineOptions> for VOption<'_> {
+    fn from(options: HoughLineOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.width {
+            option.add(
+                "width",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of icc_export, for [`VipsImage::icc_export_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct IccExportOptions {
+    /// Set Profile Connection Space
+    pub pcs: Option<PCS>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Enable black point compensation
+    pub black_point_compensation: Option<bool>,
+    /// Filename to load output profile from
+    pub output_profile: Option<String>,
+    /// Output device space depth in bits
+    pub depth: Option<i32>,
+}
+
+impl IccExportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set Profile Connection Space
+    pub fn pcs(mut self, pcs: PCS) -> Self {
+        self.pcs = Some(pcs);
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Enable black point compensation
+    pub fn black_point_compensation(mut self, black_point_compensation: bool) -> Self {
+        self.black_point_compensation = Some(black_point_compensation);
+        self
+    }
+
+    /// Filename to load output profile from
+    pub fn output_profile(mut self, output_profile: &str) -> Self {
+        self.output_profile = Some(output_profile.to_string());
+        self
+    }
+
+    /// Output device space depth in bits
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+}
+
+impl From<IccExportOptions> for VOption<'_> {
+    fn from(options: IccExportOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.pcs {
+            option.add(
+                "pcs", value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.black_point_compensation {
+            option.add(
+                "black_point_compensation",
+                value,
+            );
+        }
+        if let Some(value) = options.output_profile {
+            option.add(
+                "output_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of icc_import, for [`VipsImage::icc_import_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct IccImportOptions {
+    /// Set Profile Connection Space
+    pub pcs: Option<PCS>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Enable black point compensation
+    pub black_point_compensation: Option<bool>,
+    /// Use embedded input profile, if available
+    pub embedded: Option<bool>,
+    /// Filename to load input profile from
+    pub input_profile: Option<String>,
+}
+
+impl IccImportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set Profile Connection Space
+    pub fn pcs(mut self, pcs: PCS) -> Self {
+        self.pcs = Some(pcs);
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Enable black point compensation
+    pub fn black_point_compensation(mut self, black_point_compensation: bool) -> Self {
+        self.black_point_compensation = Some(black_point_compensation);
+        self
+    }
+
+    /// Use embedded input profile, if available
+    pub fn embedded(mut self, embedded: bool) -> Self {
+        self.embedded = Some(embedded);
+        self
+    }
+
+    /// Filename to load input profile from
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+}
+
+impl From<IccImportOptions> for VOption<'_> {
+    fn from(options: IccImportOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.pcs {
+            option.add(
+                "pcs", value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.black_point_compensation {
+            option.add(
+                "black_point_compensation",
+                value,
+            );
+        }
+        if let Some(value) = options.embedded {
+            option.add(
+                "embedded",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of icc_transform, for [`VipsImage::icc_transform_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct IccTransformOptions {
+    /// Set Profile Connection Space
+    pub pcs: Option<PCS>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Enable black point compensation
+    pub black_point_compensation: Option<bool>,
+    /// Use embedded input profile, if available
+    pub embedded: Option<bool>,
+    /// Filename to load input profile from
+    pub input_profile: Option<String>,
+    /// Output device space depth in bits
+    pub depth: Option<i32>,
+}
+
+impl IccTransformOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set Profile Connection Space
+    pub fn pcs(mut self, pcs: PCS) -> Self {
+        self.pcs = Some(pcs);
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Enable black point compensation
+    pub fn black_point_compensation(mut self, black_point_compensation: bool) -> Self {
+        self.black_point_compensation = Some(black_point_compensation);
+        self
+    }
+
+    /// Use embedded input profile, if available
+    pub fn embedded(mut self, embedded: bool) -> Self {
+        self.embedded = Some(embedded);
+        self
+    }
+
+    /// Filename to load input profile from
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+
+    /// Output device space depth in bits
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+}
+
+impl From<IccTransformOptions> for VOption<'_> {
+    fn from(options: IccTransformOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.pcs {
+            option.add(
+                "pcs", value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.black_point_compensation {
+            option.add(
+                "black_point_compensation",
+                value,
+            );
+        }
+        if let Some(value) = options.embedded {
+            option.add(
+                "embedded",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of identity, for [`VipsImage::identity_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityOptions {
+    /// Number of bands in LUT
+    pub bands: Option<i32>,
+    /// Create a 16-bit LUT
+    pub ushort: Option<bool>,
+    /// Size of 16-bit LUT
+    pub size: Option<i32>,
+}
+
+impl IdentityOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bands in LUT
+    pub fn bands(mut self, bands: i32) -> Self {
+        self.bands = Some(bands);
+        self
+    }
+
+    /// Create a 16-bit LUT
+    pub fn ushort(mut self, ushort: bool) -> Self {
+        self.ushort = Some(ushort);
+        self
+    }
+
+    /// Size of 16-bit LUT
+    pub fn size(mut self, size: i32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<IdentityOptions> for VOption<'_> {
+    fn from(options: IdentityOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.bands {
+            option.add(
+                "bands",
+                value,
+            );
+        }
+        if let Some(value) = options.ushort {
+            option.add(
+                "ushort",
+                value,
+            );
+        }
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ifthenelse, for [`VipsImage::ifthenelse_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct IfthenelseOptions {
+    /// Blend smoothly between then and else parts
+    pub blend: Option<bool>,
+}
+
+impl IfthenelseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blend smoothly between then and else parts
+    pub fn blend(mut self, blend: bool) -> Self {
+        self.blend = Some(blend);
+        self
+    }
+}
+
+impl From<IfthenelseOptions> for VOption<'_> {
+    fn from(options: IfthenelseOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.blend {
+            option.add(
+                "blend",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of insert, for [`VipsImage::insert_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct InsertOptions {
+    /// Expand output to hold all of both inputs
+    pub expand: Option<bool>,
+    /// Color for new pixels
+    pub background: Option<Vec<f64>>,
+}
+
+impl InsertOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand output to hold all of both inputs
+    pub fn expand(mut self, expand: bool) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Color for new pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+}
+
+impl From<InsertOptions> for VOption<'_> {
+    fn from(options: InsertOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.expand {
+            option.add(
+                "expand",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of invertlut, for [`VipsImage::invertlut_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct InvertlutOptions {
+    /// LUT size to generate
+    pub size: Option<i32>,
+}
+
+impl InvertlutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// LUT size to generate
+    pub fn size(mut self, size: i32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<InvertlutOptions> for VOption<'_> {
+    fn from(options: InvertlutOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of invfft, for [`VipsImage::invfft_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct InvfftOptions {
+    /// Output only the real part of the transform
+    pub real: Option<bool>,
+}
+
+impl InvfftOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output only the real part of the transform
+    pub fn real(mut self, real: bool) -> Self {
+        self.real = Some(real);
+        self
+    }
+}
+
+impl From<InvfftOptions> for VOption<'_> {
+    fn from(options: InvfftOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.real {
+            option.add(
+                "real",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of join, for [`VipsImage::join_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JoinOptions {
+    /// Expand output to hold all of both inputs
+    pub expand: Option<bool>,
+    /// Pixels between images
+    pub shim: Option<i32>,
+    /// Colour for new pixels
+    pub background: Option<Vec<f64>>,
+    /// Align on the low, centre or high coordinate edge
+    pub align: Option<Align>,
+}
+
+impl JoinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand output to hold all of both inputs
+    pub fn expand(mut self, expand: bool) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Pixels between images
+    pub fn shim(mut self, shim: i32) -> Self {
+        self.shim = Some(shim);
+        self
+    }
+
+    /// Colour for new pixels
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Align on the low, centre or high coordinate edge
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+}
+
+impl From<JoinOptions> for VOption<'_> {
+    fn from(options: JoinOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.expand {
+            option.add(
+                "expand",
+                value,
+            );
+        }
+        if let Some(value) = options.shim {
+            option.add(
+                "shim",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.align {
+            option.add(
+                "align",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2kload, for [`VipsImage::jp2kload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2kloadOptions {
+    /// Load this page from the image
+    pub page: Option<i32>,
+    /// Load images a frame at a time
+    pub oneshot: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl Jp2kloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this page from the image
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Load images a frame at a time
+    pub fn oneshot(mut self, oneshot: bool) -> Self {
+        self.oneshot = Some(oneshot);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<Jp2kloadOptions> for VOption<'_> {
+    fn from(options: Jp2kloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.oneshot {
+            option.add(
+                "oneshot",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2kload_buffer, for [`VipsImage::jp2kload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2kloadBufferOptions {
+    /// Load this page from the image
+    pub page: Option<i32>,
+    /// Load images a frame at a time
+    pub oneshot: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl Jp2kloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this page from the image
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Load images a frame at a time
+    pub fn oneshot(mut self, oneshot: bool) -> Self {
+        self.oneshot = Some(oneshot);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<Jp2kloadBufferOptions> for VOption<'_> {
+    fn from(options: Jp2kloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.oneshot {
+            option.add(
+                "oneshot",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2kload_source, for [`VipsImage::jp2kload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2kloadSourceOptions {
+    /// Load this page from the image
+    pub page: Option<i32>,
+    /// Load images a frame at a time
+    pub oneshot: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl Jp2kloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this page from the image
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Load images a frame at a time
+    pub fn oneshot(mut self, oneshot: bool) -> Self {
+        self.oneshot = Some(oneshot);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<Jp2kloadSourceOptions> for VOption<'_> {
+    fn from(options: Jp2kloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.oneshot {
+            option.add(
+                "oneshot",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2ksave, for [`VipsImage::jp2ksave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2ksaveOptions {
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl Jp2ksaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<Jp2ksaveOptions> for VOption<'_> {
+    fn from(options: Jp2ksaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2ksave_buffer, for [`VipsImage::jp2ksave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2ksaveBufferOptions {
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl Jp2ksaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<Jp2ksaveBufferOptions> for VOption<'_> {
+    fn from(options: Jp2ksaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jp2ksave_target, for [`VipsImage::jp2ksave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Jp2ksaveTargetOptions {
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl Jp2ksaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<Jp2ksaveTargetOptions> for VOption<'_> {
+    fn from(options: Jp2ksaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegload, for [`VipsImage::jpegload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegloadOptions {
+    /// Shrink factor on load
+    pub shrink: Option<i32>,
+    /// Rotate image using exif orientation
+    pub autorotate: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JpegloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shrink factor on load
+    pub fn shrink(mut self, shrink: i32) -> Self {
+        self.shrink = Some(shrink);
+        self
+    }
+
+    /// Rotate image using exif orientation
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JpegloadOptions> for VOption<'_> {
+    fn from(options: JpegloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.shrink {
+            option.add(
+                "shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegload_buffer, for [`VipsImage::jpegload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegloadBufferOptions {
+    /// Shrink factor on load
+    pub shrink: Option<i32>,
+    /// Rotate image using exif orientation
+    pub autorotate: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JpegloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shrink factor on load
+    pub fn shrink(mut self, shrink: i32) -> Self {
+        self.shrink = Some(shrink);
+        self
+    }
+
+    /// Rotate image using exif orientation
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JpegloadBufferOptions> for VOption<'_> {
+    fn from(options: JpegloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.shrink {
+            option.add(
+                "shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegload_source, for [`VipsImage::jpegload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegloadSourceOptions {
+    /// Shrink factor on load
+    pub shrink: Option<i32>,
+    /// Rotate image using exif orientation
+    pub autorotate: Option<bool>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JpegloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shrink factor on load
+    pub fn shrink(mut self, shrink: i32) -> Self {
+        self.shrink = Some(shrink);
+        self
+    }
+
+    /// Rotate image using exif orientation
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JpegloadSourceOptions> for VOption<'_> {
+    fn from(options: JpegloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.shrink {
+            option.add(
+                "shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegsave, for [`VipsImage::jpegsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegsaveOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compute optimal Huffman coding tables
+    pub optimize_coding: Option<bool>,
+    /// Generate an interlaced (progressive) jpeg
+    pub interlace: Option<bool>,
+    /// Apply trellis quantisation to each 8x8 block
+    pub trellis_quant: Option<bool>,
+    /// Apply overshooting to samples with extreme values
+    pub overshoot_deringing: Option<bool>,
+    /// Split spectrum of DCT coefficients into separate scans
+    pub optimize_scans: Option<bool>,
+    /// Use predefined quantization table with given index
+    pub quant_table: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Add restart markers every specified number of mcu
+    pub restart_interval: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JpegsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compute optimal Huffman coding tables
+    pub fn optimize_coding(mut self, optimize_coding: bool) -> Self {
+        self.optimize_coding = Some(optimize_coding);
+        self
+    }
+
+    /// Generate an interlaced (progressive) jpeg
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Apply trellis quantisation to each 8x8 block
+    pub fn trellis_quant(mut self, trellis_quant: bool) -> Self {
+        self.trellis_quant = Some(trellis_quant);
+        self
+    }
+
+    /// Apply overshooting to samples with extreme values
+    pub fn overshoot_deringing(mut self, overshoot_deringing: bool) -> Self {
+        self.overshoot_deringing = Some(overshoot_deringing);
+        self
+    }
+
+    /// Split spectrum of DCT coefficients into separate scans
+    pub fn optimize_scans(mut self, optimize_scans: bool) -> Self {
+        self.optimize_scans = Some(optimize_scans);
+        self
+    }
+
+    /// Use predefined quantization table with given index
+    pub fn quant_table(mut self, quant_table: i32) -> Self {
+        self.quant_table = Some(quant_table);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Add restart markers every specified number of mcu
+    pub fn restart_interval(mut self, restart_interval: i32) -> Self {
+        self.restart_interval = Some(restart_interval);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JpegsaveOptions> for VOption<'_> {
+    fn from(options: JpegsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.optimize_coding {
+            option.add(
+                "optimize_coding",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.trellis_quant {
+            option.add(
+                "trellis_quant",
+                value,
+            );
+        }
+        if let Some(value) = options.overshoot_deringing {
+            option.add(
+                "overshoot_deringing",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_scans {
+            option.add(
+                "optimize_scans",
+                value,
+            );
+        }
+        if let Some(value) = options.quant_table {
+            option.add(
+                "quant_table",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.restart_interval {
+            option.add(
+                "restart_interval",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegsave_buffer, for [`VipsImage::jpegsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegsaveBufferOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compute optimal Huffman coding tables
+    pub optimize_coding: Option<bool>,
+    /// Generate an interlaced (progressive) jpeg
+    pub interlace: Option<bool>,
+    /// Apply trellis quantisation to each 8x8 block
+    pub trellis_quant: Option<bool>,
+    /// Apply overshooting to samples with extreme values
+    pub overshoot_deringing: Option<bool>,
+    /// Split spectrum of DCT coefficients into separate scans
+    pub optimize_scans: Option<bool>,
+    /// Use predefined quantization table with given index
+    pub quant_table: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Add restart markers every specified number of mcu
+    pub restart_interval: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JpegsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compute optimal Huffman coding tables
+    pub fn optimize_coding(mut self, optimize_coding: bool) -> Self {
+        self.optimize_coding = Some(optimize_coding);
+        self
+    }
+
+    /// Generate an interlaced (progressive) jpeg
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Apply trellis quantisation to each 8x8 block
+    pub fn trellis_quant(mut self, trellis_quant: bool) -> Self {
+        self.trellis_quant = Some(trellis_quant);
+        self
+    }
+
+    /// Apply overshooting to samples with extreme values
+    pub fn overshoot_deringing(mut self, overshoot_deringing: bool) -> Self {
+        self.overshoot_deringing = Some(overshoot_deringing);
+        self
+    }
+
+    /// Split spectrum of DCT coefficients into separate scans
+    pub fn optimize_scans(mut self, optimize_scans: bool) -> Self {
+        self.optimize_scans = Some(optimize_scans);
+        self
+    }
+
+    /// Use predefined quantization table with given index
+    pub fn quant_table(mut self, quant_table: i32) -> Self {
+        self.quant_table = Some(quant_table);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Add restart markers every specified number of mcu
+    pub fn restart_interval(mut self, restart_interval: i32) -> Self {
+        self.restart_interval = Some(restart_interval);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JpegsaveBufferOptions> for VOption<'_> {
+    fn from(options: JpegsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.optimize_coding {
+            option.add(
+                "optimize_coding",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.trellis_quant {
+            option.add(
+                "trellis_quant",
+                value,
+            );
+        }
+        if let Some(value) = options.overshoot_deringing {
+            option.add(
+                "overshoot_deringing",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_scans {
+            option.add(
+                "optimize_scans",
+                value,
+            );
+        }
+        if let Some(value) = options.quant_table {
+            option.add(
+                "quant_table",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.restart_interval {
+            option.add(
+                "restart_interval",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegsave_mime, for [`VipsImage::jpegsave_mime_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegsaveMimeOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compute optimal Huffman coding tables
+    pub optimize_coding: Option<bool>,
+    /// Generate an interlaced (progressive) jpeg
+    pub interlace: Option<bool>,
+    /// Apply trellis quantisation to each 8x8 block
+    pub trellis_quant: Option<bool>,
+    /// Apply overshooting to samples with extreme values
+    pub overshoot_deringing: Option<bool>,
+    /// Split spectrum of DCT coefficients into separate scans
+    pub optimize_scans: Option<bool>,
+    /// Use predefined quantization table with given index
+    pub quant_table: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Add restart markers every specified number of mcu
+    pub restart_interval: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JpegsaveMimeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compute optimal Huffman coding tables
+    pub fn optimize_coding(mut self, optimize_coding: bool) -> Self {
+        self.optimize_coding = Some(optimize_coding);
+        self
+    }
+
+    /// Generate an interlaced (progressive) jpeg
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Apply trellis quantisation to each 8x8 block
+    pub fn trellis_quant(mut self, trellis_quant: bool) -> Self {
+        self.trellis_quant = Some(trellis_quant);
+        self
+    }
+
+    /// Apply overshooting to samples with extreme values
+    pub fn overshoot_deringing(mut self, overshoot_deringing: bool) -> Self {
+        self.overshoot_deringing = Some(overshoot_deringing);
+        self
+    }
+
+    /// Split spectrum of DCT coefficients into separate scans
+    pub fn optimize_scans(mut self, optimize_scans: bool) -> Self {
+        self.optimize_scans = Some(optimize_scans);
+        self
+    }
+
+    /// Use predefined quantization table with given index
+    pub fn quant_table(mut self, quant_table: i32) -> Self {
+        self.quant_table = Some(quant_table);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Add restart markers every specified number of mcu
+    pub fn restart_interval(mut self, restart_interval: i32) -> Self {
+        self.restart_interval = Some(restart_interval);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JpegsaveMimeOptions> for VOption<'_> {
+    fn from(options: JpegsaveMimeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.optimize_coding {
+            option.add(
+                "optimize_coding",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.trellis_quant {
+            option.add(
+                "trellis_quant",
+                value,
+            );
+        }
+        if let Some(value) = options.overshoot_deringing {
+            option.add(
+                "overshoot_deringing",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_scans {
+            option.add(
+                "optimize_scans",
+                value,
+            );
+        }
+        if let Some(value) = options.quant_table {
+            option.add(
+                "quant_table",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.restart_interval {
+            option.add(
+                "restart_interval",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jpegsave_target, for [`VipsImage::jpegsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JpegsaveTargetOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compute optimal Huffman coding tables
+    pub optimize_coding: Option<bool>,
+    /// Generate an interlaced (progressive) jpeg
+    pub interlace: Option<bool>,
+    /// Apply trellis quantisation to each 8x8 block
+    pub trellis_quant: Option<bool>,
+    /// Apply overshooting to samples with extreme values
+    pub overshoot_deringing: Option<bool>,
+    /// Split spectrum of DCT coefficients into separate scans
+    pub optimize_scans: Option<bool>,
+    /// Use predefined quantization table with given index
+    pub quant_table: Option<i32>,
+    /// Select chroma subsample operation mode
+    pub subsample_mode: Option<ForeignSubsample>,
+    /// Add restart markers every specified number of mcu
+    pub restart_interval: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JpegsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compute optimal Huffman coding tables
+    pub fn optimize_coding(mut self, optimize_coding: bool) -> Self {
+        self.optimize_coding = Some(optimize_coding);
+        self
+    }
+
+    /// Generate an interlaced (progressive) jpeg
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// Apply trellis quantisation to each 8x8 block
+    pub fn trellis_quant(mut self, trellis_quant: bool) -> Self {
+        self.trellis_quant = Some(trellis_quant);
+        self
+    }
+
+    /// Apply overshooting to samples with extreme values
+    pub fn overshoot_deringing(mut self, overshoot_deringing: bool) -> Self {
+        self.overshoot_deringing = Some(overshoot_deringing);
+        self
+    }
+
+    /// Split spectrum of DCT coefficients into separate scans
+    pub fn optimize_scans(mut self, optimize_scans: bool) -> Self {
+        self.optimize_scans = Some(optimize_scans);
+        self
+    }
+
+    /// Use predefined quantization table with given index
+    pub fn quant_table(mut self, quant_table: i32) -> Self {
+        self.quant_table = Some(quant_table);
+        self
+    }
+
+    /// Select chroma subsample operation mode
+    pub fn subsample_mode(mut self, subsample_mode: ForeignSubsample) -> Self {
+        self.subsample_mode = Some(subsample_mode);
+        self
+    }
+
+    /// Add restart markers every specified number of mcu
+    pub fn restart_interval(mut self, restart_interval: i32) -> Self {
+        self.restart_interval = Some(restart_interval);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JpegsaveTargetOptions> for VOption<'_> {
+    fn from(options: JpegsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.optimize_coding {
+            option.add(
+                "optimize_coding",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.trellis_quant {
+            option.add(
+                "trellis_quant",
+                value,
+            );
+        }
+        if let Some(value) = options.overshoot_deringing {
+            option.add(
+                "overshoot_deringing",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_scans {
+            option.add(
+                "optimize_scans",
+                value,
+            );
+        }
+        if let Some(value) = options.quant_table {
+            option.add(
+                "quant_table",
+                value,
+            );
+        }
+        if let Some(value) = options.subsample_mode {
+            option.add(
+                "subsample_mode",
+                value,
+            );
+        }
+        if let Some(value) = options.restart_interval {
+            option.add(
+                "restart_interval",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlload, for [`VipsImage::jxlload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlloadOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JxlloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JxlloadOptions> for VOption<'_> {
+    fn from(options: JxlloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlload_buffer, for [`VipsImage::jxlload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlloadBufferOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JxlloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JxlloadBufferOptions> for VOption<'_> {
+    fn from(options: JxlloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlload_source, for [`VipsImage::jxlload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlloadSourceOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl JxlloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<JxlloadSourceOptions> for VOption<'_> {
+    fn from(options: JxlloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlsave, for [`VipsImage::jxlsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlsaveOptions {
+    /// Decode speed tier
+    pub tier: Option<i32>,
+    /// Target butteraugli distance
+    pub distance: Option<f64>,
+    /// Encoding effort
+    pub effort: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Quality factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JxlsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode speed tier
+    pub fn tier(mut self, tier: i32) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Target butteraugli distance
+    pub fn distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Encoding effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Quality factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JxlsaveOptions> for VOption<'_> {
+    fn from(options: JxlsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tier {
+            option.add(
+                "tier",
+                value,
+            );
+        }
+        if let Some(value) = options.distance {
+            option.add(
+                "distance",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlsave_buffer, for [`VipsImage::jxlsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlsaveBufferOptions {
+    /// Decode speed tier
+    pub tier: Option<i32>,
+    /// Target butteraugli distance
+    pub distance: Option<f64>,
+    /// Encoding effort
+    pub effort: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Quality factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JxlsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode speed tier
+    pub fn tier(mut self, tier: i32) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Target butteraugli distance
+    pub fn distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Encoding effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Quality factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JxlsaveBufferOptions> for VOption<'_> {
+    fn from(options: JxlsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tier {
+            option.add(
+                "tier",
+                value,
+            );
+        }
+        if let Some(value) = options.distance {
+            option.add(
+                "distance",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of jxlsave_target, for [`VipsImage::jxlsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct JxlsaveTargetOptions {
+    /// Decode speed tier
+    pub tier: Option<i32>,
+    /// Target butteraugli distance
+    pub distance: Option<f64>,
+    /// Encoding effort
+    pub effort: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Quality factor
+    pub q: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl JxlsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode speed tier
+    pub fn tier(mut self, tier: i32) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Target butteraugli distance
+    pub fn distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Encoding effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Quality factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<JxlsaveTargetOptions> for VOption<'_> {
+    fn from(options: JxlsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tier {
+            option.add(
+                "tier",
+                value,
+            );
+        }
+        if let Some(value) = options.distance {
+            option.add(
+                "distance",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of linear, for [`VipsImage::linear_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct LinearOptions {
+    /// Output should be uchar
+    pub uchar: Option<bool>,
+}
+
+impl LinearOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output should be uchar
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+}
+
+impl From<LinearOptions> for VOption<'_> {
+    fn from(options: LinearOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of linecache, for [`VipsImage::linecache_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct LinecacheOptions {
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Expected access pattern
+    pub access: Option<Access>,
+    /// Allow threaded access
+    pub threaded: Option<bool>,
+    /// Keep cache between evaluations
+    pub persistent: Option<bool>,
+}
+
+impl LinecacheOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Expected access pattern
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Allow threaded access
+    pub fn threaded(mut self, threaded: bool) -> Self {
+        self.threaded = Some(threaded);
+        self
+    }
+
+    /// Keep cache between evaluations
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = Some(persistent);
+        self
+    }
+}
+
+impl From<LinecacheOptions> for VOption<'_> {
+    fn from(options: LinecacheOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.threaded {
+            option.add(
+                "threaded",
+                value,
+            );
+        }
+        if let Some(value) = options.persistent {
+            option.add(
+                "persistent",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of logmat, for [`VipsImage::logmat_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct LogmatOptions {
+    /// Generate separable Gaussian
+    pub separable: Option<bool>,
+    /// Generate with this precision
+    pub precision: Option<Precision>,
+}
+
+impl LogmatOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate separable Gaussian
+    pub fn separable(mut self, separable: bool) -> Self {
+        self.separable = Some(separable);
+        self
+    }
+
+    /// Generate with this precision
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl From<LogmatOptions> for VOption<'_> {
+    fn from(options: LogmatOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.separable {
+            option.add(
+                "separable",
+                value,
+            );
+        }
+        if let Some(value) = options.precision {
+            option.add(
+                "precision",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of magickload, for [`VipsImage::magickload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MagickloadOptions {
+    /// Canvas resolution for rendering vector formats like SVG
+    pub density: Option<String>,
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl MagickloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canvas resolution for rendering vector formats like SVG
+    pub fn density(mut self, density: &str) -> Self {
+        self.density = Some(density.to_string());
+        self
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<MagickloadOptions> for VOption<'_> {
+    fn from(options: MagickloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.density {
+            option.add(
+                "density",
+                value,
+            );
+        }
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of magickload_buffer, for [`VipsImage::magickload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MagickloadBufferOptions {
+    /// Canvas resolution for rendering vector formats like SVG
+    pub density: Option<String>,
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl MagickloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canvas resolution for rendering vector formats like SVG
+    pub fn density(mut self, density: &str) -> Self {
+        self.density = Some(density.to_string());
+        self
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<MagickloadBufferOptions> for VOption<'_> {
+    fn from(options: MagickloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.density {
+            option.add(
+                "density",
+                value,
+            );
+        }
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of magicksave, for [`VipsImage::magicksave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MagicksaveOptions {
+    /// Format to save in
+    pub format: Option<String>,
+    /// Quality to use
+    pub quality: Option<i32>,
+    /// Apply GIF frames optimization
+    pub optimize_gif_frames: Option<bool>,
+    /// Apply GIF transparency optimization
+    pub optimize_gif_transparency: Option<bool>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl MagicksaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format to save in
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Quality to use
+    pub fn quality(mut self, quality: i32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Apply GIF frames optimization
+    pub fn optimize_gif_frames(mut self, optimize_gif_frames: bool) -> Self {
+        self.optimize_gif_frames = Some(optimize_gif_frames);
+        self
+    }
+
+    /// Apply GIF transparency optimization
+    pub fn optimize_gif_transparency(mut self, optimize_gif_transparency: bool) -> Self {
+        self.optimize_gif_transparency = Some(optimize_gif_transparency);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<MagicksaveOptions> for VOption<'_> {
+    fn from(options: MagicksaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.quality {
+            option.add(
+                "quality",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_gif_frames {
+            option.add(
+                "optimize_gif_frames",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_gif_transparency {
+            option.add(
+                "optimize_gif_transparency",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of magicksave_buffer, for [`VipsImage::magicksave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MagicksaveBufferOptions {
+    /// Format to save in
+    pub format: Option<String>,
+    /// Quality to use
+    pub quality: Option<i32>,
+    /// Apply GIF frames optimization
+    pub optimize_gif_frames: Option<bool>,
+    /// Apply GIF transparency optimization
+    pub optimize_gif_transparency: Option<bool>,
+    /// Number of bits per pixel
+    pub bitdepth: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl MagicksaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format to save in
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Quality to use
+    pub fn quality(mut self, quality: i32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Apply GIF frames optimization
+    pub fn optimize_gif_frames(mut self, optimize_gif_frames: bool) -> Self {
+        self.optimize_gif_frames = Some(optimize_gif_frames);
+        self
+    }
+
+    /// Apply GIF transparency optimization
+    pub fn optimize_gif_transparency(mut self, optimize_gif_transparency: bool) -> Self {
+        self.optimize_gif_transparency = Some(optimize_gif_transparency);
+        self
+    }
+
+    /// Number of bits per pixel
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<MagicksaveBufferOptions> for VOption<'_> {
+    fn from(options: MagicksaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.quality {
+            option.add(
+                "quality",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_gif_frames {
+            option.add(
+                "optimize_gif_frames",
+                value,
+            );
+        }
+        if let Some(value) = options.optimize_gif_transparency {
+            option.add(
+                "optimize_gif_transparency",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mapim, for [`VipsImage::mapim_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MapimOptions {
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Images have premultiplied alpha
+    pub premultiplied: Option<bool>,
+    /// How to generate the extra pixels
+    pub extend: Option<Extend>,
+}
+
+impl MapimOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Images have premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = Some(premultiplied);
+        self
+    }
+
+    /// How to generate the extra pixels
+    pub fn extend(mut self, extend: Extend) -> Self {
+        self.extend = Some(extend);
+        self
+    }
+}
+
+impl From<MapimOptions> for VOption<'_> {
+    fn from(options: MapimOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiplied {
+            option.add(
+                "premultiplied",
+                value,
+            );
+        }
+        if let Some(value) = options.extend {
+            option.add(
+                "extend",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of maplut, for [`VipsImage::maplut_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaplutOptions {
+    /// Apply one-band lut to this band of in
+    pub band: Option<i32>,
+}
+
+impl MaplutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one-band lut to this band of in
+    pub fn band(mut self, band: i32) -> Self {
+        self.band = Some(band);
+        self
+    }
+}
+
+impl From<MaplutOptions> for VOption<'_> {
+    fn from(options: MaplutOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.band {
+            option.add(
+                "band",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_butterworth, for [`VipsImage::mask_butterworth_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskButterworthOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskButterworthOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskButterworthOptions> for VOption<'_> {
+    fn from(options: MaskButterworthOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_butterworth_band, for [`VipsImage::mask_butterworth_band_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskButterworthBandOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskButterworthBandOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskButterworthBandOptions> for VOption<'_> {
+    fn from(options: MaskButterworthBandOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_butterworth_ring, for [`VipsImage::mask_butterworth_ring_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskButterworthRingOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskButterworthRingOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskButterworthRingOptions> for VOption<'_> {
+    fn from(options: MaskButterworthRingOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_fractal, for [`VipsImage::mask_fractal_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskFractalOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskFractalOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskFractalOptions> for VOption<'_> {
+    fn from(options: MaskFractalOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_gaussian, for [`VipsImage::mask_gaussian_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskGaussianOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskGaussianOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskGaussianOptions> for VOption<'_> {
+    fn from(options: MaskGaussianOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_gaussian_band, for [`VipsImage::mask_gaussian_band_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskGaussianBandOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskGaussianBandOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskGaussianBandOptions> for VOption<'_> {
+    fn from(options: MaskGaussianBandOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_gaussian_ring, for [`VipsImage::mask_gaussian_ring_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskGaussianRingOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskGaussianRingOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskGaussianRingOptions> for VOption<'_> {
+    fn from(options: MaskGaussianRingOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_ideal, for [`VipsImage::mask_ideal_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskIdealOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskIdealOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskIdealOptions> for VOption<'_> {
+    fn from(options: MaskIdealOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_ideal_band, for [`VipsImage::mask_ideal_band_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskIdealBandOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskIdealBandOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskIdealBandOptions> for VOption<'_> {
+    fn from(options: MaskIdealBandOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mask_ideal_ring, for [`VipsImage::mask_ideal_ring_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaskIdealRingOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Remove DC component
+    pub nodc: Option<bool>,
+    /// Invert the sense of the filter
+    pub reject: Option<bool>,
+    /// Rotate quadrants to optical space
+    pub optical: Option<bool>,
+}
+
+impl MaskIdealRingOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Remove DC component
+    pub fn nodc(mut self, nodc: bool) -> Self {
+        self.nodc = Some(nodc);
+        self
+    }
+
+    /// Invert the sense of the filter
+    pub fn reject(mut self, reject: bool) -> Self {
+        self.reject = Some(reject);
+        self
+    }
+
+    /// Rotate quadrants to optical space
+    pub fn optical(mut self, optical: bool) -> Self {
+        self.optical = Some(optical);
+        self
+    }
+}
+
+impl From<MaskIdealRingOptions> for VOption<'_> {
+    fn from(options: MaskIdealRingOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.nodc {
+            option.add(
+                "nodc",
+                value,
+            );
+        }
+        if let Some(value) = options.reject {
+            option.add(
+                "reject",
+                value,
+            );
+        }
+        if let Some(value) = options.optical {
+            option.add(
+                "optical",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of match, for [`VipsImage::matches_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatchesOptions {
+    /// Half window size
+    pub hwindow: Option<i32>,
+    /// Half area size
+    pub harea: Option<i32>,
+    /// Search to improve tie-points
+    pub search: Option<bool>,
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+}
+
+impl MatchesOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Half window size
+    pub fn hwindow(mut self, hwindow: i32) -> Self {
+        self.hwindow = Some(hwindow);
+        self
+    }
+
+    /// Half area size
+    pub fn harea(mut self, harea: i32) -> Self {
+        self.harea = Some(harea);
+        self
+    }
+
+    /// Search to improve tie-points
+    pub fn search(mut self, search: bool) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+}
+
+impl From<MatchesOptions> for VOption<'_> {
+    fn from(options: MatchesOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.hwindow {
+            option.add(
+                "hwindow",
+                value,
+            );
+        }
+        if let Some(value) = options.harea {
+            option.add(
+                "harea",
+                value,
+            );
+        }
+        if let Some(value) = options.search {
+            option.add(
+                "search",
+                value,
+            );
+        }
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matload, for [`VipsImage::matload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl MatloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<MatloadOptions> for VOption<'_> {
+    fn from(options: MatloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matrixload, for [`VipsImage::matrixload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl MatrixloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<MatrixloadOptions> for VOption<'_> {
+    fn from(options: MatrixloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matrixload_source, for [`VipsImage::matrixload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixloadSourceOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl MatrixloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<MatrixloadSourceOptions> for VOption<'_> {
+    fn from(options: MatrixloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matrixprint, for [`VipsImage::matrixprint_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixprintOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl MatrixprintOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<MatrixprintOptions> for VOption<'_> {
+    fn from(options: MatrixprintOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matrixsave, for [`VipsImage::matrixsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixsaveOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl MatrixsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<MatrixsaveOptions> for VOption<'_> {
+    fn from(options: MatrixsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of matrixsave_target, for [`VipsImage::matrixsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixsaveTargetOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl MatrixsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<MatrixsaveTargetOptions> for VOption<'_> {
+    fn from(options: MatrixsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of max, for [`VipsImage::max_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MaxOptions {
+    /// Number of maximum values to find
+    pub size: Option<i32>,
+}
+
+impl MaxOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of maximum values to find
+    pub fn size(mut self, size: i32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<MaxOptions> for VOption<'_> {
+    fn from(options: MaxOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of measure, for [`VipsImage::measure_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MeasureOptions {
+    /// Left edge of extract area
+    pub left: Option<i32>,
+    /// Top edge of extract area
+    pub top: Option<i32>,
+    /// Width of extract area
+    pub width: Option<i32>,
+    /// Height of extract area
+    pub height: Option<i32>,
+}
+
+impl MeasureOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Left edge of extract area
+    pub fn left(mut self, left: i32) -> Self {
+        self.left = Some(left);
+        self
+    }
+
+    /// Top edge of extract area
+    pub fn top(mut self, top: i32) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Width of extract area
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of extract area
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl From<MeasureOptions> for VOption<'_> {
+    fn from(options: MeasureOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.left {
+            option.add(
+                "left",
+                value,
+            );
+        }
+        if let Some(value) = options.top {
+            option.add(
+                "top", value,
+            );
+        }
+        if let Some(value) = options.width {
+            option.add(
+                "width",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of merge, for [`VipsImage::merge_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MergeOptions {
+    /// Maximum blend size
+    pub mblend: Option<i32>,
+}
+
+impl MergeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum blend size
+    pub fn mblend(mut self, mblend: i32) -> Self {
+        self.mblend = Some(mblend);
+        self
+    }
+}
+
+impl From<MergeOptions> for VOption<'_> {
+    fn from(options: MergeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.mblend {
+            option.add(
+                "mblend",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of min, for [`VipsImage::min_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MinOptions {
+    /// Number of minimum values to find
+    pub size: Option<i32>,
+}
+
+impl MinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of minimum values to find
+    pub fn size(mut self, size: i32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<MinOptions> for VOption<'_> {
+    fn from(options: MinOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mosaic1, for [`VipsImage::mosaic1_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Mosaic1Options {
+    /// Half window size
+    pub hwindow: Option<i32>,
+    /// Half area size
+    pub harea: Option<i32>,
+    /// Search to improve tie-points
+    pub search: Option<bool>,
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+    /// Maximum blend size
+    pub mblend: Option<i32>,
+}
+
+impl Mosaic1Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Half window size
+    pub fn hwindow(mut self, hwindow: i32) -> Self {
+        self.hwindow = Some(hwindow);
+        self
+    }
+
+    /// Half area size
+    pub fn harea(mut self, harea: i32) -> Self {
+        self.harea = Some(harea);
+        self
+    }
+
+    /// Search to improve tie-points
+    pub fn search(mut self, search: bool) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+
+    /// Maximum blend size
+    pub fn mblend(mut self, mblend: i32) -> Self {
+        self.mblend = Some(mblend);
+        self
+    }
+}
+
+impl From<Mosaic1Options> for VOption<'_> {
+    fn from(options: Mosaic1Options) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.hwindow {
+            option.add(
+                "hwindow",
+                value,
+            );
+        }
+        if let Some(value) = options.harea {
+            option.add(
+                "harea",
+                value,
+            );
+        }
+        if let Some(value) = options.search {
+            option.add(
+                "search",
+                value,
+            );
+        }
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        if let Some(value) = options.mblend {
+            option.add(
+                "mblend",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of mosaic, for [`VipsImage::mosaic_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MosaicOptions {
+    /// Half window size
+    pub hwindow: Option<i32>,
+    /// Half area size
+    pub harea: Option<i32>,
+    /// Maximum blend size
+    pub mblend: Option<i32>,
+    /// Band to search for features on
+    pub bandno: Option<i32>,
+}
+
+impl MosaicOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Half window size
+    pub fn hwindow(mut self, hwindow: i32) -> Self {
+        self.hwindow = Some(hwindow);
+        self
+    }
+
+    /// Half area size
+    pub fn harea(mut self, harea: i32) -> Self {
+        self.harea = Some(harea);
+        self
+    }
+
+    /// Maximum blend size
+    pub fn mblend(mut self, mblend: i32) -> Self {
+        self.mblend = Some(mblend);
+        self
+    }
+
+    /// Band to search for features on
+    pub fn bandno(mut self, bandno: i32) -> Self {
+        self.bandno = Some(bandno);
+        self
+    }
+}
+
+impl From<MosaicOptions> for VOption<'_> {
+    fn from(options: MosaicOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.hwindow {
+            option.add(
+                "hwindow",
+                value,
+            );
+        }
+        if let Some(value) = options.harea {
+            option.add(
+                "harea",
+                value,
+            );
+        }
+        if let Some(value) = options.mblend {
+            option.add(
+                "mblend",
+                value,
+            );
+        }
+        if let Some(value) = options.bandno {
+            option.add(
+                "bandno",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of msb, for [`VipsImage::msb_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MsbOptions {
+    /// Band to msb
+    pub band: Option<i32>,
+}
+
+impl MsbOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Band to msb
+    pub fn band(mut self, band: i32) -> Self {
+        self.band = Some(band);
+        self
+    }
+}
+
+impl From<MsbOptions> for VOption<'_> {
+    fn from(options: MsbOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.band {
+            option.add(
+                "band",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of openexrload, for [`VipsImage::openexrload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct OpenexrloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl OpenexrloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<OpenexrloadOptions> for VOption<'_> {
+    fn from(options: OpenexrloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of openslideload, for [`VipsImage::openslideload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct OpenslideloadOptions {
+    /// Load this level from the file
+    pub level: Option<i32>,
+    /// Crop to image bounds
+    pub autocrop: Option<bool>,
+    /// Load this associated image
+    pub associated: Option<String>,
+    /// Attach all associated images
+    pub attach_associated: Option<bool>,
+    /// Output RGB (not RGBA)
+    pub rgb: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl OpenslideloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this level from the file
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Crop to image bounds
+    pub fn autocrop(mut self, autocrop: bool) -> Self {
+        self.autocrop = Some(autocrop);
+        self
+    }
+
+    /// Load this associated image
+    pub fn associated(mut self, associated: &str) -> Self {
+        self.associated = Some(associated.to_string());
+        self
+    }
+
+    /// Attach all associated images
+    pub fn attach_associated(mut self, attach_associated: bool) -> Self {
+        self.attach_associated = Some(attach_associated);
+        self
+    }
+
+    /// Output RGB (not RGBA)
+    pub fn rgb(mut self, rgb: bool) -> Self {
+        self.rgb = Some(rgb);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<OpenslideloadOptions> for VOption<'_> {
+    fn from(options: OpenslideloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.level {
+            option.add(
+                "level",
+                value,
+            );
+        }
+        if let Some(value) = options.autocrop {
+            option.add(
+                "autocrop",
+                value,
+            );
+        }
+        if let Some(value) = options.associated {
+            option.add(
+                "associated",
+                value,
+            );
+        }
+        if let Some(value) = options.attach_associated {
+            option.add(
+                "attach_associated",
+                value,
+            );
+        }
+        if let Some(value) = options.rgb {
+            option.add(
+                "rgb", value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of openslideload_source, for [`VipsImage::openslideload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct OpenslideloadSourceOptions {
+    /// Load this level from the file
+    pub level: Option<i32>,
+    /// Crop to image bounds
+    pub autocrop: Option<bool>,
+    /// Load this associated image
+    pub associated: Option<String>,
+    /// Attach all associated images
+    pub attach_associated: Option<bool>,
+    /// Output RGB (not RGBA)
+    pub rgb: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl OpenslideloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this level from the file
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Crop to image bounds
+    pub fn autocrop(mut self, autocrop: bool) -> Self {
+        self.autocrop = Some(autocrop);
+        self
+    }
+
+    /// Load this associated image
+    pub fn associated(mut self, associated: &str) -> Self {
+        self.associated = Some(associated.to_string());
+        self
+    }
+
+    /// Attach all associated images
+    pub fn attach_associated(mut self, attach_associated: bool) -> Self {
+        self.attach_associated = Some(attach_associated);
+        self
+    }
+
+    /// Output RGB (not RGBA)
+    pub fn rgb(mut self, rgb: bool) -> Self {
+        self.rgb = Some(rgb);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<OpenslideloadSourceOptions> for VOption<'_> {
+    fn from(options: OpenslideloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.level {
+            option.add(
+                "level",
+                value,
+            );
+        }
+        if let Some(value) = options.autocrop {
+            option.add(
+                "autocrop",
+                value,
+            );
+        }
+        if let Some(value) = options.associated {
+            option.add(
+                "associated",
+                value,
+            );
+        }
+        if let Some(value) = options.attach_associated {
+            option.add(
+                "attach_associated",
+                value,
+            );
+        }
+        if let Some(value) = options.rgb {
+            option.add(
+                "rgb", value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pdfload, for [`VipsImage::pdfload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PdfloadOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// DPI to render at
+    pub dpi: Option<f64>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Background colour
+    pub background: Option<Vec<f64>>,
+    /// Password to decrypt with
+    pub password: Option<String>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PdfloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// DPI to render at
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Background colour
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Password to decrypt with
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PdfloadOptions> for VOption<'_> {
+    fn from(options: PdfloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.password {
+            option.add(
+                "password",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pdfload_buffer, for [`VipsImage::pdfload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PdfloadBufferOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// DPI to render at
+    pub dpi: Option<f64>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Background colour
+    pub background: Option<Vec<f64>>,
+    /// Password to decrypt with
+    pub password: Option<String>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PdfloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// DPI to render at
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Background colour
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Password to decrypt with
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PdfloadBufferOptions> for VOption<'_> {
+    fn from(options: PdfloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.password {
+            option.add(
+                "password",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pdfload_source, for [`VipsImage::pdfload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PdfloadSourceOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// DPI to render at
+    pub dpi: Option<f64>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Background colour
+    pub background: Option<Vec<f64>>,
+    /// Password to decrypt with
+    pub password: Option<String>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PdfloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// DPI to render at
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Background colour
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Password to decrypt with
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PdfloadSourceOptions> for VOption<'_> {
+    fn from(options: PdfloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.password {
+            option.add(
+                "password",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of perlin, for [`VipsImage::perlin_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PerlinOptions {
+    /// Size of Perlin cells
+    pub cell_size: Option<i32>,
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Random number seed
+    pub seed: Option<i32>,
+}
+
+impl PerlinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size of Perlin cells
+    pub fn cell_size(mut self, cell_size: i32) -> Self {
+        self.cell_size = Some(cell_size);
+        self
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Random number seed
+    pub fn seed(mut self, seed: i32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+impl From<PerlinOptions> for VOption<'_> {
+    fn from(options: PerlinOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.cell_size {
+            option.add(
+                "cell_size",
+                value,
+            );
+        }
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.seed {
+            option.add(
+                "seed",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngload, for [`VipsImage::pngload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngloadOptions {
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PngloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PngloadOptions> for VOption<'_> {
+    fn from(options: PngloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngload_buffer, for [`VipsImage::pngload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngloadBufferOptions {
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PngloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PngloadBufferOptions> for VOption<'_> {
+    fn from(options: PngloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngload_source, for [`VipsImage::pngload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngloadSourceOptions {
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PngloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PngloadSourceOptions> for VOption<'_> {
+    fn from(options: PngloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngsave, for [`VipsImage::pngsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngsaveOptions {
+    /// Compression factor
+    pub compression: Option<i32>,
+    /// Interlace image
+    pub interlace: Option<bool>,
+    /// libspng row filter flag(s)
+    pub filter: Option<ForeignPngFilter>,
+    /// Quantise to 8bpp palette
+    pub palette: Option<bool>,
+    /// Quantisation quality
+    pub q: Option<i32>,
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub bitdepth: Option<i32>,
+    /// Quantisation CPU effort
+    pub effort: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl PngsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression factor
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Interlace image
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// libspng row filter flag(s)
+    pub fn filter(mut self, filter: ForeignPngFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Quantise to 8bpp palette
+    pub fn palette(mut self, palette: bool) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Quantisation quality
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Quantisation CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<PngsaveOptions> for VOption<'_> {
+    fn from(options: PngsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.filter {
+            option.add(
+                "filter",
+                value,
+            );
+        }
+        if let Some(value) = options.palette {
+            option.add(
+                "palette",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngsave_buffer, for [`VipsImage::pngsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngsaveBufferOptions {
+    /// Compression factor
+    pub compression: Option<i32>,
+    /// Interlace image
+    pub interlace: Option<bool>,
+    /// libspng row filter flag(s)
+    pub filter: Option<ForeignPngFilter>,
+    /// Quantise to 8bpp palette
+    pub palette: Option<bool>,
+    /// Quantisation quality
+    pub q: Option<i32>,
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub bitdepth: Option<i32>,
+    /// Quantisation CPU effort
+    pub effort: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl PngsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression factor
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Interlace image
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// libspng row filter flag(s)
+    pub fn filter(mut self, filter: ForeignPngFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Quantise to 8bpp palette
+    pub fn palette(mut self, palette: bool) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Quantisation quality
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Quantisation CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<PngsaveBufferOptions> for VOption<'_> {
+    fn from(options: PngsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.filter {
+            option.add(
+                "filter",
+                value,
+            );
+        }
+        if let Some(value) = options.palette {
+            option.add(
+                "palette",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of pngsave_target, for [`VipsImage::pngsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PngsaveTargetOptions {
+    /// Compression factor
+    pub compression: Option<i32>,
+    /// Interlace image
+    pub interlace: Option<bool>,
+    /// libspng row filter flag(s)
+    pub filter: Option<ForeignPngFilter>,
+    /// Quantise to 8bpp palette
+    pub palette: Option<bool>,
+    /// Quantisation quality
+    pub q: Option<i32>,
+    /// Amount of dithering
+    pub dither: Option<f64>,
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub bitdepth: Option<i32>,
+    /// Quantisation CPU effort
+    pub effort: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl PngsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression factor
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Interlace image
+    pub fn interlace(mut self, interlace: bool) -> Self {
+        self.interlace = Some(interlace);
+        self
+    }
+
+    /// libspng row filter flag(s)
+    pub fn filter(mut self, filter: ForeignPngFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Quantise to 8bpp palette
+    pub fn palette(mut self, palette: bool) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Quantisation quality
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Amount of dithering
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    /// Write as a 1, 2, 4, 8 or 16 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Quantisation CPU effort
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<PngsaveTargetOptions> for VOption<'_> {
+    fn from(options: PngsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.interlace {
+            option.add(
+                "interlace",
+                value,
+            );
+        }
+        if let Some(value) = options.filter {
+            option.add(
+                "filter",
+                value,
+            );
+        }
+        if let Some(value) = options.palette {
+            option.add(
+                "palette",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.dither {
+            option.add(
+                "dither",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ppmload, for [`VipsImage::ppmload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PpmloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PpmloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PpmloadOptions> for VOption<'_> {
+    fn from(options: PpmloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ppmload_buffer, for [`VipsImage::ppmload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PpmloadBufferOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PpmloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PpmloadBufferOptions> for VOption<'_> {
+    fn from(options: PpmloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ppmload_source, for [`VipsImage::ppmload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PpmloadSourceOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl PpmloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<PpmloadSourceOptions> for VOption<'_> {
+    fn from(options: PpmloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ppmsave, for [`VipsImage::ppmsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PpmsaveOptions {
+    /// Format to save in
+    pub format: Option<ForeignPpmFormat>,
+    /// Save as ascii
+    pub ascii: Option<bool>,
+    /// Set to 1 to write as a 1 bit image
+    pub bitdepth: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl PpmsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format to save in
+    pub fn format(mut self, format: ForeignPpmFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Save as ascii
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = Some(ascii);
+        self
+    }
+
+    /// Set to 1 to write as a 1 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<PpmsaveOptions> for VOption<'_> {
+    fn from(options: PpmsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.ascii {
+            option.add(
+                "ascii",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of ppmsave_target, for [`VipsImage::ppmsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PpmsaveTargetOptions {
+    /// Format to save in
+    pub format: Option<ForeignPpmFormat>,
+    /// Save as ascii
+    pub ascii: Option<bool>,
+    /// Set to 1 to write as a 1 bit image
+    pub bitdepth: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl PpmsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format to save in
+    pub fn format(mut self, format: ForeignPpmFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Save as ascii
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = Some(ascii);
+        self
+    }
+
+    /// Set to 1 to write as a 1 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<PpmsaveTargetOptions> for VOption<'_> {
+    fn from(options: PpmsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.ascii {
+            option.add(
+                "ascii",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of premultiply, for [`VipsImage::premultiply_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PremultiplyOptions {
+    /// Maximum value of alpha channel
+    pub max_alpha: Option<f64>,
+}
+
+impl PremultiplyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum value of alpha channel
+    pub fn max_alpha(mut self, max_alpha: f64) -> Self {
+        self.max_alpha = Some(max_alpha);
+        self
+    }
+}
+
+impl From<PremultiplyOptions> for VOption<'_> {
+    fn from(options: PremultiplyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.max_alpha {
+            option.add(
+                "max_alpha",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of quadratic, for [`VipsImage::quadratic_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct QuadraticOptions {
+    /// Interpolate values with this
+    pub interpolate: Option<VipsInterpolate>,
+}
+
+impl QuadraticOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interpolate values with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+}
+
+impl From<QuadraticOptions> for VOption<'_> {
+    fn from(options: QuadraticOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radload, for [`VipsImage::radload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl RadloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<RadloadOptions> for VOption<'_> {
+    fn from(options: RadloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radload_buffer, for [`VipsImage::radload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadloadBufferOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl RadloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<RadloadBufferOptions> for VOption<'_> {
+    fn from(options: RadloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radload_source, for [`VipsImage::radload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadloadSourceOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl RadloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<RadloadSourceOptions> for VOption<'_> {
+    fn from(options: RadloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radsave, for [`VipsImage::radsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadsaveOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RadsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RadsaveOptions> for VOption<'_> {
+    fn from(options: RadsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radsave_buffer, for [`VipsImage::radsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadsaveBufferOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RadsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RadsaveBufferOptions> for VOption<'_> {
+    fn from(options: RadsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of radsave_target, for [`VipsImage::radsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RadsaveTargetOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RadsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RadsaveTargetOptions> for VOption<'_> {
+    fn from(options: RadsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rawload, for [`VipsImage::rawload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RawloadOptions {
+    /// Offset in bytes from start of file
+    pub offset: Option<u64>,
+    /// Pixel format in image
+    pub format: Option<BandFormat>,
+    /// Pixel interpretation
+    pub interpretation: Option<Interpretation>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl RawloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Offset in bytes from start of file
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Pixel format in image
+    pub fn format(mut self, format: BandFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Pixel interpretation
+    pub fn interpretation(mut self, interpretation: Interpretation) -> Self {
+        self.interpretation = Some(interpretation);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<RawloadOptions> for VOption<'_> {
+    fn from(options: RawloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.offset {
+            option.add(
+                "offset",
+                value,
+            );
+        }
+        if let Some(value) = options.format {
+            option.add(
+                "format",
+                value,
+            );
+        }
+        if let Some(value) = options.interpretation {
+            option.add(
+                "interpretation",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rawsave, for [`VipsImage::rawsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RawsaveOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RawsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RawsaveOptions> for VOption<'_> {
+    fn from(options: RawsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rawsave_buffer, for [`VipsImage::rawsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RawsaveBufferOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RawsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RawsaveBufferOptions> for VOption<'_> {
+    fn from(options: RawsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rawsave_target, for [`VipsImage::rawsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RawsaveTargetOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl RawsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<RawsaveTargetOptions> for VOption<'_> {
+    fn from(options: RawsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of reduce, for [`VipsImage::reduce_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ReduceOptions {
+    /// Resampling kernel
+    pub kernel: Option<Kernel>,
+    /// Reducing gap
+    pub gap: Option<f64>,
+}
+
+impl ReduceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resampling kernel
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = Some(kernel);
+        self
+    }
+
+    /// Reducing gap
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+}
+
+impl From<ReduceOptions> for VOption<'_> {
+    fn from(options: ReduceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.kernel {
+            option.add(
+                "kernel",
+                value,
+            );
+        }
+        if let Some(value) = options.gap {
+            option.add(
+                "gap", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of reduceh, for [`VipsImage::reduceh_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ReducehOptions {
+    /// Resampling kernel
+    pub kernel: Option<Kernel>,
+    /// Reducing gap
+    pub gap: Option<f64>,
+}
+
+impl ReducehOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resampling kernel
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = Some(kernel);
+        self
+    }
+
+    /// Reducing gap
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+}
+
+impl From<ReducehOptions> for VOption<'_> {
+    fn from(options: ReducehOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.kernel {
+            option.add(
+                "kernel",
+                value,
+            );
+        }
+        if let Some(value) = options.gap {
+            option.add(
+                "gap", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of reducev, for [`VipsImage::reducev_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ReducevOptions {
+    /// Resampling kernel
+    pub kernel: Option<Kernel>,
+    /// Reducing gap
+    pub gap: Option<f64>,
+}
+
+impl ReducevOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resampling kernel
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = Some(kernel);
+        self
+    }
+
+    /// Reducing gap
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+}
+
+impl From<ReducevOptions> for VOption<'_> {
+    fn from(options: ReducevOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.kernel {
+            option.add(
+                "kernel",
+                value,
+            );
+        }
+        if let Some(value) = options.gap {
+            option.add(
+                "gap", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of resize, for [`VipsImage::resize_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ResizeOptions {
+    /// Resampling kernel
+    pub kernel: Option<Kernel>,
+    /// Reducing gap
+    pub gap: Option<f64>,
+    /// Vertical scale image by this factor
+    pub vscale: Option<f64>,
+}
+
+impl ResizeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resampling kernel
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = Some(kernel);
+        self
+    }
+
+    /// Reducing gap
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    /// Vertical scale image by this factor
+    pub fn vscale(mut self, vscale: f64) -> Self {
+        self.vscale = Some(vscale);
+        self
+    }
+}
+
+impl From<ResizeOptions> for VOption<'_> {
+    fn from(options: ResizeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.kernel {
+            option.add(
+                "kernel",
+                value,
+            );
+        }
+        if let Some(value) = options.gap {
+            option.add(
+                "gap", value,
+            );
+        }
+        if let Some(value) = options.vscale {
+            option.add(
+                "vscale",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rot45, for [`VipsImage::rot45_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Rot45Options {
+    /// Angle to rotate image
+    pub angle: Option<Angle45>,
+}
+
+impl Rot45Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Angle to rotate image
+    pub fn angle(mut self, angle: Angle45) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+}
+
+impl From<Rot45Options> for VOption<'_> {
+    fn from(options: Rot45Options) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of rotate, for [`VipsImage::rotate_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RotateOptions {
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Horizontal output displacement
+    pub odx: Option<f64>,
+    /// Vertical output displacement
+    pub ody: Option<f64>,
+    /// Horizontal input displacement
+    pub idx: Option<f64>,
+    /// Vertical input displacement
+    pub idy: Option<f64>,
+}
+
+impl RotateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Horizontal output displacement
+    pub fn odx(mut self, odx: f64) -> Self {
+        self.odx = Some(odx);
+        self
+    }
+
+    /// Vertical output displacement
+    pub fn ody(mut self, ody: f64) -> Self {
+        self.ody = Some(ody);
+        self
+    }
+
+    /// Horizontal input displacement
+    pub fn idx(mut self, idx: f64) -> Self {
+        self.idx = Some(idx);
+        self
+    }
+
+    /// Vertical input displacement
+    pub fn idy(mut self, idy: f64) -> Self {
+        self.idy = Some(idy);
+        self
+    }
+}
+
+impl From<RotateOptions> for VOption<'_> {
+    fn from(options: RotateOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.odx {
+            option.add(
+                "odx", value,
+            );
+        }
+        if let Some(value) = options.ody {
+            option.add(
+                "ody", value,
+            );
+        }
+        if let Some(value) = options.idx {
+            option.add(
+                "idx", value,
+            );
+        }
+        if let Some(value) = options.idy {
+            option.add(
+                "idy", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of scRGB2BW, for [`VipsImage::scRGB2BW_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ScRGB2BWOptions {
+    /// Output device space depth in bits
+    pub depth: Option<i32>,
+}
+
+impl ScRGB2BWOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output device space depth in bits
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+}
+
+impl From<ScRGB2BWOptions> for VOption<'_> {
+    fn from(options: ScRGB2BWOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of scRGB2sRGB, for [`VipsImage::scRGB2sRGB_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ScRGB2sRGBOptions {
+    /// Output device space depth in bits
+    pub depth: Option<i32>,
+}
+
+impl ScRGB2sRGBOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output device space depth in bits
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+}
+
+impl From<ScRGB2sRGBOptions> for VOption<'_> {
+    fn from(options: ScRGB2sRGBOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of scale, for [`VipsImage::scale_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ScaleOptions {
+    /// Exponent for log scale
+    pub exp: Option<f64>,
+    /// Log scale
+    pub log: Option<bool>,
+}
+
+impl ScaleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exponent for log scale
+    pub fn exp(mut self, exp: f64) -> Self {
+        self.exp = Some(exp);
+        self
+    }
+
+    /// Log scale
+    pub fn log(mut self, log: bool) -> Self {
+        self.log = Some(log);
+        self
+    }
+}
+
+impl From<ScaleOptions> for VOption<'_> {
+    fn from(options: ScaleOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.exp {
+            option.add(
+                "exp", value,
+            );
+        }
+        if let Some(value) = options.log {
+            option.add(
+                "log", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of sdf, for [`VipsImage::sdf_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SdfOptions {
+    /// Radius
+    pub r: Option<f64>,
+    /// Point a
+    pub a: Option<Vec<f64>>,
+    /// Point b
+    pub b: Option<Vec<f64>>,
+    /// Corner radii
+    pub corners: Option<Vec<f64>>,
+}
+
+impl SdfOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Radius
+    pub fn r(mut self, r: f64) -> Self {
+        self.r = Some(r);
+        self
+    }
+
+    /// Point a
+    pub fn a(mut self, a: &[f64]) -> Self {
+        self.a = Some(a.to_vec());
+        self
+    }
+
+    /// Point b
+    pub fn b(mut self, b: &[f64]) -> Self {
+        self.b = Some(b.to_vec());
+        self
+    }
+
+    /// Corner radii
+    pub fn corners(mut self, corners: &[f64]) -> Self {
+        self.corners = Some(corners.to_vec());
+        self
+    }
+}
+
+impl From<SdfOptions> for VOption<'_> {
+    fn from(options: SdfOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.r {
+            option.add("r", value);
+        }
+        if let Some(value) = options.a {
+            option.add("a", value);
+        }
+        if let Some(value) = options.b {
+            option.add("b", value);
+        }
+        if let Some(value) = options.corners {
+            option.add(
+                "corners",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of sequential, for [`VipsImage::sequential_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SequentialOptions {
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+}
+
+impl SequentialOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+}
+
+impl From<SequentialOptions> for VOption<'_> {
+    fn from(options: SequentialOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of sharpen, for [`VipsImage::sharpen_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SharpenOptions {
+    /// Sigma of Gaussian
+    pub sigma: Option<f64>,
+    /// Flat/jaggy threshold
+    pub x1: Option<f64>,
+    /// Maximum brightening
+    pub y2: Option<f64>,
+    /// Maximum darkening
+    pub y3: Option<f64>,
+    /// Slope for flat areas
+    pub m1: Option<f64>,
+    /// Slope for jaggy areas
+    pub m2: Option<f64>,
+}
+
+impl SharpenOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sigma of Gaussian
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = Some(sigma);
+        self
+    }
+
+    /// Flat/jaggy threshold
+    pub fn x1(mut self, x1: f64) -> Self {
+        self.x1 = Some(x1);
+        self
+    }
+
+    /// Maximum brightening
+    pub fn y2(mut self, y2: f64) -> Self {
+        self.y2 = Some(y2);
+        self
+    }
+
+    /// Maximum darkening
+    pub fn y3(mut self, y3: f64) -> Self {
+        self.y3 = Some(y3);
+        self
+    }
+
+    /// Slope for flat areas
+    pub fn m1(mut self, m1: f64) -> Self {
+        self.m1 = Some(m1);
+        self
+    }
+
+    /// Slope for jaggy areas
+    pub fn m2(mut self, m2: f64) -> Self {
+        self.m2 = Some(m2);
+        self
+    }
+}
+
+impl From<SharpenOptions> for VOption<'_> {
+    fn from(options: SharpenOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.sigma {
+            option.add(
+                "sigma",
+                value,
+            );
+        }
+        if let Some(value) = options.x1 {
+            option.add(
+                "x1", value,
+            );
+        }
+        if let Some(value) = options.y2 {
+            option.add(
+                "y2", value,
+            );
+        }
+        if let Some(value) = options.y3 {
+            option.add(
+                "y3", value,
+            );
+        }
+        if let Some(value) = options.m1 {
+            option.add(
+                "m1", value,
+            );
+        }
+        if let Some(value) = options.m2 {
+            option.add(
+                "m2", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of shrink, for [`VipsImage::shrink_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ShrinkOptions {
+    /// Round-up output dimensions
+    pub ceil: Option<bool>,
+}
+
+impl ShrinkOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round-up output dimensions
+    pub fn ceil(mut self, ceil: bool) -> Self {
+        self.ceil = Some(ceil);
+        self
+    }
+}
+
+impl From<ShrinkOptions> for VOption<'_> {
+    fn from(options: ShrinkOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.ceil {
+            option.add(
+                "ceil",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of shrinkh, for [`VipsImage::shrinkh_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ShrinkhOptions {
+    /// Round-up output dimensions
+    pub ceil: Option<bool>,
+}
+
+impl ShrinkhOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round-up output dimensions
+    pub fn ceil(mut self, ceil: bool) -> Self {
+        self.ceil = Some(ceil);
+        self
+    }
+}
+
+impl From<ShrinkhOptions> for VOption<'_> {
+    fn from(options: ShrinkhOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.ceil {
+            option.add(
+                "ceil",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of shrinkv, for [`VipsImage::shrinkv_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ShrinkvOptions {
+    /// Round-up output dimensions
+    pub ceil: Option<bool>,
+}
+
+impl ShrinkvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round-up output dimensions
+    pub fn ceil(mut self, ceil: bool) -> Self {
+        self.ceil = Some(ceil);
+        self
+    }
+}
+
+impl From<ShrinkvOptions> for VOption<'_> {
+    fn from(options: ShrinkvOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.ceil {
+            option.add(
+                "ceil",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of similarity, for [`VipsImage::similarity_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SimilarityOptions {
+    /// Scale by this factor
+    pub scale: Option<f64>,
+    /// Rotate clockwise by this many degrees
+    pub angle: Option<f64>,
+    /// Interpolate pixels with this
+    pub interpolate: Option<VipsInterpolate>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Horizontal output displacement
+    pub odx: Option<f64>,
+    /// Vertical output displacement
+    pub ody: Option<f64>,
+    /// Horizontal input displacement
+    pub idx: Option<f64>,
+    /// Vertical input displacement
+    pub idy: Option<f64>,
+}
+
+impl SimilarityOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scale by this factor
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Rotate clockwise by this many degrees
+    pub fn angle(mut self, angle: f64) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Interpolate pixels with this
+    pub fn interpolate(mut self, interpolate: &VipsInterpolate) -> Self {
+        self.interpolate = Some(interpolate.clone());
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Horizontal output displacement
+    pub fn odx(mut self, odx: f64) -> Self {
+        self.odx = Some(odx);
+        self
+    }
+
+    /// Vertical output displacement
+    pub fn ody(mut self, ody: f64) -> Self {
+        self.ody = Some(ody);
+        self
+    }
+
+    /// Horizontal input displacement
+    pub fn idx(mut self, idx: f64) -> Self {
+        self.idx = Some(idx);
+        self
+    }
+
+    /// Vertical input displacement
+    pub fn idy(mut self, idy: f64) -> Self {
+        self.idy = Some(idy);
+        self
+    }
+}
+
+impl From<SimilarityOptions> for VOption<'_> {
+    fn from(options: SimilarityOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.angle {
+            option.add(
+                "angle",
+                value,
+            );
+        }
+        if let Some(value) = options.interpolate {
+            option.add(
+                "interpolate",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.odx {
+            option.add(
+                "odx", value,
+            );
+        }
+        if let Some(value) = options.ody {
+            option.add(
+                "ody", value,
+            );
+        }
+        if let Some(value) = options.idx {
+            option.add(
+                "idx", value,
+            );
+        }
+        if let Some(value) = options.idy {
+            option.add(
+                "idy", value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of sines, for [`VipsImage::sines_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SinesOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+    /// Horizontal spatial frequency
+    pub hfreq: Option<f64>,
+    /// Vertical spatial frequency
+    pub vfreq: Option<f64>,
+}
+
+impl SinesOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+
+    /// Horizontal spatial frequency
+    pub fn hfreq(mut self, hfreq: f64) -> Self {
+        self.hfreq = Some(hfreq);
+        self
+    }
+
+    /// Vertical spatial frequency
+    pub fn vfreq(mut self, vfreq: f64) -> Self {
+        self.vfreq = Some(vfreq);
+        self
+    }
+}
+
+impl From<SinesOptions> for VOption<'_> {
+    fn from(options: SinesOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        if let Some(value) = options.hfreq {
+            option.add(
+                "hfreq",
+                value,
+            );
+        }
+        if let Some(value) = options.vfreq {
+            option.add(
+                "vfreq",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of smartcrop, for [`VipsImage::smartcrop_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SmartcropOptions {
+    /// How to measure interestingness
+    pub interesting: Option<Interesting>,
+    /// Input image already has premultiplied alpha
+    pub premultiplied: Option<bool>,
+}
+
+impl SmartcropOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How to measure interestingness
+    pub fn interesting(mut self, interesting: Interesting) -> Self {
+        self.interesting = Some(interesting);
+        self
+    }
+
+    /// Input image already has premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = Some(premultiplied);
+        self
+    }
+}
+
+impl From<SmartcropOptions> for VOption<'_> {
+    fn from(options: SmartcropOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.interesting {
+            option.add(
+                "interesting",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiplied {
+            option.add(
+                "premultiplied",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of stdif, for [`VipsImage::stdif_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct StdifOptions {
+    /// New deviation
+    pub s0: Option<f64>,
+    /// Weight of new deviation
+    pub b: Option<f64>,
+    /// New mean
+    pub m0: Option<f64>,
+    /// Weight of new mean
+    pub a: Option<f64>,
+}
+
+impl StdifOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New deviation
+    pub fn s0(mut self, s0: f64) -> Self {
+        self.s0 = Some(s0);
+        self
+    }
+
+    /// Weight of new deviation
+    pub fn b(mut self, b: f64) -> Self {
+        self.b = Some(b);
+        self
+    }
+
+    /// New mean
+    pub fn m0(mut self, m0: f64) -> Self {
+        self.m0 = Some(m0);
+        self
+    }
+
+    /// Weight of new mean
+    pub fn a(mut self, a: f64) -> Self {
+        self.a = Some(a);
+        self
+    }
+}
+
+impl From<StdifOptions> for VOption<'_> {
+    fn from(options: StdifOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.s0 {
+            option.add(
+                "s0", value,
+            );
+        }
+        if let Some(value) = options.b {
+            option.add("b", value);
+        }
+        if let Some(value) = options.m0 {
+            option.add(
+                "m0", value,
+            );
+        }
+        if let Some(value) = options.a {
+            option.add("a", value);
+        }
+        option
+    }
+}
+
+/// Optional arguments of subsample, for [`VipsImage::subsample_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SubsampleOptions {
+    /// Point sample
+    pub point: Option<bool>,
+}
+
+impl SubsampleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Point sample
+    pub fn point(mut self, point: bool) -> Self {
+        self.point = Some(point);
+        self
+    }
+}
+
+impl From<SubsampleOptions> for VOption<'_> {
+    fn from(options: SubsampleOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.point {
+            option.add(
+                "point",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of svgload, for [`VipsImage::svgload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SvgloadOptions {
+    /// Render at this DPI
+    pub dpi: Option<f64>,
+    /// Scale output by this factor
+    pub scale: Option<f64>,
+    /// Allow SVG of any size
+    pub unlimited: Option<bool>,
+    /// Custom CSS
+    pub stylesheet: Option<String>,
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub high_bitdepth: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl SvgloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render at this DPI
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Scale output by this factor
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Allow SVG of any size
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Custom CSS
+    pub fn stylesheet(mut self, stylesheet: &str) -> Self {
+        self.stylesheet = Some(stylesheet.to_string());
+        self
+    }
+
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub fn high_bitdepth(mut self, high_bitdepth: bool) -> Self {
+        self.high_bitdepth = Some(high_bitdepth);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<SvgloadOptions> for VOption<'_> {
+    fn from(options: SvgloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.stylesheet {
+            option.add(
+                "stylesheet",
+                value,
+            );
+        }
+        if let Some(value) = options.high_bitdepth {
+            option.add(
+                "high_bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of svgload_buffer, for [`VipsImage::svgload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SvgloadBufferOptions {
+    /// Render at this DPI
+    pub dpi: Option<f64>,
+    /// Scale output by this factor
+    pub scale: Option<f64>,
+    /// Allow SVG of any size
+    pub unlimited: Option<bool>,
+    /// Custom CSS
+    pub stylesheet: Option<String>,
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub high_bitdepth: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl SvgloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render at this DPI
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Scale output by this factor
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Allow SVG of any size
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Custom CSS
+    pub fn stylesheet(mut self, stylesheet: &str) -> Self {
+        self.stylesheet = Some(stylesheet.to_string());
+        self
+    }
+
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub fn high_bitdepth(mut self, high_bitdepth: bool) -> Self {
+        self.high_bitdepth = Some(high_bitdepth);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<SvgloadBufferOptions> for VOption<'_> {
+    fn from(options: SvgloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.stylesheet {
+            option.add(
+                "stylesheet",
+                value,
+            );
+        }
+        if let Some(value) = options.high_bitdepth {
+            option.add(
+                "high_bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of svgload_source, for [`VipsImage::svgload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SvgloadSourceOptions {
+    /// Render at this DPI
+    pub dpi: Option<f64>,
+    /// Scale output by this factor
+    pub scale: Option<f64>,
+    /// Allow SVG of any size
+    pub unlimited: Option<bool>,
+    /// Custom CSS
+    pub stylesheet: Option<String>,
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub high_bitdepth: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl SvgloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render at this DPI
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Scale output by this factor
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Allow SVG of any size
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Custom CSS
+    pub fn stylesheet(mut self, stylesheet: &str) -> Self {
+        self.stylesheet = Some(stylesheet.to_string());
+        self
+    }
+
+    /// Enable scRGB 128-bit output (32-bit per channel)
+    pub fn high_bitdepth(mut self, high_bitdepth: bool) -> Self {
+        self.high_bitdepth = Some(high_bitdepth);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<SvgloadSourceOptions> for VOption<'_> {
+    fn from(options: SvgloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.stylesheet {
+            option.add(
+                "stylesheet",
+                value,
+            );
+        }
+        if let Some(value) = options.high_bitdepth {
+            option.add(
+                "high_bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of system, for [`VipsImage::system_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct SystemOptions {
+    /// Array of input images
+    pub inp: Option<Vec<VipsImage>>,
+    /// Format for output filename
+    pub out_format: Option<String>,
+    /// Format for input filename
+    pub in_format: Option<String>,
+}
+
+impl SystemOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Array of input images
+    pub fn inp(mut self, inp: &[VipsImage]) -> Self {
+        self.inp = Some(inp.to_vec());
+        self
+    }
+
+    /// Format for output filename
+    pub fn out_format(mut self, out_format: &str) -> Self {
+        self.out_format = Some(out_format.to_string());
+        self
+    }
+
+    /// Format for input filename
+    pub fn in_format(mut self, in_format: &str) -> Self {
+        self.in_format = Some(in_format.to_string());
+        self
+    }
+}
+
+impl From<SystemOptions> for VOption<'_> {
+    fn from(options: SystemOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.inp {
+            option.add(
+                "inp", value,
+            );
+        }
+        if let Some(value) = options.out_format {
+            option.add(
+                "out_format",
+                value,
+            );
+        }
+        if let Some(value) = options.in_format {
+            option.add(
+                "in_format",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of text, for [`VipsImage::text_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TextOptions {
+    /// Font to render with
+    pub font: Option<String>,
+    /// Maximum image width in pixels
+    pub width: Option<i32>,
+    /// Maximum image height in pixels
+    pub height: Option<i32>,
+    /// Align on the low, centre or high edge
+    pub align: Option<Align>,
+    /// Justify lines
+    pub justify: Option<bool>,
+    /// DPI to render at
+    pub dpi: Option<i32>,
+    /// Line spacing
+    pub spacing: Option<i32>,
+    /// Load this font file
+    pub fontfile: Option<String>,
+    /// Enable RGBA output
+    pub rgba: Option<bool>,
+    /// Wrap lines on word or character boundaries
+    pub wrap: Option<TextWrap>,
+}
+
+impl TextOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Font to render with
+    pub fn font(mut self, font: &str) -> Self {
+        self.font = Some(font.to_string());
+        self
+    }
+
+    /// Maximum image width in pixels
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Maximum image height in pixels
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Align on the low, centre or high edge
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Justify lines
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// DPI to render at
+    pub fn dpi(mut self, dpi: i32) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Line spacing
+    pub fn spacing(mut self, spacing: i32) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Load this font file
+    pub fn fontfile(mut self, fontfile: &str) -> Self {
+        self.fontfile = Some(fontfile.to_string());
+        self
+    }
+
+    /// Enable RGBA output
+    pub fn rgba(mut self, rgba: bool) -> Self {
+        self.rgba = Some(rgba);
+        self
+    }
+
+    /// Wrap lines on word or character boundaries
+    pub fn wrap(mut self, wrap: TextWrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+}
+
+impl From<TextOptions> for VOption<'_> {
+    fn from(options: TextOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.font {
+            option.add(
+                "font",
+                value,
+            );
+        }
+        if let Some(value) = options.width {
+            option.add(
+                "width",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.align {
+            option.add(
+                "align",
+                value,
+            );
+        }
+        if let Some(value) = options.justify {
+            option.add(
+                "justify",
+                value,
+            );
+        }
+        if let Some(value) = options.dpi {
+            option.add(
+                "dpi", value,
+            );
+        }
+        if let Some(value) = options.spacing {
+            option.add(
+                "spacing",
+                value,
+            );
+        }
+        if let Some(value) = options.fontfile {
+            option.add(
+                "fontfile",
+                value,
+            );
+        }
+        if let Some(value) = options.rgba {
+            option.add(
+                "rgba",
+                value,
+            );
+        }
+        if let Some(value) = options.wrap {
+            option.add(
+                "wrap",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of thumbnail, for [`VipsImage::thumbnail_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailOptions {
+    /// Size to this height
+    pub height: Option<i32>,
+    /// Only upsize, only downsize, or both
+    pub size: Option<Size>,
+    /// Don't use orientation tags to rotate image upright
+    pub no_rotate: Option<bool>,
+    /// Reduce to fill target rectangle, then crop
+    pub crop: Option<Interesting>,
+    /// Reduce in linear light
+    pub linear: Option<bool>,
+    /// Fallback input profile
+    pub input_profile: Option<String>,
+    /// Fallback output profile
+    pub output_profile: Option<String>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+}
+
+impl ThumbnailOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size to this height
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Only upsize, only downsize, or both
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Don't use orientation tags to rotate image upright
+    pub fn no_rotate(mut self, no_rotate: bool) -> Self {
+        self.no_rotate = Some(no_rotate);
+        self
+    }
+
+    /// Reduce to fill target rectangle, then crop
+    pub fn crop(mut self, crop: Interesting) -> Self {
+        self.crop = Some(crop);
+        self
+    }
+
+    /// Reduce in linear light
+    pub fn linear(mut self, linear: bool) -> Self {
+        self.linear = Some(linear);
+        self
+    }
+
+    /// Fallback input profile
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+
+    /// Fallback output profile
+    pub fn output_profile(mut self, output_profile: &str) -> Self {
+        self.output_profile = Some(output_profile.to_string());
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+}
+
+impl From<ThumbnailOptions> for VOption<'_> {
+    fn from(options: ThumbnailOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        if let Some(value) = options.no_rotate {
+            option.add(
+                "no_rotate",
+                value,
+            );
+        }
+        if let Some(value) = options.crop {
+            option.add(
+                "crop",
+                value,
+            );
+        }
+        if let Some(value) = options.linear {
+            option.add(
+                "linear",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.output_profile {
+            option.add(
+                "output_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of thumbnail_buffer, for [`VipsImage::thumbnail_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailBufferOptions {
+    /// Options that are passed on to the underlying loader
+    pub option_string: Option<String>,
+    /// Size to this height
+    pub height: Option<i32>,
+    /// Only upsize, only downsize, or both
+    pub size: Option<Size>,
+    /// Don't use orientation tags to rotate image upright
+    pub no_rotate: Option<bool>,
+    /// Reduce to fill target rectangle, then crop
+    pub crop: Option<Interesting>,
+    /// Reduce in linear light
+    pub linear: Option<bool>,
+    /// Fallback input profile
+    pub input_profile: Option<String>,
+    /// Fallback output profile
+    pub output_profile: Option<String>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+}
+
+impl ThumbnailBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options that are passed on to the underlying loader
+    pub fn option_string(mut self, option_string: &str) -> Self {
+        self.option_string = Some(option_string.to_string());
+        self
+    }
+
+    /// Size to this height
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Only upsize, only downsize, or both
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Don't use orientation tags to rotate image upright
+    pub fn no_rotate(mut self, no_rotate: bool) -> Self {
+        self.no_rotate = Some(no_rotate);
+        self
+    }
+
+    /// Reduce to fill target rectangle, then crop
+    pub fn crop(mut self, crop: Interesting) -> Self {
+        self.crop = Some(crop);
+        self
+    }
+
+    /// Reduce in linear light
+    pub fn linear(mut self, linear: bool) -> Self {
+        self.linear = Some(linear);
+        self
+    }
+
+    /// Fallback input profile
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+
+    /// Fallback output profile
+    pub fn output_profile(mut self, output_profile: &str) -> Self {
+        self.output_profile = Some(output_profile.to_string());
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+}
+
+impl From<ThumbnailBufferOptions> for VOption<'_> {
+    fn from(options: ThumbnailBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.option_string {
+            option.add(
+                "option_string",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        if let Some(value) = options.no_rotate {
+            option.add(
+                "no_rotate",
+                value,
+            );
+        }
+        if let Some(value) = options.crop {
+            option.add(
+                "crop",
+                value,
+            );
+        }
+        if let Some(value) = options.linear {
+            option.add(
+                "linear",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.output_profile {
+            option.add(
+                "output_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of thumbnail_image, for [`VipsImage::thumbnail_image_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailImageOptions {
+    /// Size to this height
+    pub height: Option<i32>,
+    /// Only upsize, only downsize, or both
+    pub size: Option<Size>,
+    /// Don't use orientation tags to rotate image upright
+    pub no_rotate: Option<bool>,
+    /// Reduce to fill target rectangle, then crop
+    pub crop: Option<Interesting>,
+    /// Reduce in linear light
+    pub linear: Option<bool>,
+    /// Fallback input profile
+    pub input_profile: Option<String>,
+    /// Fallback output profile
+    pub output_profile: Option<String>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+}
+
+impl ThumbnailImageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size to this height
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Only upsize, only downsize, or both
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Don't use orientation tags to rotate image upright
+    pub fn no_rotate(mut self, no_rotate: bool) -> Self {
+        self.no_rotate = Some(no_rotate);
+        self
+    }
+
+    /// Reduce to fill target rectangle, then crop
+    pub fn crop(mut self, crop: Interesting) -> Self {
+        self.crop = Some(crop);
+        self
+    }
+
+    /// Reduce in linear light
+    pub fn linear(mut self, linear: bool) -> Self {
+        self.linear = Some(linear);
+        self
+    }
+
+    /// Fallback input profile
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+
+    /// Fallback output profile
+    pub fn output_profile(mut self, output_profile: &str) -> Self {
+        self.output_profile = Some(output_profile.to_string());
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+}
+
+impl From<ThumbnailImageOptions> for VOption<'_> {
+    fn from(options: ThumbnailImageOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        if let Some(value) = options.no_rotate {
+            option.add(
+                "no_rotate",
+                value,
+            );
+        }
+        if let Some(value) = options.crop {
+            option.add(
+                "crop",
+                value,
+            );
+        }
+        if let Some(value) = options.linear {
+            option.add(
+                "linear",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.output_profile {
+            option.add(
+                "output_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of thumbnail_source, for [`VipsImage::thumbnail_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailSourceOptions {
+    /// Options that are passed on to the underlying loader
+    pub option_string: Option<String>,
+    /// Size to this height
+    pub height: Option<i32>,
+    /// Only upsize, only downsize, or both
+    pub size: Option<Size>,
+    /// Don't use orientation tags to rotate image upright
+    pub no_rotate: Option<bool>,
+    /// Reduce to fill target rectangle, then crop
+    pub crop: Option<Interesting>,
+    /// Reduce in linear light
+    pub linear: Option<bool>,
+    /// Fallback input profile
+    pub input_profile: Option<String>,
+    /// Fallback output profile
+    pub output_profile: Option<String>,
+    /// Rendering intent
+    pub intent: Option<Intent>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+}
+
+impl ThumbnailSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options that are passed on to the underlying loader
+    pub fn option_string(mut self, option_string: &str) -> Self {
+        self.option_string = Some(option_string.to_string());
+        self
+    }
+
+    /// Size to this height
+    pub fn height(mut self, height: i32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Only upsize, only downsize, or both
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Don't use orientation tags to rotate image upright
+    pub fn no_rotate(mut self, no_rotate: bool) -> Self {
+        self.no_rotate = Some(no_rotate);
+        self
+    }
+
+    /// Reduce to fill target rectangle, then crop
+    pub fn crop(mut self, crop: Interesting) -> Self {
+        self.crop = Some(crop);
+        self
+    }
+
+    /// Reduce in linear light
+    pub fn linear(mut self, linear: bool) -> Self {
+        self.linear = Some(linear);
+        self
+    }
+
+    /// Fallback input profile
+    pub fn input_profile(mut self, input_profile: &str) -> Self {
+        self.input_profile = Some(input_profile.to_string());
+        self
+    }
+
+    /// Fallback output profile
+    pub fn output_profile(mut self, output_profile: &str) -> Self {
+        self.output_profile = Some(output_profile.to_string());
+        self
+    }
+
+    /// Rendering intent
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+}
+
+impl From<ThumbnailSourceOptions> for VOption<'_> {
+    fn from(options: ThumbnailSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.option_string {
+            option.add(
+                "option_string",
+                value,
+            );
+        }
+        if let Some(value) = options.height {
+            option.add(
+                "height",
+                value,
+            );
+        }
+        if let Some(value) = options.size {
+            option.add(
+                "size",
+                value,
+            );
+        }
+        if let Some(value) = options.no_rotate {
+            option.add(
+                "no_rotate",
+                value,
+            );
+        }
+        if let Some(value) = options.crop {
+            option.add(
+                "crop",
+                value,
+            );
+        }
+        if let Some(value) = options.linear {
+            option.add(
+                "linear",
+                value,
+            );
+        }
+        if let Some(value) = options.input_profile {
+            option.add(
+                "input_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.output_profile {
+            option.add(
+                "output_profile",
+                value,
+            );
+        }
+        if let Some(value) = options.intent {
+            option.add(
+                "intent",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffload, for [`VipsImage::tiffload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffloadOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Rotate image using orientation tag
+    pub autorotate: Option<bool>,
+    /// Subifd index
+    pub subifd: Option<i32>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl TiffloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Rotate image using orientation tag
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Subifd index
+    pub fn subifd(mut self, subifd: i32) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<TiffloadOptions> for VOption<'_> {
+    fn from(options: TiffloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffload_buffer, for [`VipsImage::tiffload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffloadBufferOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Rotate image using orientation tag
+    pub autorotate: Option<bool>,
+    /// Subifd index
+    pub subifd: Option<i32>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl TiffloadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Rotate image using orientation tag
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Subifd index
+    pub fn subifd(mut self, subifd: i32) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<TiffloadBufferOptions> for VOption<'_> {
+    fn from(options: TiffloadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffload_source, for [`VipsImage::tiffload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffloadSourceOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Rotate image using orientation tag
+    pub autorotate: Option<bool>,
+    /// Subifd index
+    pub subifd: Option<i32>,
+    /// Remove all denial of service limits
+    pub unlimited: Option<bool>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl TiffloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Rotate image using orientation tag
+    pub fn autorotate(mut self, autorotate: bool) -> Self {
+        self.autorotate = Some(autorotate);
+        self
+    }
+
+    /// Subifd index
+    pub fn subifd(mut self, subifd: i32) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Remove all denial of service limits
+    pub fn unlimited(mut self, unlimited: bool) -> Self {
+        self.unlimited = Some(unlimited);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<TiffloadSourceOptions> for VOption<'_> {
+    fn from(options: TiffloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.autorotate {
+            option.add(
+                "autorotate",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.unlimited {
+            option.add(
+                "unlimited",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffsave, for [`VipsImage::tiffsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffsaveOptions {
+    /// Compression for this file
+    pub compression: Option<ForeignTiffCompression>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compression prediction
+    pub predictor: Option<ForeignTiffPredictor>,
+    /// Write a tiled tiff
+    pub tile: Option<bool>,
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Write a pyramidal tiff
+    pub pyramid: Option<bool>,
+    /// Use 0 for white in 1-bit images
+    pub miniswhite: Option<bool>,
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub bitdepth: Option<i32>,
+    /// Resolution unit
+    pub resunit: Option<ForeignTiffResunit>,
+    /// Horizontal resolution in pixels/mm
+    pub xres: Option<f64>,
+    /// Vertical resolution in pixels/mm
+    pub yres: Option<f64>,
+    /// Write a bigtiff image
+    pub bigtiff: Option<bool>,
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub properties: Option<bool>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub level: Option<i32>,
+    /// Enable WEBP lossless mode
+    pub lossless: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Save pyr layers as sub-IFDs
+    pub subifd: Option<bool>,
+    /// Save with premultiplied alpha
+    pub premultiply: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl TiffsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression for this file
+    pub fn compression(mut self, compression: ForeignTiffCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compression prediction
+    pub fn predictor(mut self, predictor: ForeignTiffPredictor) -> Self {
+        self.predictor = Some(predictor);
+        self
+    }
+
+    /// Write a tiled tiff
+    pub fn tile(mut self, tile: bool) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Write a pyramidal tiff
+    pub fn pyramid(mut self, pyramid: bool) -> Self {
+        self.pyramid = Some(pyramid);
+        self
+    }
+
+    /// Use 0 for white in 1-bit images
+    pub fn miniswhite(mut self, miniswhite: bool) -> Self {
+        self.miniswhite = Some(miniswhite);
+        self
+    }
+
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Resolution unit
+    pub fn resunit(mut self, resunit: ForeignTiffResunit) -> Self {
+        self.resunit = Some(resunit);
+        self
+    }
+
+    /// Horizontal resolution in pixels/mm
+    pub fn xres(mut self, xres: f64) -> Self {
+        self.xres = Some(xres);
+        self
+    }
+
+    /// Vertical resolution in pixels/mm
+    pub fn yres(mut self, yres: f64) -> Self {
+        self.yres = Some(yres);
+        self
+    }
+
+    /// Write a bigtiff image
+    pub fn bigtiff(mut self, bigtiff: bool) -> Self {
+        self.bigtiff = Some(bigtiff);
+        self
+    }
+
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub fn properties(mut self, properties: bool) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Enable WEBP lossless mode
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Save pyr layers as sub-IFDs
+    pub fn subifd(mut self, subifd: bool) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Save with premultiplied alpha
+    pub fn premultiply(mut self, premultiply: bool) -> Self {
+        self.premultiply = Some(premultiply);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<TiffsaveOptions> for VOption<'_> {
+    fn from(options: TiffsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.predictor {
+            option.add(
+                "predictor",
+                value,
+            );
+        }
+        if let Some(value) = options.tile {
+            option.add(
+                "tile",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.pyramid {
+            option.add(
+                "pyramid",
+                value,
+            );
+        }
+        if let Some(value) = options.miniswhite {
+            option.add(
+                "miniswhite",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.resunit {
+            option.add(
+                "resunit",
+                value,
+            );
+        }
+        if let Some(value) = options.xres {
+            option.add(
+                "xres",
+                value,
+            );
+        }
+        if let Some(value) = options.yres {
+            option.add(
+                "yres",
+                value,
+            );
+        }
+        if let Some(value) = options.bigtiff {
+            option.add(
+                "bigtiff",
+                value,
+            );
+        }
+        if let Some(value) = options.properties {
+            option.add(
+                "properties",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.level {
+            option.add(
+                "level",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiply {
+            option.add(
+                "premultiply",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffsave_buffer, for [`VipsImage::tiffsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffsaveBufferOptions {
+    /// Compression for this file
+    pub compression: Option<ForeignTiffCompression>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compression prediction
+    pub predictor: Option<ForeignTiffPredictor>,
+    /// Write a tiled tiff
+    pub tile: Option<bool>,
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Write a pyramidal tiff
+    pub pyramid: Option<bool>,
+    /// Use 0 for white in 1-bit images
+    pub miniswhite: Option<bool>,
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub bitdepth: Option<i32>,
+    /// Resolution unit
+    pub resunit: Option<ForeignTiffResunit>,
+    /// Horizontal resolution in pixels/mm
+    pub xres: Option<f64>,
+    /// Vertical resolution in pixels/mm
+    pub yres: Option<f64>,
+    /// Write a bigtiff image
+    pub bigtiff: Option<bool>,
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub properties: Option<bool>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub level: Option<i32>,
+    /// Enable WEBP lossless mode
+    pub lossless: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Save pyr layers as sub-IFDs
+    pub subifd: Option<bool>,
+    /// Save with premultiplied alpha
+    pub premultiply: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl TiffsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression for this file
+    pub fn compression(mut self, compression: ForeignTiffCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compression prediction
+    pub fn predictor(mut self, predictor: ForeignTiffPredictor) -> Self {
+        self.predictor = Some(predictor);
+        self
+    }
+
+    /// Write a tiled tiff
+    pub fn tile(mut self, tile: bool) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Write a pyramidal tiff
+    pub fn pyramid(mut self, pyramid: bool) -> Self {
+        self.pyramid = Some(pyramid);
+        self
+    }
+
+    /// Use 0 for white in 1-bit images
+    pub fn miniswhite(mut self, miniswhite: bool) -> Self {
+        self.miniswhite = Some(miniswhite);
+        self
+    }
+
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Resolution unit
+    pub fn resunit(mut self, resunit: ForeignTiffResunit) -> Self {
+        self.resunit = Some(resunit);
+        self
+    }
+
+    /// Horizontal resolution in pixels/mm
+    pub fn xres(mut self, xres: f64) -> Self {
+        self.xres = Some(xres);
+        self
+    }
+
+    /// Vertical resolution in pixels/mm
+    pub fn yres(mut self, yres: f64) -> Self {
+        self.yres = Some(yres);
+        self
+    }
+
+    /// Write a bigtiff image
+    pub fn bigtiff(mut self, bigtiff: bool) -> Self {
+        self.bigtiff = Some(bigtiff);
+        self
+    }
+
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub fn properties(mut self, properties: bool) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Enable WEBP lossless mode
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Save pyr layers as sub-IFDs
+    pub fn subifd(mut self, subifd: bool) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Save with premultiplied alpha
+    pub fn premultiply(mut self, premultiply: bool) -> Self {
+        self.premultiply = Some(premultiply);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<TiffsaveBufferOptions> for VOption<'_> {
+    fn from(options: TiffsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.predictor {
+            option.add(
+                "predictor",
+                value,
+            );
+        }
+        if let Some(value) = options.tile {
+            option.add(
+                "tile",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.pyramid {
+            option.add(
+                "pyramid",
+                value,
+            );
+        }
+        if let Some(value) = options.miniswhite {
+            option.add(
+                "miniswhite",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.resunit {
+            option.add(
+                "resunit",
+                value,
+            );
+        }
+        if let Some(value) = options.xres {
+            option.add(
+                "xres",
+                value,
+            );
+        }
+        if let Some(value) = options.yres {
+            option.add(
+                "yres",
+                value,
+            );
+        }
+        if let Some(value) = options.bigtiff {
+            option.add(
+                "bigtiff",
+                value,
+            );
+        }
+        if let Some(value) = options.properties {
+            option.add(
+                "properties",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.level {
+            option.add(
+                "level",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiply {
+            option.add(
+                "premultiply",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tiffsave_target, for [`VipsImage::tiffsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TiffsaveTargetOptions {
+    /// Compression for this file
+    pub compression: Option<ForeignTiffCompression>,
+    /// Q factor
+    pub q: Option<i32>,
+    /// Compression prediction
+    pub predictor: Option<ForeignTiffPredictor>,
+    /// Write a tiled tiff
+    pub tile: Option<bool>,
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Write a pyramidal tiff
+    pub pyramid: Option<bool>,
+    /// Use 0 for white in 1-bit images
+    pub miniswhite: Option<bool>,
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub bitdepth: Option<i32>,
+    /// Resolution unit
+    pub resunit: Option<ForeignTiffResunit>,
+    /// Horizontal resolution in pixels/mm
+    pub xres: Option<f64>,
+    /// Vertical resolution in pixels/mm
+    pub yres: Option<f64>,
+    /// Write a bigtiff image
+    pub bigtiff: Option<bool>,
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub properties: Option<bool>,
+    /// Method to shrink regions
+    pub region_shrink: Option<RegionShrink>,
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub level: Option<i32>,
+    /// Enable WEBP lossless mode
+    pub lossless: Option<bool>,
+    /// Pyramid depth
+    pub depth: Option<ForeignDzDepth>,
+    /// Save pyr layers as sub-IFDs
+    pub subifd: Option<bool>,
+    /// Save with premultiplied alpha
+    pub premultiply: Option<bool>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl TiffsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression for this file
+    pub fn compression(mut self, compression: ForeignTiffCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Compression prediction
+    pub fn predictor(mut self, predictor: ForeignTiffPredictor) -> Self {
+        self.predictor = Some(predictor);
+        self
+    }
+
+    /// Write a tiled tiff
+    pub fn tile(mut self, tile: bool) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Write a pyramidal tiff
+    pub fn pyramid(mut self, pyramid: bool) -> Self {
+        self.pyramid = Some(pyramid);
+        self
+    }
+
+    /// Use 0 for white in 1-bit images
+    pub fn miniswhite(mut self, miniswhite: bool) -> Self {
+        self.miniswhite = Some(miniswhite);
+        self
+    }
+
+    /// Write as a 1, 2, 4 or 8 bit image
+    pub fn bitdepth(mut self, bitdepth: i32) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// Resolution unit
+    pub fn resunit(mut self, resunit: ForeignTiffResunit) -> Self {
+        self.resunit = Some(resunit);
+        self
+    }
+
+    /// Horizontal resolution in pixels/mm
+    pub fn xres(mut self, xres: f64) -> Self {
+        self.xres = Some(xres);
+        self
+    }
+
+    /// Vertical resolution in pixels/mm
+    pub fn yres(mut self, yres: f64) -> Self {
+        self.yres = Some(yres);
+        self
+    }
+
+    /// Write a bigtiff image
+    pub fn bigtiff(mut self, bigtiff: bool) -> Self {
+        self.bigtiff = Some(bigtiff);
+        self
+    }
+
+    /// Write a properties document to IMAGEDESCRIPTION
+    pub fn properties(mut self, properties: bool) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Method to shrink regions
+    pub fn region_shrink(mut self, region_shrink: RegionShrink) -> Self {
+        self.region_shrink = Some(region_shrink);
+        self
+    }
+
+    /// Deflate (1-9, default 6) or ZSTD (1-22, default 9) compression level
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Enable WEBP lossless mode
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Pyramid depth
+    pub fn depth(mut self, depth: ForeignDzDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Save pyr layers as sub-IFDs
+    pub fn subifd(mut self, subifd: bool) -> Self {
+        self.subifd = Some(subifd);
+        self
+    }
+
+    /// Save with premultiplied alpha
+    pub fn premultiply(mut self, premultiply: bool) -> Self {
+        self.premultiply = Some(premultiply);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<TiffsaveTargetOptions> for VOption<'_> {
+    fn from(options: TiffsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.compression {
+            option.add(
+                "compression",
+                value,
+            );
+        }
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.predictor {
+            option.add(
+                "predictor",
+                value,
+            );
+        }
+        if let Some(value) = options.tile {
+            option.add(
+                "tile",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.pyramid {
+            option.add(
+                "pyramid",
+                value,
+            );
+        }
+        if let Some(value) = options.miniswhite {
+            option.add(
+                "miniswhite",
+                value,
+            );
+        }
+        if let Some(value) = options.bitdepth {
+            option.add(
+                "bitdepth",
+                value,
+            );
+        }
+        if let Some(value) = options.resunit {
+            option.add(
+                "resunit",
+                value,
+            );
+        }
+        if let Some(value) = options.xres {
+            option.add(
+                "xres",
+                value,
+            );
+        }
+        if let Some(value) = options.yres {
+            option.add(
+                "yres",
+                value,
+            );
+        }
+        if let Some(value) = options.bigtiff {
+            option.add(
+                "bigtiff",
+                value,
+            );
+        }
+        if let Some(value) = options.properties {
+            option.add(
+                "properties",
+                value,
+            );
+        }
+        if let Some(value) = options.region_shrink {
+            option.add(
+                "region_shrink",
+                value,
+            );
+        }
+        if let Some(value) = options.level {
+            option.add(
+                "level",
+                value,
+            );
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.depth {
+            option.add(
+                "depth",
+                value,
+            );
+        }
+        if let Some(value) = options.subifd {
+            option.add(
+                "subifd",
+                value,
+            );
+        }
+        if let Some(value) = options.premultiply {
+            option.add(
+                "premultiply",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tilecache, for [`VipsImage::tilecache_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TilecacheOptions {
+    /// Tile width in pixels
+    pub tile_width: Option<i32>,
+    /// Tile height in pixels
+    pub tile_height: Option<i32>,
+    /// Maximum number of tiles to cache
+    pub max_tiles: Option<i32>,
+    /// Expected access pattern
+    pub access: Option<Access>,
+    /// Allow threaded access
+    pub threaded: Option<bool>,
+    /// Keep cache between evaluations
+    pub persistent: Option<bool>,
+}
+
+impl TilecacheOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tile width in pixels
+    pub fn tile_width(mut self, tile_width: i32) -> Self {
+        self.tile_width = Some(tile_width);
+        self
+    }
+
+    /// Tile height in pixels
+    pub fn tile_height(mut self, tile_height: i32) -> Self {
+        self.tile_height = Some(tile_height);
+        self
+    }
+
+    /// Maximum number of tiles to cache
+    pub fn max_tiles(mut self, max_tiles: i32) -> Self {
+        self.max_tiles = Some(max_tiles);
+        self
+    }
+
+    /// Expected access pattern
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Allow threaded access
+    pub fn threaded(mut self, threaded: bool) -> Self {
+        self.threaded = Some(threaded);
+        self
+    }
+
+    /// Keep cache between evaluations
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = Some(persistent);
+        self
+    }
+}
+
+impl From<TilecacheOptions> for VOption<'_> {
+    fn from(options: TilecacheOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.tile_width {
+            option.add(
+                "tile_width",
+                value,
+            );
+        }
+        if let Some(value) = options.tile_height {
+            option.add(
+                "tile_height",
+                value,
+            );
+        }
+        if let Some(value) = options.max_tiles {
+            option.add(
+                "max_tiles",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.threaded {
+            option.add(
+                "threaded",
+                value,
+            );
+        }
+        if let Some(value) = options.persistent {
+            option.add(
+                "persistent",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of tonelut, for [`VipsImage::tonelut_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct TonelutOptions {
+    /// Size of LUT to build
+    pub in_max: Option<i32>,
+    /// Maximum value in output LUT
+    pub out_max: Option<i32>,
+    /// Lowest value in output
+    pub lb: Option<f64>,
+    /// Highest value in output
+    pub lw: Option<f64>,
+    /// Position of shadow
+    pub ps: Option<f64>,
+    /// Position of mid-tones
+    pub pm: Option<f64>,
+    /// Position of highlights
+    pub ph: Option<f64>,
+    /// Adjust shadows by this much
+    pub s: Option<f64>,
+    /// Adjust mid-tones by this much
+    pub m: Option<f64>,
+    /// Adjust highlights by this much
+    pub h: Option<f64>,
+}
+
+impl TonelutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size of LUT to build
+    pub fn in_max(mut self, in_max: i32) -> Self {
+        self.in_max = Some(in_max);
+        self
+    }
+
+    /// Maximum value in output LUT
+    pub fn out_max(mut self, out_max: i32) -> Self {
+        self.out_max = Some(out_max);
+        self
+    }
+
+    /// Lowest value in output
+    pub fn lb(mut self, lb: f64) -> Self {
+        self.lb = Some(lb);
+        self
+    }
+
+    /// Highest value in output
+    pub fn lw(mut self, lw: f64) -> Self {
+        self.lw = Some(lw);
+        self
+    }
+
+    /// Position of shadow
+    pub fn ps(mut self, ps: f64) -> Self {
+        self.ps = Some(ps);
+        self
+    }
+
+    /// Position of mid-tones
+    pub fn pm(mut self, pm: f64) -> Self {
+        self.pm = Some(pm);
+        self
+    }
+
+    /// Position of highlights
+    pub fn ph(mut self, ph: f64) -> Self {
+        self.ph = Some(ph);
+        self
+    }
+
+    /// Adjust shadows by this much
+    pub fn s(mut self, s: f64) -> Self {
+        self.s = Some(s);
+        self
+    }
+
+    /// Adjust mid-tones by this much
+    pub fn m(mut self, m: f64) -> Self {
+        self.m = Some(m);
+        self
+    }
+
+    /// Adjust highlights by this much
+    pub fn h(mut self, h: f64) -> Self {
+        self.h = Some(h);
+        self
+    }
+}
+
+impl From<TonelutOptions> for VOption<'_> {
+    fn from(options: TonelutOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.in_max {
+            option.add(
+                "in_max",
+                value,
+            );
+        }
+        if let Some(value) = options.out_max {
+            option.add(
+                "out_max",
+                value,
+            );
+        }
+        if let Some(value) = options.lb {
+            option.add(
+                "Lb", value,
+            );
+        }
+        if let Some(value) = options.lw {
+            option.add(
+                "Lw", value,
+            );
+        }
+        if let Some(value) = options.ps {
+            option.add(
+                "Ps", value,
+            );
+        }
+        if let Some(value) = options.pm {
+            option.add(
+                "Pm", value,
+            );
+        }
+        if let Some(value) = options.ph {
+            option.add(
+                "Ph", value,
+            );
+        }
+        if let Some(value) = options.s {
+            option.add("S", value);
+        }
+        if let Some(value) = options.m {
+            option.add("M", value);
+        }
+        if let Some(value) = options.h {
+            option.add("H", value);
+        }
+        option
+    }
+}
+
+/// Optional arguments of transpose3d, for [`VipsImage::transpose3d_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Transpose3dOptions {
+    /// Height of each input page
+    pub page_height: Option<i32>,
+}
+
+impl Transpose3dOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Height of each input page
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+}
+
+impl From<Transpose3dOptions> for VOption<'_> {
+    fn from(options: Transpose3dOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of unpremultiply, for [`VipsImage::unpremultiply_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct UnpremultiplyOptions {
+    /// Maximum value of alpha channel
+    pub max_alpha: Option<f64>,
+    /// Unpremultiply with this alpha
+    pub alpha_band: Option<i32>,
+}
+
+impl UnpremultiplyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum value of alpha channel
+    pub fn max_alpha(mut self, max_alpha: f64) -> Self {
+        self.max_alpha = Some(max_alpha);
+        self
+    }
+
+    /// Unpremultiply with this alpha
+    pub fn alpha_band(mut self, alpha_band: i32) -> Self {
+        self.alpha_band = Some(alpha_band);
+        self
+    }
+}
+
+impl From<UnpremultiplyOptions> for VOption<'_> {
+    fn from(options: UnpremultiplyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.max_alpha {
+            option.add(
+                "max_alpha",
+                value,
+            );
+        }
+        if let Some(value) = options.alpha_band {
+            option.add(
+                "alpha_band",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of vipsload, for [`VipsImage::vipsload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct VipsloadOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl VipsloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<VipsloadOptions> for VOption<'_> {
+    fn from(options: VipsloadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of vipsload_source, for [`VipsImage::vipsload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct VipsloadSourceOptions {
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl VipsloadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<VipsloadSourceOptions> for VOption<'_> {
+    fn from(options: VipsloadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of vipssave, for [`VipsImage::vipssave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct VipssaveOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl VipssaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<VipssaveOptions> for VOption<'_> {
+    fn from(options: VipssaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of vipssave_target, for [`VipsImage::vipssave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct VipssaveTargetOptions {
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl VipssaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<VipssaveTargetOptions> for VOption<'_> {
+    fn from(options: VipssaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpload, for [`VipsImage::webpload_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebploadOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl WebploadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<WebploadOptions> for VOption<'_> {
+    fn from(options: WebploadOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpload_buffer, for [`VipsImage::webpload_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebploadBufferOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl WebploadBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<WebploadBufferOptions> for VOption<'_> {
+    fn from(options: WebploadBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpload_source, for [`VipsImage::webpload_source_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebploadSourceOptions {
+    /// First page to load
+    pub page: Option<i32>,
+    /// Number of pages to load, -1 for all
+    pub n: Option<i32>,
+    /// Factor to scale by
+    pub scale: Option<f64>,
+    /// Flags for this file
+    pub flags: Option<ForeignFlags>,
+    /// Force open via memory
+    pub memory: Option<bool>,
+    /// Required access pattern for this file
+    pub access: Option<Access>,
+    /// Error level to fail on
+    pub fail_on: Option<FailOn>,
+    /// Don't use a cached result for this operation
+    pub revalidate: Option<bool>,
+}
+
+impl WebploadSourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First page to load
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of pages to load, -1 for all
+    pub fn n(mut self, n: i32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Factor to scale by
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Flags for this file
+    pub fn flags(mut self, flags: ForeignFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Force open via memory
+    pub fn memory(mut self, memory: bool) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Required access pattern for this file
+    pub fn access(mut self, access: Access) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    /// Error level to fail on
+    pub fn fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Don't use a cached result for this operation
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = Some(revalidate);
+        self
+    }
+}
+
+impl From<WebploadSourceOptions> for VOption<'_> {
+    fn from(options: WebploadSourceOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.page {
+            option.add(
+                "page",
+                value,
+            );
+        }
+        if let Some(value) = options.n {
+            option.add("n", value);
+        }
+        if let Some(value) = options.scale {
+            option.add(
+                "scale",
+                value,
+            );
+        }
+        if let Some(value) = options.flags {
+            option.add(
+                "flags",
+                value,
+            );
+        }
+        if let Some(value) = options.memory {
+            option.add(
+                "memory",
+                value,
+            );
+        }
+        if let Some(value) = options.access {
+            option.add(
+                "access",
+                value,
+            );
+        }
+        if let Some(value) = options.fail_on {
+            option.add(
+                "fail_on",
+                value,
+            );
+        }
+        if let Some(value) = options.revalidate {
+            option.add(
+                "revalidate",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpsave, for [`VipsImage::webpsave_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebpsaveOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Preset for lossy compression
+    pub preset: Option<ForeignWebpPreset>,
+    /// Enable high quality chroma subsampling
+    pub smart_subsample: Option<bool>,
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub near_lossless: Option<bool>,
+    /// Change alpha plane fidelity for lossy compression
+    pub alpha_q: Option<i32>,
+    /// Optimise for minimum size
+    pub min_size: Option<bool>,
+    /// Minimum number of frames between key frames
+    pub kmin: Option<i32>,
+    /// Maximum number of frames between key frames
+    pub kmax: Option<i32>,
+    /// Level of CPU effort to reduce file size
+    pub effort: Option<i32>,
+    /// Desired target size in bytes
+    pub target_size: Option<i32>,
+    /// Allow mixed encoding (might reduce file size)
+    pub mixed: Option<bool>,
+    /// Enable auto-adjusting of the deblocking filter
+    pub smart_deblock: Option<bool>,
+    /// Number of entropy-analysis passes (in [1..10])
+    pub passes: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl WebpsaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Preset for lossy compression
+    pub fn preset(mut self, preset: ForeignWebpPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Enable high quality chroma subsampling
+    pub fn smart_subsample(mut self, smart_subsample: bool) -> Self {
+        self.smart_subsample = Some(smart_subsample);
+        self
+    }
+
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub fn near_lossless(mut self, near_lossless: bool) -> Self {
+        self.near_lossless = Some(near_lossless);
+        self
+    }
+
+    /// Change alpha plane fidelity for lossy compression
+    pub fn alpha_q(mut self, alpha_q: i32) -> Self {
+        self.alpha_q = Some(alpha_q);
+        self
+    }
+
+    /// Optimise for minimum size
+    pub fn min_size(mut self, min_size: bool) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Minimum number of frames between key frames
+    pub fn kmin(mut self, kmin: i32) -> Self {
+        self.kmin = Some(kmin);
+        self
+    }
+
+    /// Maximum number of frames between key frames
+    pub fn kmax(mut self, kmax: i32) -> Self {
+        self.kmax = Some(kmax);
+        self
+    }
+
+    /// Level of CPU effort to reduce file size
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Desired target size in bytes
+    pub fn target_size(mut self, target_size: i32) -> Self {
+        self.target_size = Some(target_size);
+        self
+    }
+
+    /// Allow mixed encoding (might reduce file size)
+    pub fn mixed(mut self, mixed: bool) -> Self {
+        self.mixed = Some(mixed);
+        self
+    }
+
+    /// Enable auto-adjusting of the deblocking filter
+    pub fn smart_deblock(mut self, smart_deblock: bool) -> Self {
+        self.smart_deblock = Some(smart_deblock);
+        self
+    }
+
+    /// Number of entropy-analysis passes (in [1..10])
+    pub fn passes(mut self, passes: i32) -> Self {
+        self.passes = Some(passes);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<WebpsaveOptions> for VOption<'_> {
+    fn from(options: WebpsaveOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.preset {
+            option.add(
+                "preset",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_subsample {
+            option.add(
+                "smart_subsample",
+                value,
+            );
+        }
+        if let Some(value) = options.near_lossless {
+            option.add(
+                "near_lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.alpha_q {
+            option.add(
+                "alpha_q",
+                value,
+            );
+        }
+        if let Some(value) = options.min_size {
+            option.add(
+                "min_size",
+                value,
+            );
+        }
+        if let Some(value) = options.kmin {
+            option.add(
+                "kmin",
+                value,
+            );
+        }
+        if let Some(value) = options.kmax {
+            option.add(
+                "kmax",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.target_size {
+            option.add(
+                "target_size",
+                value,
+            );
+        }
+        if let Some(value) = options.mixed {
+            option.add(
+                "mixed",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_deblock {
+            option.add(
+                "smart_deblock",
+                value,
+            );
+        }
+        if let Some(value) = options.passes {
+            option.add(
+                "passes",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpsave_buffer, for [`VipsImage::webpsave_buffer_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebpsaveBufferOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Preset for lossy compression
+    pub preset: Option<ForeignWebpPreset>,
+    /// Enable high quality chroma subsampling
+    pub smart_subsample: Option<bool>,
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub near_lossless: Option<bool>,
+    /// Change alpha plane fidelity for lossy compression
+    pub alpha_q: Option<i32>,
+    /// Optimise for minimum size
+    pub min_size: Option<bool>,
+    /// Minimum number of frames between key frames
+    pub kmin: Option<i32>,
+    /// Maximum number of frames between key frames
+    pub kmax: Option<i32>,
+    /// Level of CPU effort to reduce file size
+    pub effort: Option<i32>,
+    /// Desired target size in bytes
+    pub target_size: Option<i32>,
+    /// Allow mixed encoding (might reduce file size)
+    pub mixed: Option<bool>,
+    /// Enable auto-adjusting of the deblocking filter
+    pub smart_deblock: Option<bool>,
+    /// Number of entropy-analysis passes (in [1..10])
+    pub passes: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl WebpsaveBufferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Preset for lossy compression
+    pub fn preset(mut self, preset: ForeignWebpPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Enable high quality chroma subsampling
+    pub fn smart_subsample(mut self, smart_subsample: bool) -> Self {
+        self.smart_subsample = Some(smart_subsample);
+        self
+    }
+
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub fn near_lossless(mut self, near_lossless: bool) -> Self {
+        self.near_lossless = Some(near_lossless);
+        self
+    }
+
+    /// Change alpha plane fidelity for lossy compression
+    pub fn alpha_q(mut self, alpha_q: i32) -> Self {
+        self.alpha_q = Some(alpha_q);
+        self
+    }
+
+    /// Optimise for minimum size
+    pub fn min_size(mut self, min_size: bool) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Minimum number of frames between key frames
+    pub fn kmin(mut self, kmin: i32) -> Self {
+        self.kmin = Some(kmin);
+        self
+    }
+
+    /// Maximum number of frames between key frames
+    pub fn kmax(mut self, kmax: i32) -> Self {
+        self.kmax = Some(kmax);
+        self
+    }
+
+    /// Level of CPU effort to reduce file size
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Desired target size in bytes
+    pub fn target_size(mut self, target_size: i32) -> Self {
+        self.target_size = Some(target_size);
+        self
+    }
+
+    /// Allow mixed encoding (might reduce file size)
+    pub fn mixed(mut self, mixed: bool) -> Self {
+        self.mixed = Some(mixed);
+        self
+    }
+
+    /// Enable auto-adjusting of the deblocking filter
+    pub fn smart_deblock(mut self, smart_deblock: bool) -> Self {
+        self.smart_deblock = Some(smart_deblock);
+        self
+    }
+
+    /// Number of entropy-analysis passes (in [1..10])
+    pub fn passes(mut self, passes: i32) -> Self {
+        self.passes = Some(passes);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<WebpsaveBufferOptions> for VOption<'_> {
+    fn from(options: WebpsaveBufferOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.preset {
+            option.add(
+                "preset",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_subsample {
+            option.add(
+                "smart_subsample",
+                value,
+            );
+        }
+        if let Some(value) = options.near_lossless {
+            option.add(
+                "near_lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.alpha_q {
+            option.add(
+                "alpha_q",
+                value,
+            );
+        }
+        if let Some(value) = options.min_size {
+            option.add(
+                "min_size",
+                value,
+            );
+        }
+        if let Some(value) = options.kmin {
+            option.add(
+                "kmin",
+                value,
+            );
+        }
+        if let Some(value) = options.kmax {
+            option.add(
+                "kmax",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.target_size {
+            option.add(
+                "target_size",
+                value,
+            );
+        }
+        if let Some(value) = options.mixed {
+            option.add(
+                "mixed",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_deblock {
+            option.add(
+                "smart_deblock",
+                value,
+            );
+        }
+        if let Some(value) = options.passes {
+            option.add(
+                "passes",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpsave_mime, for [`VipsImage::webpsave_mime_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebpsaveMimeOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Preset for lossy compression
+    pub preset: Option<ForeignWebpPreset>,
+    /// Enable high quality chroma subsampling
+    pub smart_subsample: Option<bool>,
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub near_lossless: Option<bool>,
+    /// Change alpha plane fidelity for lossy compression
+    pub alpha_q: Option<i32>,
+    /// Optimise for minimum size
+    pub min_size: Option<bool>,
+    /// Minimum number of frames between key frames
+    pub kmin: Option<i32>,
+    /// Maximum number of frames between key frames
+    pub kmax: Option<i32>,
+    /// Level of CPU effort to reduce file size
+    pub effort: Option<i32>,
+    /// Desired target size in bytes
+    pub target_size: Option<i32>,
+    /// Allow mixed encoding (might reduce file size)
+    pub mixed: Option<bool>,
+    /// Enable auto-adjusting of the deblocking filter
+    pub smart_deblock: Option<bool>,
+    /// Number of entropy-analysis passes (in [1..10])
+    pub passes: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl WebpsaveMimeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Preset for lossy compression
+    pub fn preset(mut self, preset: ForeignWebpPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Enable high quality chroma subsampling
+    pub fn smart_subsample(mut self, smart_subsample: bool) -> Self {
+        self.smart_subsample = Some(smart_subsample);
+        self
+    }
+
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub fn near_lossless(mut self, near_lossless: bool) -> Self {
+        self.near_lossless = Some(near_lossless);
+        self
+    }
+
+    /// Change alpha plane fidelity for lossy compression
+    pub fn alpha_q(mut self, alpha_q: i32) -> Self {
+        self.alpha_q = Some(alpha_q);
+        self
+    }
+
+    /// Optimise for minimum size
+    pub fn min_size(mut self, min_size: bool) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Minimum number of frames between key frames
+    pub fn kmin(mut self, kmin: i32) -> Self {
+        self.kmin = Some(kmin);
+        self
+    }
+
+    /// Maximum number of frames between key frames
+    pub fn kmax(mut self, kmax: i32) -> Self {
+        self.kmax = Some(kmax);
+        self
+    }
+
+    /// Level of CPU effort to reduce file size
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Desired target size in bytes
+    pub fn target_size(mut self, target_size: i32) -> Self {
+        self.target_size = Some(target_size);
+        self
+    }
+
+    /// Allow mixed encoding (might reduce file size)
+    pub fn mixed(mut self, mixed: bool) -> Self {
+        self.mixed = Some(mixed);
+        self
+    }
+
+    /// Enable auto-adjusting of the deblocking filter
+    pub fn smart_deblock(mut self, smart_deblock: bool) -> Self {
+        self.smart_deblock = Some(smart_deblock);
+        self
+    }
+
+    /// Number of entropy-analysis passes (in [1..10])
+    pub fn passes(mut self, passes: i32) -> Self {
+        self.passes = Some(passes);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<WebpsaveMimeOptions> for VOption<'_> {
+    fn from(options: WebpsaveMimeOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.preset {
+            option.add(
+                "preset",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_subsample {
+            option.add(
+                "smart_subsample",
+                value,
+            );
+        }
+        if let Some(value) = options.near_lossless {
+            option.add(
+                "near_lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.alpha_q {
+            option.add(
+                "alpha_q",
+                value,
+            );
+        }
+        if let Some(value) = options.min_size {
+            option.add(
+                "min_size",
+                value,
+            );
+        }
+        if let Some(value) = options.kmin {
+            option.add(
+                "kmin",
+                value,
+            );
+        }
+        if let Some(value) = options.kmax {
+            option.add(
+                "kmax",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.target_size {
+            option.add(
+                "target_size",
+                value,
+            );
+        }
+        if let Some(value) = options.mixed {
+            option.add(
+                "mixed",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_deblock {
+            option.add(
+                "smart_deblock",
+                value,
+            );
+        }
+        if let Some(value) = options.passes {
+            option.add(
+                "passes",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of webpsave_target, for [`VipsImage::webpsave_target_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WebpsaveTargetOptions {
+    /// Q factor
+    pub q: Option<i32>,
+    /// Enable lossless compression
+    pub lossless: Option<bool>,
+    /// Preset for lossy compression
+    pub preset: Option<ForeignWebpPreset>,
+    /// Enable high quality chroma subsampling
+    pub smart_subsample: Option<bool>,
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub near_lossless: Option<bool>,
+    /// Change alpha plane fidelity for lossy compression
+    pub alpha_q: Option<i32>,
+    /// Optimise for minimum size
+    pub min_size: Option<bool>,
+    /// Minimum number of frames between key frames
+    pub kmin: Option<i32>,
+    /// Maximum number of frames between key frames
+    pub kmax: Option<i32>,
+    /// Level of CPU effort to reduce file size
+    pub effort: Option<i32>,
+    /// Desired target size in bytes
+    pub target_size: Option<i32>,
+    /// Allow mixed encoding (might reduce file size)
+    pub mixed: Option<bool>,
+    /// Enable auto-adjusting of the deblocking filter
+    pub smart_deblock: Option<bool>,
+    /// Number of entropy-analysis passes (in [1..10])
+    pub passes: Option<i32>,
+    /// Which metadata to retain
+    pub keep: Option<ForeignKeep>,
+    /// Background value
+    pub background: Option<Vec<f64>>,
+    /// Set page height for multipage save
+    pub page_height: Option<i32>,
+    /// Filename of ICC profile to embed
+    pub profile: Option<String>,
+}
+
+impl WebpsaveTargetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Q factor
+    pub fn q(mut self, q: i32) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Enable lossless compression
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = Some(lossless);
+        self
+    }
+
+    /// Preset for lossy compression
+    pub fn preset(mut self, preset: ForeignWebpPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Enable high quality chroma subsampling
+    pub fn smart_subsample(mut self, smart_subsample: bool) -> Self {
+        self.smart_subsample = Some(smart_subsample);
+        self
+    }
+
+    /// Enable preprocessing in lossless mode (uses Q)
+    pub fn near_lossless(mut self, near_lossless: bool) -> Self {
+        self.near_lossless = Some(near_lossless);
+        self
+    }
+
+    /// Change alpha plane fidelity for lossy compression
+    pub fn alpha_q(mut self, alpha_q: i32) -> Self {
+        self.alpha_q = Some(alpha_q);
+        self
+    }
+
+    /// Optimise for minimum size
+    pub fn min_size(mut self, min_size: bool) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Minimum number of frames between key frames
+    pub fn kmin(mut self, kmin: i32) -> Self {
+        self.kmin = Some(kmin);
+        self
+    }
+
+    /// Maximum number of frames between key frames
+    pub fn kmax(mut self, kmax: i32) -> Self {
+        self.kmax = Some(kmax);
+        self
+    }
+
+    /// Level of CPU effort to reduce file size
+    pub fn effort(mut self, effort: i32) -> Self {
+        self.effort = Some(effort);
+        self
+    }
+
+    /// Desired target size in bytes
+    pub fn target_size(mut self, target_size: i32) -> Self {
+        self.target_size = Some(target_size);
+        self
+    }
+
+    /// Allow mixed encoding (might reduce file size)
+    pub fn mixed(mut self, mixed: bool) -> Self {
+        self.mixed = Some(mixed);
+        self
+    }
+
+    /// Enable auto-adjusting of the deblocking filter
+    pub fn smart_deblock(mut self, smart_deblock: bool) -> Self {
+        self.smart_deblock = Some(smart_deblock);
+        self
+    }
+
+    /// Number of entropy-analysis passes (in [1..10])
+    pub fn passes(mut self, passes: i32) -> Self {
+        self.passes = Some(passes);
+        self
+    }
+
+    /// Which metadata to retain
+    pub fn keep(mut self, keep: ForeignKeep) -> Self {
+        self.keep = Some(keep);
+        self
+    }
+
+    /// Background value
+    pub fn background(mut self, background: &[f64]) -> Self {
+        self.background = Some(background.to_vec());
+        self
+    }
+
+    /// Set page height for multipage save
+    pub fn page_height(mut self, page_height: i32) -> Self {
+        self.page_height = Some(page_height);
+        self
+    }
+
+    /// Filename of ICC profile to embed
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+impl From<WebpsaveTargetOptions> for VOption<'_> {
+    fn from(options: WebpsaveTargetOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.q {
+            option.add("Q", value);
+        }
+        if let Some(value) = options.lossless {
+            option.add(
+                "lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.preset {
+            option.add(
+                "preset",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_subsample {
+            option.add(
+                "smart_subsample",
+                value,
+            );
+        }
+        if let Some(value) = options.near_lossless {
+            option.add(
+                "near_lossless",
+                value,
+            );
+        }
+        if let Some(value) = options.alpha_q {
+            option.add(
+                "alpha_q",
+                value,
+            );
+        }
+        if let Some(value) = options.min_size {
+            option.add(
+                "min_size",
+                value,
+            );
+        }
+        if let Some(value) = options.kmin {
+            option.add(
+                "kmin",
+                value,
+            );
+        }
+        if let Some(value) = options.kmax {
+            option.add(
+                "kmax",
+                value,
+            );
+        }
+        if let Some(value) = options.effort {
+            option.add(
+                "effort",
+                value,
+            );
+        }
+        if let Some(value) = options.target_size {
+            option.add(
+                "target_size",
+                value,
+            );
+        }
+        if let Some(value) = options.mixed {
+            option.add(
+                "mixed",
+                value,
+            );
+        }
+        if let Some(value) = options.smart_deblock {
+            option.add(
+                "smart_deblock",
+                value,
+            );
+        }
+        if let Some(value) = options.passes {
+            option.add(
+                "passes",
+                value,
+            );
+        }
+        if let Some(value) = options.keep {
+            option.add(
+                "keep",
+                value,
+            );
+        }
+        if let Some(value) = options.background {
+            option.add(
+                "background",
+                value,
+            );
+        }
+        if let Some(value) = options.page_height {
+            option.add(
+                "page_height",
+                value,
+            );
+        }
+        if let Some(value) = options.profile {
+            option.add(
+                "profile",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of worley, for [`VipsImage::worley_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WorleyOptions {
+    /// Size of Worley cells
+    pub cell_size: Option<i32>,
+    /// Random number seed
+    pub seed: Option<i32>,
+}
+
+impl WorleyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size of Worley cells
+    pub fn cell_size(mut self, cell_size: i32) -> Self {
+        self.cell_size = Some(cell_size);
+        self
+    }
+
+    /// Random number seed
+    pub fn seed(mut self, seed: i32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+impl From<WorleyOptions> for VOption<'_> {
+    fn from(options: WorleyOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.cell_size {
+            option.add(
+                "cell_size",
+                value,
+            );
+        }
+        if let Some(value) = options.seed {
+            option.add(
+                "seed",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of wrap, for [`VipsImage::wrap_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct WrapOptions {
+    /// Left edge of input in output
+    pub x: Option<i32>,
+    /// Top edge of input in output
+    pub y: Option<i32>,
+}
+
+impl WrapOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Left edge of input in output
+    pub fn x(mut self, x: i32) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Top edge of input in output
+    pub fn y(mut self, y: i32) -> Self {
+        self.y = Some(y);
+        self
+    }
+}
+
+impl From<WrapOptions> for VOption<'_> {
+    fn from(options: WrapOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.x {
+            option.add("x", value);
+        }
+        if let Some(value) = options.y {
+            option.add("y", value);
+        }
+        option
+    }
+}
+
+/// Optional arguments of xyz, for [`VipsImage::xyz_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct XyzOptions {
+    /// Size of third dimension
+    pub csize: Option<i32>,
+    /// Size of fourth dimension
+    pub dsize: Option<i32>,
+    /// Size of fifth dimension
+    pub esize: Option<i32>,
+}
+
+impl XyzOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size of third dimension
+    pub fn csize(mut self, csize: i32) -> Self {
+        self.csize = Some(csize);
+        self
+    }
+
+    /// Size of fourth dimension
+    pub fn dsize(mut self, dsize: i32) -> Self {
+        self.dsize = Some(dsize);
+        self
+    }
+
+    /// Size of fifth dimension
+    pub fn esize(mut self, esize: i32) -> Self {
+        self.esize = Some(esize);
+        self
+    }
+}
+
+impl From<XyzOptions> for VOption<'_> {
+    fn from(options: XyzOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.csize {
+            option.add(
+                "csize",
+                value,
+            );
+        }
+        if let Some(value) = options.dsize {
+            option.add(
+                "dsize",
+                value,
+            );
+        }
+        if let Some(value) = options.esize {
+            option.add(
+                "esize",
+                value,
+            );
+        }
+        option
+    }
+}
+
+/// Optional arguments of zone, for [`VipsImage::zone_with_opts`]. Unset arguments keep their libvips defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneOptions {
+    /// Output an unsigned char image
+    pub uchar: Option<bool>,
+}
+
+impl ZoneOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output an unsigned char image
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
+    }
+}
+
+impl From<ZoneOptions> for VOption<'_> {
+    fn from(options: ZoneOptions) -> Self {
+        let mut option = VOption::new();
+        if let Some(value) = options.uchar {
+            option.add(
+                "uchar",
+                value,
+            );
+        }
+        option
+    }
+}
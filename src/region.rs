@@ -1,12 +1,37 @@
 // (c) Copyright 2019-2025 OLX
 // (c) Copyright 2025 mrdkprj
 use crate::bindings::{self, vips_area_unref};
+use std::ffi::{c_int, c_void};
 
-#[derive(Debug, Clone)]
+/// A reference-counted area of bytes shared with libvips.
+///
+/// Cloning a blob takes a new reference to the same memory; nothing is copied.
+#[derive(Debug)]
 pub struct VipsBlob {
     pub(crate) ctx: *mut bindings::VipsBlob,
 }
 
+// SAFETY: the bytes of a blob are never written after it is created, and VipsArea counts its references
+// under a lock, so a blob can be dropped or cloned from any thread. VipsBuffer gets both traits from it.
+unsafe impl Send for VipsBlob {}
+unsafe impl Sync for VipsBlob {}
+
+impl Clone for VipsBlob {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::vips_area_copy(&mut (*self.ctx).area);
+            }
+        }
+        Self {
+            ctx: self.ctx,
+        }
+    }
+}
+
 impl Drop for VipsBlob {
     fn drop(&mut self) {
         unsafe {
@@ -14,22 +39,98 @@ impl Drop for VipsBlob {
                 .ctx
                 .is_null()
             {
-                bindings::g_object_unref(self.ctx as _);
+                vips_area_unref(&mut (*self.ctx).area);
             }
         }
     }
 }
 
 impl VipsBlob {
-    pub(crate) fn area_unref(mut self) {
+    /// Create a blob that takes ownership of data without copying it.
+    /// data is dropped when the last reference to the blob is released.
+    pub fn new<T>(data: T) -> Self
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        let owner = Box::new(data);
+        let bytes = (*owner).as_ref();
         unsafe {
-            (*self.ctx)
+            let blob = bindings::vips_blob_new(
+                Some(free_owned::<T>),
+                bytes.as_ptr() as _,
+                bytes.len() as _,
+            );
+            (*blob)
                 .area
-                .free_fn = None;
-            vips_area_unref(&mut (*self.ctx).area);
-            self.ctx = std::ptr::null_mut();
+                .client = Box::into_raw(owner) as _;
+            VipsBlob {
+                ctx: blob,
+            }
         }
     }
+
+    /// Create a blob holding a copy of data.
+    pub fn copy_from_slice(data: &[u8]) -> Self {
+        unsafe {
+            VipsBlob {
+                ctx: bindings::vips_blob_copy(
+                    data.as_ptr() as _,
+                    data.len() as _,
+                ),
+            }
+        }
+    }
+
+    /// Borrow the bytes of the blob.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe {
+            if self
+                .ctx
+                .is_null()
+            {
+                return &[];
+            }
+
+            let mut length: u64 = 0;
+            let data = bindings::vips_blob_get(
+                self.ctx,
+                &mut length,
+            );
+            if data.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(
+                    data as *const u8,
+                    length as usize,
+                )
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for VipsBlob {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for VipsBlob {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl From<Vec<u8>> for VipsBlob {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl From<&[u8]> for VipsBlob {
+    fn from(data: &[u8]) -> Self {
+        Self::copy_from_slice(data)
+    }
 }
 
 impl From<*mut bindings::VipsBlob> for VipsBlob {
@@ -43,26 +144,90 @@ impl From<*mut bindings::VipsBlob> for VipsBlob {
 #[allow(clippy::from_over_into)]
 impl Into<Vec<u8>> for VipsBlob {
     fn into(self) -> Vec<u8> {
-        unsafe {
-            if self
-                .ctx
-                .is_null()
-            {
-                return Vec::new();
-            }
+        VipsBuffer::from(self).to_vec()
+    }
+}
 
-            let mut size: u64 = 0;
-            let bytes = bindings::vips_blob_get(
-                self.ctx,
-                &mut size,
-            );
-            // unref area
-            self.area_unref();
-            Vec::from_raw_parts(
-                bytes as *mut u8,
-                size as usize,
-                size as usize,
-            )
+/// An owned buffer of bytes produced by libvips, such as the result of a save to memory.
+///
+/// The memory is not copied into a `Vec`. It stays where libvips allocated it and is released with libvips' own deallocator on drop.
+#[derive(Debug, Clone)]
+pub struct VipsBuffer {
+    blob: VipsBlob,
+}
+
+impl VipsBuffer {
+    /// Take ownership of memory allocated with g_malloc(). It is released with g_free().
+    pub(crate) unsafe fn from_glib(data: *mut c_void, length: u64) -> Self {
+        Self {
+            blob: VipsBlob {
+                ctx: bindings::vips_blob_new(
+                    Some(free_glib),
+                    data,
+                    length,
+                ),
+            },
         }
     }
+
+    pub fn as_slice(&self) -> &[u8] {
+        self.blob
+            .as_slice()
+    }
+
+    /// Consume the buffer and return the underlying blob.
+    pub fn into_blob(self) -> VipsBlob {
+        self.blob
+    }
+}
+
+impl From<VipsBlob> for VipsBuffer {
+    fn from(blob: VipsBlob) -> Self {
+        Self {
+            blob,
+        }
+    }
+}
+
+impl std::ops::Deref for VipsBuffer {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for VipsBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl From<VipsBuffer> for Vec<u8> {
+    fn from(buffer: VipsBuffer) -> Self {
+        buffer.to_vec()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<VipsBuffer> for bytes::Bytes {
+    fn from(buffer: VipsBuffer) -> Self {
+        bytes::Bytes::from_owner(buffer)
+    }
+}
+
+unsafe extern "C" fn free_glib(data: *mut c_void, _area: *mut c_void) -> c_int {
+    bindings::g_free(data);
+    0
+}
+
+unsafe extern "C" fn free_owned<T>(_data: *mut c_void, area: *mut c_void) -> c_int {
+    let area = area as *mut bindings::VipsArea;
+    if !(*area)
+        .client
+        .is_null()
+    {
+        drop(Box::from_raw((*area).client as *mut T));
+        (*area).client = std::ptr::null_mut();
+    }
+    0
 }
@@ -2,7 +2,7 @@
 // (c) Copyright 2025 mrdkprj
 use crate::bindings::{self, g_type_from_name};
 use crate::bindings::{VipsArrayDouble, VipsArrayImage, VipsArrayInt};
-use crate::error::Error;
+use crate::error::{Error, ErrorInfo};
 use crate::VipsImage;
 use crate::{
     connection::{VipsSource, VipsTarget},
@@ -164,8 +164,9 @@ where
 
 #[inline]
 pub(crate) fn new_c_string(string: impl Into<Vec<u8>>) -> Result<CString> {
-    CString::new(string)
-        .map_err(|_| Error::InitializationError("Error initializing C string.".to_string()))
+    CString::new(string).map_err(|e| {
+        Error::InitializationError(ErrorInfo::from("Error initializing C string.").with_source(e))
+    })
 }
 
 #[inline]
@@ -174,12 +175,18 @@ pub(crate) fn ensure_null_terminated(input: impl AsRef<[u8]>) -> crate::Result<C
 
     // Check if already null-terminated
     if bytes.last() == Some(&0) {
-        CString::new(&bytes[..bytes.len() - 1])
-            .map_err(|_| Error::InitializationError("Error initializing C string.".to_string()))
+        CString::new(&bytes[..bytes.len() - 1]).map_err(|e| {
+            Error::InitializationError(
+                ErrorInfo::from("Error initializing C string.").with_source(e),
+            )
+        })
     } else {
         // Not null-terminated, append 0 and create CString
-        CString::new(bytes)
-            .map_err(|_| Error::InitializationError("Error initializing C string.".to_string()))
+        CString::new(bytes).map_err(|e| {
+            Error::InitializationError(
+                ErrorInfo::from("Error initializing C string.").with_source(e),
+            )
+        })
     }
 }
 
@@ -243,6 +250,7 @@ pub(crate) const G_TYPE_INT: &str = "gint";
 pub(crate) const G_TYPE_UINT64: &str = "guint64";
 pub(crate) const G_TYPE_DOUBLE: &str = "gdouble";
 pub(crate) const G_TYPE_STRING: &str = "gchararray";
+pub(crate) const G_TYPE_FLAGS: &str = "GFlags";
 
 pub(crate) fn get_g_type(name: &str) -> u64 {
     let type_name = new_c_string(name).unwrap();
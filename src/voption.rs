@@ -1,27 +1,41 @@
 // (c) Copyright 2025 mrdkprj
 use crate::{
     bindings::{
-        g_log, g_object_get_property, g_object_ref, g_object_set_property, g_object_unref,
-        g_type_check_instance_is_a, g_value_dup_boxed, g_value_get_boolean, g_value_get_double,
-        g_value_get_int, g_value_get_object, g_value_get_string, g_value_init, g_value_set_boolean,
+        self, g_log, g_object_get_property, g_object_ref, g_object_set_property, g_object_unref,
+        g_type_check_instance_is_a, g_type_class_peek, g_type_fundamental, g_type_name,
+        g_type_parent, g_value_dup_boxed, g_value_get_boolean, g_value_get_double,
+        g_value_get_enum, g_value_get_flags, g_value_get_int, g_value_get_object,
+        g_value_get_string, g_value_get_uint64, g_value_init, g_value_set_boolean,
         g_value_set_boxed, g_value_set_double, g_value_set_enum, g_value_set_int,
-        g_value_set_object, g_value_set_string, g_value_set_uint64, g_value_unset,
-        vips_array_double_get_type, vips_array_image_get_type, vips_array_int_get_type,
-        vips_blob_get_type, vips_cache_operation_buildp, vips_enum_from_nick, vips_error_buffer,
-        vips_error_clear, vips_image_get_type, vips_interpolate_get_type, vips_object_get_argument,
-        vips_object_set_from_string, vips_object_unref_outputs, vips_operation_new,
-        vips_source_get_type, vips_target_get_type, vips_value_get_array_double,
-        vips_value_get_array_image, vips_value_set_array_double, vips_value_set_array_image,
-        vips_value_set_array_int, GLogLevelFlags_G_LOG_LEVEL_WARNING, GParamSpec, GTypeInstance,
-        GValue, VipsArgumentClass, VipsArgumentInstance, VipsBlob, VipsImage, VipsObject,
-        VipsOperation,
+        g_value_set_object, g_value_set_string, g_value_set_uint64, g_value_type_compatible,
+        g_value_type_transformable, g_value_unset, vips_array_double_get_type,
+        vips_array_image_get_type, vips_array_int_get_type, vips_blob_get_type,
+        vips_cache_operation_buildp, vips_enum_from_nick, vips_error, vips_error_buffer,
+        vips_image_get_type, vips_interpolate_get_type, vips_object_get_argument,
+        vips_object_get_type, vips_object_set_from_string, vips_object_unref_outputs,
+        vips_operation_get_flags, vips_operation_new, vips_source_get_type, vips_target_get_type,
+        vips_value_get_array_double, vips_value_get_array_image, vips_value_get_array_int,
+        vips_value_set_array_double, vips_value_set_array_image, vips_value_set_array_int,
+        GLogLevelFlags_G_LOG_LEVEL_WARNING, GParamSpec, GType, GTypeInstance, GValue,
+        VipsArgumentClass, VipsArgumentFlags_VIPS_ARGUMENT_INPUT,
+        VipsArgumentFlags_VIPS_ARGUMENT_OUTPUT, VipsArgumentInstance, VipsBlob, VipsImage,
+        VipsObject, VipsObjectClass, VipsOperation, VipsOperationFlags_VIPS_OPERATION_BLOCKED,
     },
+    error::{clear_pending, discard_messages, set_pending_error, ArgumentError, Capture, Error},
+    operation::{ArgumentDirection, OperationInfo},
     utils::{
-        get_g_type, new_c_string, G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_INT, G_TYPE_STRING,
-        G_TYPE_UINT64,
+        get_g_type, new_c_string, G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_FLAGS, G_TYPE_INT,
+        G_TYPE_STRING, G_TYPE_UINT64,
     },
+    Vips,
+};
+use num_traits::FromPrimitive;
+use std::{
+    ffi::CStr,
+    mem::MaybeUninit,
+    os::raw::c_void,
+    sync::atomic::{AtomicBool, Ordering},
 };
-use std::{mem::MaybeUninit, os::raw::c_void};
 
 /// Runs the vips operation with options
 pub fn call(operation: &str, option: VOption) -> std::os::raw::c_int {
@@ -52,8 +66,27 @@ pub(crate) fn call_option_string_(
     option_string: *mut i8,
     option: VOption,
 ) -> std::os::raw::c_int {
+    crate::register_thread();
+    clear_pending();
     unsafe {
         let mut vips_operation = vips_operation_new(operation as _);
+        if vips_operation.is_null() {
+            return 1;
+        }
+
+        if vips_operation_get_flags(vips_operation) & VipsOperationFlags_VIPS_OPERATION_BLOCKED != 0
+        {
+            set_pending_error(Some(
+                Error::BlockedError(operation_nickname(vips_operation)),
+            ));
+            g_object_unref(vips_operation as _);
+            return 1;
+        }
+
+        let capture = Capture::begin(
+            operation_domains(vips_operation),
+            option.markers(),
+        );
 
         if !option_string.is_null()
             && vips_object_set_from_string(
@@ -61,19 +94,30 @@ pub(crate) fn call_option_string_(
                 option_string as _,
             ) < 0
         {
+            capture.fail(Some(
+                operation_nickname(vips_operation),
+            ));
             vips_object_unref_outputs(vips_operation as _);
             g_object_unref(vips_operation as _);
             return 1;
         }
 
-        set_opreration(
+        if let Err(e) = set_opreration(
             vips_operation,
             &option,
-        );
+        ) {
+            report_argument_error(e);
+            vips_object_unref_outputs(vips_operation as _);
+            g_object_unref(vips_operation as _);
+            return 1;
+        }
 
         let result = vips_cache_operation_buildp(&mut vips_operation);
 
         if result < 0 {
+            capture.fail(Some(
+                operation_nickname(vips_operation),
+            ));
             vips_object_unref_outputs(vips_operation as _);
             g_object_unref(vips_operation as _);
             return 1;
@@ -90,6 +134,54 @@ pub(crate) fn call_option_string_(
     }
 }
 
+unsafe fn operation_nickname(operation: *mut VipsOperation) -> String {
+    CStr::from_ptr((*(operation as *mut VipsObject)).nickname)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// The domains libvips reports the messages of an operation under: the nicknames and type names of its classes.
+unsafe fn operation_domains(operation: *mut VipsOperation) -> Vec<String> {
+    let mut domains = Vec::new();
+    let mut gtype = (*(*(operation as *mut GTypeInstance)).g_class).g_type;
+    while gtype != vips_object_get_type() {
+        let class = g_type_class_peek(gtype) as *mut VipsObjectClass;
+        if !class.is_null()
+            && !(*class)
+                .nickname
+                .is_null()
+        {
+            domains.push(
+                CStr::from_ptr((*class).nickname)
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+        domains.push(
+            CStr::from_ptr(g_type_name(gtype))
+                .to_string_lossy()
+                .to_string(),
+        );
+        gtype = g_type_parent(gtype);
+    }
+    domains
+}
+
+static STRICT_ARGUMENTS: AtomicBool = AtomicBool::new(true);
+
+impl Vips {
+    /// Turn on or off strict checking of VOption arguments. On by default.
+    ///
+    /// When on, an unknown argument name, an invalid enum nick or a value of the wrong type makes the operation fail
+    /// with `Error::ArgumentError`. When off, the argument is skipped with a GLib warning.
+    pub fn strict_arguments_set(strict: bool) {
+        STRICT_ARGUMENTS.store(
+            strict,
+            Ordering::Relaxed,
+        );
+    }
+}
+
 enum VipsValue<'a> {
     Bool(bool),
     MutBool(&'a mut bool),
@@ -104,15 +196,56 @@ enum VipsValue<'a> {
     IntArray(&'a [i32]),
     DoubleArray(&'a [f64]),
     DoubleVec(Vec<f64>),
+    String(String),
+    IntVec(Vec<i32>),
+    OwnedImage(crate::VipsImage),
+    ImageVec(Vec<crate::VipsImage>),
+    OwnedInterpolate(crate::interpolate::VipsInterpolate),
     MutDoubleArray(&'a mut Vec<f64>),
     ImageArray(&'a [crate::VipsImage]),
     Blob(&'a crate::region::VipsBlob),
     MutBlob(&'a mut crate::region::VipsBlob),
+    MutStr(&'a mut String),
+    MutUint(&'a mut u64),
+    MutIntArray(&'a mut Vec<i32>),
+    MutImageArray(&'a mut Vec<crate::VipsImage>),
+    MutInterpolate(&'a mut crate::interpolate::VipsInterpolate),
+    MutEnum(Box<dyn FnMut(i32) + 'a>),
     Target(&'a crate::connection::VipsTarget),
     Source(&'a crate::connection::VipsSource),
     Interpolate(&'a crate::interpolate::VipsInterpolate),
 }
 
+impl VipsValue<'_> {
+    /// The value of an input, borrowing whatever the option owns.
+    fn as_input(&self) -> Option<VipsValue<'_>> {
+        Some(
+            match self {
+                VipsValue::Bool(value) => VipsValue::Bool(*value),
+                VipsValue::Int(value) => VipsValue::Int(*value),
+                VipsValue::Uint(value) => VipsValue::Uint(*value),
+                VipsValue::Double(value) => VipsValue::Double(*value),
+                VipsValue::Str(value) => VipsValue::Str(value),
+                VipsValue::String(value) => VipsValue::Str(value),
+                VipsValue::Image(value) => VipsValue::Image(value),
+                VipsValue::OwnedImage(value) => VipsValue::Image(value),
+                VipsValue::IntArray(value) => VipsValue::IntArray(value),
+                VipsValue::IntVec(value) => VipsValue::IntArray(value),
+                VipsValue::DoubleArray(value) => VipsValue::DoubleArray(value),
+                VipsValue::DoubleVec(value) => VipsValue::DoubleArray(value),
+                VipsValue::ImageArray(value) => VipsValue::ImageArray(value),
+                VipsValue::ImageVec(value) => VipsValue::ImageArray(value),
+                VipsValue::Blob(value) => VipsValue::Blob(value),
+                VipsValue::Target(value) => VipsValue::Target(value),
+                VipsValue::Source(value) => VipsValue::Source(value),
+                VipsValue::Interpolate(value) => VipsValue::Interpolate(value),
+                VipsValue::OwnedInterpolate(value) => VipsValue::Interpolate(value),
+                _ => return None,
+            },
+        )
+    }
+}
+
 struct Pair<'a> {
     input: bool,
     name: String,
@@ -149,6 +282,21 @@ impl<'a> VOption<'a> {
             options: Vec::new(),
         }
     }
+
+    /// The filenames passed in, which libvips repeats in the messages about them.
+    fn markers(&self) -> Vec<String> {
+        self.options
+            .iter()
+            .filter(|pair| pair.input && pair.name == "filename")
+            .filter_map(
+                |pair| match &pair.value {
+                    VipsValue::Str(value) => Some(value.to_string()),
+                    VipsValue::String(value) => Some(value.clone()),
+                    _ => None,
+                },
+            )
+            .collect()
+    }
 }
 
 fn get_operation(vips_operation: *mut VipsOperation, option: VOption) {
@@ -231,7 +379,7 @@ fn get_operation(vips_operation: *mut VipsOperation, option: VOption) {
                         gvalue_ptr,
                     );
                     let out_blob: *mut VipsBlob = g_value_dup_boxed(gvalue_ptr).cast();
-                    out.ctx = out_blob;
+                    *out = crate::VipsBlob::from(out_blob);
                 }
                 VipsValue::MutImage(out) => {
                     g_value_init(
@@ -244,7 +392,128 @@ fn get_operation(vips_operation: *mut VipsOperation, option: VOption) {
                         gvalue_ptr,
                     );
                     let out_image: *mut VipsImage = g_value_get_object(gvalue_ptr).cast();
-                    out.ctx = out_image;
+                    // replacing the old value drops its reference
+                    *out = crate::VipsImage::from(out_image);
+                }
+                VipsValue::MutStr(out) => {
+                    g_value_init(
+                        gvalue_ptr,
+                        get_g_type(G_TYPE_STRING),
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    let string = g_value_get_string(gvalue_ptr);
+                    if !string.is_null() {
+                        *out = CStr::from_ptr(string)
+                            .to_string_lossy()
+                            .to_string();
+                    }
+                }
+                VipsValue::MutUint(out) => {
+                    g_value_init(
+                        gvalue_ptr,
+                        get_g_type(G_TYPE_UINT64),
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    *out = g_value_get_uint64(gvalue_ptr);
+                }
+                VipsValue::MutIntArray(out) => {
+                    g_value_init(
+                        gvalue_ptr,
+                        vips_array_int_get_type(),
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    let mut len: i32 = 0;
+                    let array = vips_value_get_array_int(
+                        gvalue_ptr,
+                        &mut len,
+                    );
+                    if !array.is_null() {
+                        let result = std::slice::from_raw_parts(
+                            array,
+                            len as usize,
+                        );
+                        out.extend(result);
+                    }
+                }
+                VipsValue::MutImageArray(out) => {
+                    g_value_init(
+                        gvalue_ptr,
+                        vips_array_image_get_type(),
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    let mut len: i32 = 0;
+                    let array = vips_value_get_array_image(
+                        gvalue_ptr,
+                        &mut len,
+                    );
+                    if !array.is_null() {
+                        // the array keeps its own references, so take one for each image
+                        let result = std::slice::from_raw_parts(
+                            array,
+                            len as usize,
+                        );
+                        out.extend(
+                            result
+                                .iter()
+                                .map(|image| {
+                                    crate::VipsImage::from(
+                                        g_object_ref(*image as _) as *mut VipsImage,
+                                    )
+                                }),
+                        );
+                    }
+                }
+                VipsValue::MutInterpolate(out) => {
+                    g_value_init(
+                        gvalue_ptr,
+                        vips_interpolate_get_type(),
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    let out_interpolate: *mut bindings::VipsInterpolate =
+                        g_value_get_object(gvalue_ptr).cast();
+                    *out = crate::interpolate::VipsInterpolate::from(out_interpolate);
+                }
+                VipsValue::MutEnum(mut out) => {
+                    let Some(enum_type) = argument_type(
+                        vips_operation,
+                        &name,
+                    ) else {
+                        continue;
+                    };
+                    g_value_init(
+                        gvalue_ptr,
+                        enum_type,
+                    );
+                    g_object_get_property(
+                        vips_operation.cast(),
+                        name.as_ptr(),
+                        gvalue_ptr,
+                    );
+                    if g_type_fundamental(enum_type) == get_g_type(G_TYPE_FLAGS) {
+                        out(g_value_get_flags(gvalue_ptr) as i32);
+                    } else {
+                        out(g_value_get_enum(gvalue_ptr));
+                    }
                 }
                 _ => {}
             }
@@ -253,17 +522,32 @@ fn get_operation(vips_operation: *mut VipsOperation, option: VOption) {
     }
 }
 
-fn set_opreration(operation: *mut VipsOperation, option: &VOption) {
+fn set_opreration(
+    operation: *mut VipsOperation,
+    option: &VOption,
+) -> std::result::Result<(), ArgumentError> {
     unsafe {
         for pair in &option.options {
             if !pair.input {
+                // outputs are only read back after the build, so check them up front
+                check_output(
+                    operation,
+                    &pair.name,
+                )?;
                 continue;
             }
 
+            let Some(value) = pair
+                .value
+                .as_input()
+            else {
+                continue;
+            };
+
             let mut gvalue = MaybeUninit::<GValue>::zeroed();
             let gvalue_ptr = gvalue.as_mut_ptr();
 
-            match pair.value {
+            match value {
                 VipsValue::Bool(value) => {
                     g_value_init(
                         gvalue_ptr,
@@ -412,36 +696,47 @@ fn set_opreration(operation: *mut VipsOperation, option: &VOption) {
                 _ => {}
             }
 
-            set_property(
+            let res = set_property(
                 operation,
                 &pair.name,
                 gvalue_ptr,
             );
             g_value_unset(gvalue_ptr);
+            res?;
         }
     }
+    Ok(())
 }
 
-fn set_property(operation: *mut VipsOperation, name: &str, value: *mut GValue) {
+fn set_property(
+    operation: *mut VipsOperation,
+    name: &str,
+    value: *mut GValue,
+) -> std::result::Result<(), ArgumentError> {
     unsafe {
         let object: *mut VipsObject = operation.cast();
-        let name = new_c_string(name).unwrap();
+        let c_name = new_c_string(name).unwrap();
 
         let mut pspec: *mut GParamSpec = std::ptr::null_mut();
         let mut argument_class: *mut VipsArgumentClass = std::ptr::null_mut();
         let mut argument_instance: *mut VipsArgumentInstance = std::ptr::null_mut();
         if vips_object_get_argument(
             object,
-            name.as_ptr(),
+            c_name.as_ptr(),
             &mut pspec,
             &mut argument_class,
             &mut argument_instance,
         ) < 0
+            || (*argument_class).flags & VipsArgumentFlags_VIPS_ARGUMENT_INPUT == 0
         {
-            g_warning();
-            vips_error_clear();
-            return;
+            return skip_argument(
+                operation,
+                name,
+                "no such input argument".to_string(),
+                ArgumentDirection::Input,
+            );
         }
+        let name = c_name;
 
         let is_param_spec_enum = g_type_check_instance_is_a(
             pspec as *mut GTypeInstance,
@@ -456,9 +751,15 @@ fn set_property(operation: *mut VipsOperation, name: &str, value: *mut GValue) {
                 g_value_get_string(value),
             );
             if enum_value < 0 {
-                g_warning();
-                vips_error_clear();
-                return;
+                return skip_argument(
+                    operation,
+                    &name.to_string_lossy(),
+                    format!(
+                        "invalid value {}",
+                        CStr::from_ptr(g_value_get_string(value)).to_string_lossy()
+                    ),
+                    ArgumentDirection::Input,
+                );
             }
 
             let mut gvalue = MaybeUninit::<GValue>::zeroed();
@@ -478,6 +779,27 @@ fn set_property(operation: *mut VipsOperation, name: &str, value: *mut GValue) {
             );
             g_value_unset(value2);
         } else {
+            let pspec_type = (*pspec).value_type;
+            if g_value_type_compatible(
+                (*value).g_type,
+                pspec_type,
+            ) == 0
+                && g_value_type_transformable(
+                    (*value).g_type,
+                    pspec_type,
+                ) == 0
+            {
+                return skip_argument(
+                    operation,
+                    &name.to_string_lossy(),
+                    format!(
+                        "expected {}, got {}",
+                        CStr::from_ptr(g_type_name(pspec_type)).to_string_lossy(),
+                        CStr::from_ptr(g_type_name((*value).g_type)).to_string_lossy()
+                    ),
+                    ArgumentDirection::Input,
+                );
+            }
             g_object_set_property(
                 object.cast(),
                 name.as_ptr(),
@@ -485,6 +807,111 @@ fn set_property(operation: *mut VipsOperation, name: &str, value: *mut GValue) {
             );
         }
     }
+    Ok(())
+}
+
+unsafe fn argument_type(operation: *mut VipsOperation, name: &CStr) -> Option<GType> {
+    let mut pspec: *mut GParamSpec = std::ptr::null_mut();
+    let mut argument_class: *mut VipsArgumentClass = std::ptr::null_mut();
+    let mut argument_instance: *mut VipsArgumentInstance = std::ptr::null_mut();
+    if vips_object_get_argument(
+        operation.cast(),
+        name.as_ptr(),
+        &mut pspec,
+        &mut argument_class,
+        &mut argument_instance,
+    ) < 0
+    {
+        discard_messages();
+        return None;
+    }
+    Some((*pspec).value_type)
+}
+
+unsafe fn check_output(
+    operation: *mut VipsOperation,
+    name: &str,
+) -> std::result::Result<(), ArgumentError> {
+    let c_name = new_c_string(name).unwrap();
+    let mut pspec: *mut GParamSpec = std::ptr::null_mut();
+    let mut argument_class: *mut VipsArgumentClass = std::ptr::null_mut();
+    let mut argument_instance: *mut VipsArgumentInstance = std::ptr::null_mut();
+    if vips_object_get_argument(
+        operation.cast(),
+        c_name.as_ptr(),
+        &mut pspec,
+        &mut argument_class,
+        &mut argument_instance,
+    ) < 0
+        || (*argument_class).flags & VipsArgumentFlags_VIPS_ARGUMENT_OUTPUT == 0
+    {
+        return skip_argument(
+            operation,
+            name,
+            "no such output argument".to_string(),
+            ArgumentDirection::Output,
+        );
+    }
+    Ok(())
+}
+
+/// In strict mode, describes the rejected argument. Otherwise warns and lets the call go on without it.
+unsafe fn skip_argument(
+    operation: *mut VipsOperation,
+    name: &str,
+    reason: String,
+    direction: ArgumentDirection,
+) -> std::result::Result<(), ArgumentError> {
+    if !STRICT_ARGUMENTS.load(Ordering::Relaxed) {
+        g_warning();
+        discard_messages();
+        return Ok(());
+    }
+    discard_messages();
+
+    let nickname = CStr::from_ptr((*(operation as *mut VipsObject)).nickname)
+        .to_string_lossy()
+        .to_string();
+    let valid_arguments = OperationInfo::find(&nickname)
+        .map(|info| {
+            info.arguments
+                .into_iter()
+                .filter(|argument| argument.direction == direction)
+                .map(|argument| argument.name)
+                .collect()
+        })
+        .unwrap_or_default();
+    Err(
+        ArgumentError {
+            operation: nickname,
+            argument: name.to_string(),
+            reason,
+            valid_arguments,
+        },
+    )
+}
+
+/// Leaves the error for `Error::extend()`, and in the error buffer for callers of `call()` that only see the return code.
+fn report_argument_error(error: ArgumentError) {
+    let domain = new_c_string(
+        error
+            .operation
+            .as_str(),
+    )
+    .unwrap_or_default();
+    let format = new_c_string("%s").unwrap();
+    if let Ok(message) = new_c_string(error.to_string()) {
+        unsafe {
+            vips_error(
+                domain.as_ptr(),
+                format.as_ptr(),
+                message.as_ptr(),
+            );
+        }
+    }
+    set_pending_error(Some(
+        Error::ArgumentError(error),
+    ));
 }
 
 fn g_warning() {
@@ -893,6 +1320,144 @@ impl<'a> Setter<'a, &'a crate::interpolate::VipsInterpolate> for VOption<'a> {
     }
 }
 
+// input String
+impl<'a> Setter<'a, String> for VOption<'a> {
+    fn set(mut self, name: &str, value: String) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::String(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: String) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::String(value),
+                ),
+            );
+    }
+}
+
+// input Vec<i32>
+impl<'a> Setter<'a, Vec<i32>> for VOption<'a> {
+    fn set(mut self, name: &str, value: Vec<i32>) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::IntVec(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: Vec<i32>) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::IntVec(value),
+                ),
+            );
+    }
+}
+
+// input Vec<f64>
+impl<'a> Setter<'a, Vec<f64>> for VOption<'a> {
+    fn set(mut self, name: &str, value: Vec<f64>) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::DoubleVec(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: Vec<f64>) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::DoubleVec(value),
+                ),
+            );
+    }
+}
+
+// input owned VipsImage
+impl<'a> Setter<'a, crate::VipsImage> for VOption<'a> {
+    fn set(mut self, name: &str, value: crate::VipsImage) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::OwnedImage(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: crate::VipsImage) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::OwnedImage(value),
+                ),
+            );
+    }
+}
+
+// input Vec<VipsImage>
+impl<'a> Setter<'a, Vec<crate::VipsImage>> for VOption<'a> {
+    fn set(mut self, name: &str, value: Vec<crate::VipsImage>) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::ImageVec(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: Vec<crate::VipsImage>) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::ImageVec(value),
+                ),
+            );
+    }
+}
+
+// input owned VipsInterpolate
+impl<'a> Setter<'a, crate::interpolate::VipsInterpolate> for VOption<'a> {
+    fn set(mut self, name: &str, value: crate::interpolate::VipsInterpolate) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::OwnedInterpolate(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: crate::interpolate::VipsInterpolate) {
+        self.options
+            .push(
+                Pair::input(
+                    name,
+                    VipsValue::OwnedInterpolate(value),
+                ),
+            );
+    }
+}
+
 // output bool
 impl<'a> Setter<'a, &'a mut bool> for VOption<'a> {
     fn set(mut self, name: &str, value: &'a mut bool) -> VOption<'a> {
@@ -1030,3 +1595,270 @@ impl<'a> Setter<'a, &'a mut crate::region::VipsBlob> for VOption<'a> {
             );
     }
 }
+
+// output String
+impl<'a> Setter<'a, &'a mut String> for VOption<'a> {
+    fn set(mut self, name: &str, value: &'a mut String) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutStr(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: &'a mut String) {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutStr(value),
+                ),
+            );
+    }
+}
+
+// output u64
+impl<'a> Setter<'a, &'a mut u64> for VOption<'a> {
+    fn set(mut self, name: &str, value: &'a mut u64) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutUint(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: &'a mut u64) {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutUint(value),
+                ),
+            );
+    }
+}
+
+// output Vec<i32>
+impl<'a> Setter<'a, &'a mut Vec<i32>> for VOption<'a> {
+    fn set(mut self, name: &str, value: &'a mut Vec<i32>) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutIntArray(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: &'a mut Vec<i32>) {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutIntArray(value),
+                ),
+            );
+    }
+}
+
+// output Vec<VipsImage>
+impl<'a> Setter<'a, &'a mut Vec<crate::VipsImage>> for VOption<'a> {
+    fn set(mut self, name: &str, value: &'a mut Vec<crate::VipsImage>) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutImageArray(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: &'a mut Vec<crate::VipsImage>) {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutImageArray(value),
+                ),
+            );
+    }
+}
+
+// output VipsInterpolate
+impl<'a> Setter<'a, &'a mut crate::interpolate::VipsInterpolate> for VOption<'a> {
+    fn set(
+        mut self,
+        name: &str,
+        value: &'a mut crate::interpolate::VipsInterpolate,
+    ) -> VOption<'a> {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutInterpolate(value),
+                ),
+            );
+        self
+    }
+    fn add(&mut self, name: &str, value: &'a mut crate::interpolate::VipsInterpolate) {
+        self.options
+            .push(
+                Pair::output(
+                    name,
+                    VipsValue::MutInterpolate(value),
+                ),
+            );
+    }
+}
+
+// enums, passed to libvips as their value. An output is left untouched if libvips returns a value the enum has no variant for.
+macro_rules! enum_setter {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<'a> Setter<'a, crate::ops::$name> for VOption<'a> {
+                fn set(mut self, name: &str, value: crate::ops::$name) -> VOption<'a> {
+                    self.add(
+                        name, value,
+                    );
+                    self
+                }
+                fn add(&mut self, name: &str, value: crate::ops::$name) {
+                    self.options
+                        .push(
+                            Pair::input(
+                                name,
+                                VipsValue::Int(value as i32),
+                            ),
+                        );
+                }
+            }
+
+            impl<'a> Setter<'a, &'a mut crate::ops::$name> for VOption<'a> {
+                fn set(mut self, name: &str, value: &'a mut crate::ops::$name) -> VOption<'a> {
+                    self.add(
+                        name, value,
+                    );
+                    self
+                }
+                fn add(&mut self, name: &str, value: &'a mut crate::ops::$name) {
+                    self.options
+                        .push(
+                            Pair::output(
+                                name,
+                                VipsValue::MutEnum(Box::new(
+                                    move |out| {
+                                        if let Some(out) = FromPrimitive::from_i32(out) {
+                                            *value = out;
+                                        }
+                                    },
+                                )),
+                            ),
+                        );
+                }
+            }
+        )*
+    };
+}
+
+// flags, passed to libvips as their bits
+macro_rules! flags_setter {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<'a> Setter<'a, crate::ops::$name> for VOption<'a> {
+                fn set(mut self, name: &str, value: crate::ops::$name) -> VOption<'a> {
+                    self.add(
+                        name, value,
+                    );
+                    self
+                }
+                fn add(&mut self, name: &str, value: crate::ops::$name) {
+                    self.options
+                        .push(
+                            Pair::input(
+                                name,
+                                VipsValue::Int(value.bits() as i32),
+                            ),
+                        );
+                }
+            }
+
+            impl<'a> Setter<'a, &'a mut crate::ops::$name> for VOption<'a> {
+                fn set(mut self, name: &str, value: &'a mut crate::ops::$name) -> VOption<'a> {
+                    self.add(
+                        name, value,
+                    );
+                    self
+                }
+                fn add(&mut self, name: &str, value: &'a mut crate::ops::$name) {
+                    self.options
+                        .push(
+                            Pair::output(
+                                name,
+                                VipsValue::MutEnum(Box::new(
+                                    move |out| {
+                                        *value = crate::ops::$name::from_bits_retain(out as u32);
+                                    },
+                                )),
+                            ),
+                        );
+                }
+            }
+        )*
+    };
+}
+
+enum_setter!(
+    Access,
+    Align,
+    Angle,
+    Angle45,
+    BandFormat,
+    BlendMode,
+    Coding,
+    Combine,
+    CombineMode,
+    CompassDirection,
+    Direction,
+    Extend,
+    FailOn,
+    ForeignDzContainer,
+    ForeignDzDepth,
+    ForeignDzLayout,
+    ForeignHeifCompression,
+    ForeignHeifEncoder,
+    ForeignPpmFormat,
+    ForeignSubsample,
+    ForeignTiffCompression,
+    ForeignTiffPredictor,
+    ForeignTiffResunit,
+    ForeignWebpPreset,
+    Intent,
+    Interesting,
+    Interpretation,
+    Kernel,
+    OperationBoolean,
+    OperationComplex,
+    OperationComplex2,
+    OperationComplexget,
+    OperationMath,
+    OperationMath2,
+    OperationMorphology,
+    OperationRelational,
+    OperationRound,
+    PCS,
+    Precision,
+    RegionShrink,
+    SdfShape,
+    Size,
+    TextWrap,
+);
+
+flags_setter!(
+    ForeignFlags,
+    ForeignKeep,
+    ForeignPngFilter,
+);
@@ -0,0 +1,226 @@
+#![cfg(feature = "tokio")]
+mod common;
+
+use common::init;
+use rs_vips::{ops::BandFormat, VipsImage, VipsSource, VipsTarget};
+use std::{
+    io::{self, Cursor, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{io::AsyncReadExt, sync::oneshot};
+
+fn png() -> Vec<u8> {
+    VipsImage::black(24, 12)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn load_from_async_reader() {
+    init();
+    let source = VipsSource::from_async_reader(Cursor::new(png())).unwrap();
+    let image = VipsImage::load_source_async(source, "")
+        .await
+        .unwrap();
+    assert_eq!(
+        image.get_width(),
+        24
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn save_to_async_writer() {
+    init();
+    let (writer, mut reader) = tokio::io::duplex(1024 * 1024);
+    let target = VipsTarget::from_async_writer(writer).unwrap();
+    VipsImage::black(24, 12)
+        .unwrap()
+        .save_target_async(
+            ".png",
+            target,
+        )
+        .await
+        .unwrap();
+
+    let mut png = Vec::new();
+    reader
+        .read_to_end(&mut png)
+        .await
+        .unwrap();
+    let image = VipsImage::new_from_buffer(&png, "").unwrap();
+    assert_eq!(
+        image.get_height(),
+        12
+    );
+}
+
+/// Counts the bytes a save writes, and reports its first write and the end of the save.
+struct CountingWriter {
+    written: Arc<AtomicUsize>,
+    started: Option<oneshot::Sender<()>>,
+    finished: Option<oneshot::Sender<()>>,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written
+            .fetch_add(
+                buf.len(),
+                Ordering::SeqCst,
+            );
+        if let Some(started) = self
+            .started
+            .take()
+        {
+            let _ = started.send(());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for CountingWriter {
+    fn drop(&mut self) {
+        if let Some(finished) = self
+            .finished
+            .take()
+        {
+            let _ = finished.send(());
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dropped_save_is_cancelled() {
+    init();
+    // slow enough that the save is still running when the future is dropped
+    let image = VipsImage::black(4000, 4000)
+        .unwrap()
+        .gaussblur(50.0)
+        .unwrap()
+        .cast(BandFormat::Uchar)
+        .unwrap();
+    let pixels = 4000 * 4000;
+
+    let written = Arc::new(AtomicUsize::new(0));
+    let (started, first_write) = oneshot::channel();
+    let (finished, save_ended) = oneshot::channel();
+    let target = VipsTarget::from_writer(
+        CountingWriter {
+            written: Arc::clone(&written),
+            started: Some(started),
+            finished: Some(finished),
+        },
+    )
+    .unwrap();
+
+    let mut save = Box::pin(
+        image.save_target_async(
+            ".v",
+            target,
+        ),
+    );
+    tokio::select! {
+        _ = &mut save => panic!("the save finished before it was dropped"),
+        _ = first_write => {}
+    }
+    drop(save);
+
+    // the target, and so the writer, is dropped once the blocking save has stopped
+    tokio::time::timeout(
+        Duration::from_secs(60),
+        save_ended,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(written.load(Ordering::SeqCst) < pixels);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dropping_one_save_leaves_others_running() {
+    init();
+    let image = Arc::new(
+        VipsImage::black(2000, 2000)
+            .unwrap()
+            .gaussblur(30.0)
+            .unwrap()
+            .cast(BandFormat::Uchar)
+            .unwrap(),
+    );
+    let pixels = 2000 * 2000;
+
+    let kept_written = Arc::new(AtomicUsize::new(0));
+    let kept_target = VipsTarget::from_writer(
+        CountingWriter {
+            written: Arc::clone(&kept_written),
+            started: None,
+            finished: None,
+        },
+    )
+    .unwrap();
+    let (started, first_write) = oneshot::channel();
+    let (finished, save_ended) = oneshot::channel();
+    let dropped_target = VipsTarget::from_writer(
+        CountingWriter {
+            written: Arc::new(AtomicUsize::new(0)),
+            started: Some(started),
+            finished: Some(finished),
+        },
+    )
+    .unwrap();
+
+    let kept = tokio::spawn({
+        let image = Arc::clone(&image);
+        async move {
+            image
+                .save_target_async(
+                    ".v",
+                    kept_target,
+                )
+                .await
+        }
+    });
+    let dropped = tokio::spawn({
+        let image = Arc::clone(&image);
+        async move {
+            image
+                .save_target_async(
+                    ".v",
+                    dropped_target,
+                )
+                .await
+        }
+    });
+    first_write
+        .await
+        .unwrap();
+    dropped.abort();
+    tokio::time::timeout(
+        Duration::from_secs(60),
+        save_ended,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    kept.await
+        .unwrap()
+        .unwrap();
+    assert!(kept_written.load(Ordering::SeqCst) > pixels);
+}
+
+#[test]
+fn async_reader_needs_runtime() {
+    init();
+    assert!(VipsSource::from_async_reader(Cursor::new(png())).is_err());
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::init;
+use rs_vips::{VipsBlob, VipsImage, VipsSource};
+
+#[test]
+fn blob_from_owned_bytes() {
+    init();
+    let blob = VipsBlob::new(vec![
+        1u8, 2, 3,
+    ]);
+    let cloned = blob.clone();
+    drop(blob);
+    assert_eq!(
+        cloned.as_slice(),
+        &[1, 2, 3]
+    );
+
+    let copied = VipsBlob::copy_from_slice(&[4, 5]);
+    assert_eq!(
+        &copied[..],
+        &[4, 5]
+    );
+}
+
+#[test]
+fn blob_as_source() {
+    init();
+    let png = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap()
+        .into_blob();
+
+    let source = VipsSource::new_from_blob(&png).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        image.get_width(),
+        8
+    );
+
+    let image = VipsImage::new_from_blob(&png, "").unwrap();
+    assert_eq!(
+        image.get_height(),
+        8
+    );
+}
+
+#[test]
+fn blob_as_metadata() {
+    init();
+    let mut image = VipsImage::black(8, 8).unwrap();
+    let blob = VipsBlob::new(b"metadata".to_vec());
+    image
+        .set_vips_blob(
+            "rs-vips-blob",
+            &blob,
+        )
+        .unwrap();
+    let out = image
+        .get_blob("rs-vips-blob")
+        .unwrap();
+    assert_eq!(
+        out.as_slice(),
+        b"metadata"
+    );
+    assert_eq!(
+        out.as_ptr(),
+        blob.as_ptr()
+    );
+
+    image
+        .set_blob(
+            "rs-vips-copy",
+            b"copy",
+        )
+        .unwrap();
+    assert_eq!(
+        &image
+            .get_blob("rs-vips-copy")
+            .unwrap()[..],
+        b"copy"
+    );
+}
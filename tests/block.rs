@@ -0,0 +1,71 @@
+mod common;
+
+use common::init;
+use rs_vips::{error::Error, ImageFormat, LoaderPolicy, Vips, VipsImage};
+
+// Blocking is global, so everything runs in one test to keep it away from the others.
+#[test]
+fn block_operations_and_loaders() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let jpeg = image
+        .jpegsave_buffer()
+        .unwrap();
+    let png = image
+        .pngsave_buffer()
+        .unwrap();
+
+    Vips::block_operation(
+        "invert",
+        true,
+    )
+    .unwrap();
+    match image.invert() {
+        Err(Error::BlockedError(nickname)) => assert_eq!(
+            nickname,
+            "invert"
+        ),
+        other => panic!(
+            "expected invert to be blocked, got {:?}",
+            other
+        ),
+    }
+    Vips::block_operation(
+        "invert",
+        false,
+    )
+    .unwrap();
+    assert!(image
+        .invert()
+        .is_ok());
+
+    Vips::loader_policy_set(
+        &LoaderPolicy::Allow(vec![
+            ImageFormat::Png,
+        ]),
+    )
+    .unwrap();
+    assert!(VipsImage::new_from_buffer(&png, "").is_ok());
+    match VipsImage::new_from_buffer(&jpeg, "") {
+        Err(Error::BlockedError(nickname)) => assert_eq!(
+            nickname,
+            "jpegload_buffer"
+        ),
+        other => panic!(
+            "expected the jpeg loader to be blocked, got {:?}",
+            other
+        ),
+    }
+    assert!(matches!(
+        VipsImage::jpegload_buffer(&jpeg),
+        Err(Error::BlockedError(_))
+    ));
+    // unrecognised input is not blamed on a blocked loader
+    assert!(matches!(
+        VipsImage::new_from_buffer(b"not an image at all", ""),
+        Err(ref e) if !matches!(e, Error::BlockedError(_))
+    ));
+
+    Vips::loader_policy_set(&LoaderPolicy::AllowAll).unwrap();
+    assert!(VipsImage::new_from_buffer(&jpeg, "").is_ok());
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use common::init;
+use rs_vips::{VipsBlob, VipsImage, VipsInterpolate, VipsTarget};
+
+fn ref_count(image: &VipsImage) -> u32 {
+    unsafe {
+        (*image.as_mut_ptr())
+            .parent_instance
+            .parent_instance
+            .ref_count
+    }
+}
+
+#[test]
+fn clone_takes_a_reference() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let count = ref_count(&image);
+
+    let cloned = image.clone();
+    assert_eq!(
+        ref_count(&image),
+        count + 1
+    );
+
+    drop(cloned);
+    assert_eq!(
+        ref_count(&image),
+        count
+    );
+}
+
+#[test]
+fn clone_outlives_original() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let cloned = image.clone();
+    let count = ref_count(&cloned);
+    drop(image);
+
+    assert_eq!(
+        ref_count(&cloned),
+        count - 1
+    );
+    assert_eq!(
+        cloned.get_width(),
+        16
+    );
+    let inverted = cloned
+        .invert()
+        .unwrap();
+    assert_eq!(
+        inverted.get_height(),
+        16
+    );
+}
+
+#[test]
+fn clone_null_image() {
+    init();
+    let image = VipsImage::from(std::ptr::null_mut());
+    let cloned = image.clone();
+    assert!(cloned
+        .as_mut_ptr()
+        .is_null());
+}
+
+#[test]
+fn clone_interpolate_and_target() {
+    init();
+    let interpolate = VipsInterpolate::new_from_bilinear_static();
+    let cloned = interpolate.clone();
+    drop(interpolate);
+    assert_eq!(
+        cloned.get_window_size(),
+        2
+    );
+
+    let target = VipsTarget::new_to_memory().unwrap();
+    let cloned = target.clone();
+    drop(target);
+    drop(cloned);
+}
+
+#[test]
+fn clone_blob_from_target() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let target = VipsTarget::new_to_memory().unwrap();
+    image
+        .write_to_target(
+            ".png",
+            &target,
+        )
+        .unwrap();
+
+    let blob: VipsBlob = target.get_blob();
+    let cloned = blob.clone();
+    drop(blob);
+    drop(target);
+    drop(cloned);
+}
+
+#[test]
+fn setters_do_not_change_clones() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let mut cloned = image.clone();
+    cloned
+        .set_int(
+            "answer",
+            42,
+        )
+        .unwrap();
+    cloned
+        .draw_rect(
+            &[255.0],
+            0,
+            0,
+            16,
+            16,
+        )
+        .unwrap();
+
+    assert_eq!(
+        cloned
+            .get_int("answer")
+            .unwrap(),
+        42
+    );
+    assert!(image
+        .get_int("answer")
+        .is_err());
+    assert_eq!(
+        cloned
+            .avg()
+            .unwrap(),
+        255.0
+    );
+    assert_eq!(
+        image
+            .avg()
+            .unwrap(),
+        0.0
+    );
+}
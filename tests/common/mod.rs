@@ -0,0 +1,12 @@
+use rs_vips::{Vips, VipsGuard};
+use std::sync::OnceLock;
+
+/// Starts libvips once for the whole test binary. The guard lives in a static, so libvips is never shut down mid-run.
+pub fn init() {
+    static VIPS: OnceLock<VipsGuard> = OnceLock::new();
+    VIPS.get_or_init(|| {
+        let guard = Vips::init("rs-vips-test").expect("Cannot initialize libvips");
+        Vips::leak_set(true);
+        guard
+    });
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::init;
+use rs_vips::{Vips, VipsConfig};
+
+#[test]
+fn config_applies_settings() {
+    init();
+    let _vips = VipsConfig::new("rs-vips-test")
+        .concurrency(2)
+        .cache_max(50)
+        .pipe_read_limit(1024 * 1024)
+        .leak(true)
+        .init()
+        .unwrap();
+
+    let settings = Vips::settings();
+    assert_eq!(
+        settings.concurrency,
+        2
+    );
+    assert_eq!(
+        settings.cache_max,
+        50
+    );
+    assert_eq!(
+        settings.pipe_read_limit,
+        1024 * 1024
+    );
+    assert!(settings.leak);
+    assert!(!settings
+        .temp_dir
+        .as_os_str()
+        .is_empty());
+}
+
+#[test]
+fn config_rejects_env_settings_once_started() {
+    init();
+    assert!(
+        VipsConfig::new("rs-vips-test")
+            .temp_dir(std::env::temp_dir())
+            .init()
+            .is_err()
+    );
+    assert!(
+        VipsConfig::new("rs-vips-test")
+            .disc_threshold(1024)
+            .init()
+            .is_err()
+    );
+}
+
+#[test]
+fn from_env_ignores_pipe_read_limit_beyond_i64() {
+    std::env::set_var(
+        "VIPS_PIPE_READ_LIMIT",
+        u64::MAX.to_string(),
+    );
+    let config = VipsConfig::from_env("rs-vips-test");
+    std::env::remove_var("VIPS_PIPE_READ_LIMIT");
+    assert!(format!(
+        "{:?}",
+        config
+    )
+    .contains("pipe_read_limit: None"));
+}
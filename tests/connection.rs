@@ -0,0 +1,104 @@
+mod common;
+
+use common::init;
+use rs_vips::{VipsImage, VipsSource, VipsTarget};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const DATA: &[u8] = b"0123456789";
+
+#[test]
+fn source_read_fills_buffer() {
+    init();
+    let mut source = VipsSource::new_from_memory(DATA).unwrap();
+    let mut buffer = [0u8; 4];
+    let n = source
+        .read(&mut buffer)
+        .unwrap();
+    assert_eq!(n, 4);
+    assert_eq!(
+        &buffer,
+        b"0123"
+    );
+}
+
+#[test]
+fn source_map_returns_whole_source() {
+    init();
+    let source = VipsSource::new_from_memory(DATA).unwrap();
+    assert_eq!(
+        source
+            .map()
+            .unwrap(),
+        DATA
+    );
+}
+
+#[test]
+fn source_implements_read_and_seek() {
+    init();
+    let mut source = VipsSource::new_from_memory(DATA).unwrap();
+    Seek::seek(
+        &mut source,
+        SeekFrom::Start(6),
+    )
+    .unwrap();
+    let mut rest = Vec::new();
+    source
+        .read_to_end(&mut rest)
+        .unwrap();
+    assert_eq!(
+        rest,
+        b"6789"
+    );
+
+    let position = Seek::seek(
+        &mut source,
+        SeekFrom::End(-2),
+    )
+    .unwrap();
+    assert_eq!(
+        position,
+        8
+    );
+}
+
+#[test]
+fn source_rejects_offsets_beyond_i64() {
+    init();
+    let mut source = VipsSource::new_from_memory(DATA).unwrap();
+    let err = Seek::seek(
+        &mut source,
+        SeekFrom::Start(u64::MAX),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[test]
+fn target_implements_write() {
+    init();
+    let png = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap();
+
+    let mut target = VipsTarget::new_to_memory().unwrap();
+    target
+        .write_all(&png)
+        .unwrap();
+    target
+        .flush()
+        .unwrap();
+    let written = target.clone();
+    target.end();
+
+    assert_eq!(
+        written
+            .get_blob()
+            .as_slice(),
+        &png[..]
+    );
+}
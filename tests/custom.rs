@@ -0,0 +1,156 @@
+mod common;
+
+use common::init;
+use rs_vips::{VipsImage, VipsSource, VipsTarget};
+use std::{
+    io::{self, Cursor, Read, Write},
+    sync::{Arc, Mutex},
+};
+
+fn png() -> Vec<u8> {
+    VipsImage::black(24, 12)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap()
+        .to_vec()
+}
+
+#[test]
+fn load_from_reader() {
+    init();
+    let source = VipsSource::from_reader(Cursor::new(png())).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        image.get_width(),
+        24
+    );
+    assert_eq!(
+        image
+            .invert()
+            .unwrap()
+            .get_height(),
+        12
+    );
+}
+
+#[test]
+fn load_from_read_seek() {
+    init();
+    let source = VipsSource::from_read_seek(Cursor::new(png())).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        image.get_width(),
+        24
+    );
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("upload interrupted"))
+    }
+}
+
+#[test]
+fn reader_error_fails_load() {
+    init();
+    let source = VipsSource::from_reader(FailingReader).unwrap();
+    assert!(
+        VipsImage::new_from_source(
+            &source,
+            "",
+        )
+        .is_err()
+    );
+}
+
+#[derive(Clone, Default)]
+struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap()
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn save_to_writer() {
+    init();
+    let writer = SharedWriter::default();
+    let target = VipsTarget::from_writer(writer.clone()).unwrap();
+    VipsImage::black(24, 12)
+        .unwrap()
+        .write_to_target(
+            ".png",
+            &target,
+        )
+        .unwrap();
+
+    let png = writer
+        .0
+        .lock()
+        .unwrap()
+        .clone();
+    let image = VipsImage::new_from_buffer(&png, "").unwrap();
+    assert_eq!(
+        image.get_width(),
+        24
+    );
+}
+
+#[test]
+fn save_tiff_to_write_seek() {
+    init();
+    let target = VipsTarget::from_write_seek(Cursor::new(Vec::new())).unwrap();
+    VipsImage::black(24, 12)
+        .unwrap()
+        .write_to_target(
+            ".tif",
+            &target,
+        )
+        .unwrap();
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writer_error_fails_save() {
+    init();
+    let target = VipsTarget::from_writer(FailingWriter).unwrap();
+    let err = VipsImage::black(24, 12)
+        .unwrap()
+        .write_to_target(
+            ".png",
+            &target,
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("disk full"));
+}
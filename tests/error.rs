@@ -0,0 +1,93 @@
+mod common;
+
+use common::init;
+use rs_vips::{
+    error::{Error, ErrorKind},
+    ops::FailOn,
+    voption::{Setter, VOption},
+    VipsImage,
+};
+
+#[test]
+fn unknown_format_is_unsupported() {
+    init();
+    let err = VipsImage::new_from_buffer(
+        b"definitely not an image",
+        "",
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnsupportedFormat
+    );
+    let info = err
+        .info()
+        .unwrap();
+    assert!(!info
+        .lines
+        .is_empty());
+    assert!(!info
+        .domains()
+        .is_empty());
+}
+
+#[test]
+fn failures_do_not_share_messages() {
+    init();
+    let first = VipsImage::new_from_buffer(
+        b"first garbage",
+        "",
+    )
+    .unwrap_err();
+    let second = VipsImage::black(8, 8)
+        .unwrap()
+        .extract_area(
+            4, 4, 16, 16,
+        )
+        .unwrap_err();
+    let first_lines = &first
+        .info()
+        .unwrap()
+        .lines;
+    let second_lines = &second
+        .info()
+        .unwrap()
+        .lines;
+    assert!(
+        second_lines
+            .iter()
+            .all(|line| !first_lines.contains(line))
+    );
+    assert_eq!(
+        second.operation(),
+        Some("extract_area")
+    );
+}
+
+#[test]
+fn truncated_jpeg() {
+    init();
+    let jpeg = VipsImage::black(64, 64)
+        .unwrap()
+        .jpegsave_buffer()
+        .unwrap();
+    let truncated = &jpeg[..jpeg.len() / 2];
+    let result = VipsImage::jpegload_buffer_with_opts(
+        truncated,
+        VOption::new().set(
+            "fail_on",
+            FailOn::Truncated,
+        ),
+    )
+    .and_then(|image| image.avg());
+    match result {
+        Err(Error::OperationError(info)) => assert_eq!(
+            info.kind,
+            ErrorKind::TruncatedInput
+        ),
+        other => panic!(
+            "expected a truncated input error, got {:?}",
+            other
+        ),
+    }
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use common::init;
+use rs_vips::{ImageFormat, Vips, VipsImage, VipsSource};
+
+#[test]
+fn find_load_buffer_identifies_format() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let png = image
+        .write_to_buffer(".png")
+        .unwrap();
+    let loader = Vips::find_load_buffer(&png).unwrap();
+    assert_eq!(
+        loader.format,
+        ImageFormat::Png
+    );
+    assert_eq!(
+        loader.mime_type(),
+        Some("image/png")
+    );
+    assert_eq!(
+        loader.nickname,
+        "pngload_buffer"
+    );
+
+    let jpeg = image
+        .write_to_buffer(".jpg")
+        .unwrap();
+    assert_eq!(
+        Vips::find_load_buffer(&jpeg)
+            .unwrap()
+            .format,
+        ImageFormat::Jpeg
+    );
+}
+
+#[test]
+fn find_load_identifies_file_format() {
+    init();
+    let path = std::env::temp_dir().join("rs-vips-find-load.png");
+    VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_file(&path)
+        .unwrap();
+    let loader = Vips::find_load(
+        path.to_str()
+            .unwrap(),
+    )
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        loader.format,
+        ImageFormat::Png
+    );
+    assert_eq!(
+        loader.nickname,
+        "pngload"
+    );
+}
+
+#[test]
+fn find_load_source_identifies_format() {
+    init();
+    let gif = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".gif")
+        .unwrap();
+    let source = VipsSource::new_from_memory(&gif).unwrap();
+    let loader = Vips::find_load_source(&source).unwrap();
+    assert_eq!(
+        loader.format,
+        ImageFormat::Gif
+    );
+    assert_eq!(
+        loader.nickname,
+        "gifload_source"
+    );
+    assert!(
+        VipsImage::new_from_source(
+            &source,
+            ""
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn find_load_rejects_unknown_data() {
+    init();
+    assert!(Vips::find_load_buffer(b"not an image").is_err());
+}
+
+#[test]
+fn format_from_loader_nickname() {
+    assert_eq!(
+        ImageFormat::from_loader("heifload_buffer"),
+        ImageFormat::Heif
+    );
+    assert_eq!(
+        ImageFormat::from_loader("jxlload_source"),
+        ImageFormat::Jxl
+    );
+    assert_eq!(
+        ImageFormat::from_loader("svgload"),
+        ImageFormat::Svg
+    );
+    assert_eq!(
+        ImageFormat::from_loader("newformatload"),
+        ImageFormat::Other
+    );
+    assert_eq!(
+        ImageFormat::Webp.mime_type(),
+        Some("image/webp")
+    );
+}
+
+#[test]
+fn find_saver_by_suffix() {
+    init();
+    let saver = Vips::find_saver(".png").unwrap();
+    assert_eq!(
+        saver.format,
+        ImageFormat::Png
+    );
+    assert_eq!(
+        saver.nickname,
+        "pngsave"
+    );
+    assert_eq!(
+        Vips::find_saver("out.jpg[Q=90]")
+            .unwrap()
+            .format,
+        ImageFormat::Jpeg
+    );
+    assert_eq!(
+        Vips::find_saver_buffer(".png")
+            .unwrap()
+            .nickname,
+        "pngsave_buffer"
+    );
+    assert_eq!(
+        Vips::find_saver_target(".png")
+            .unwrap()
+            .nickname,
+        "pngsave_target"
+    );
+    assert!(Vips::find_saver(".nope").is_err());
+}
+
+#[test]
+fn avif_is_told_apart_from_heic() {
+    init();
+    assert_eq!(
+        ImageFormat::Avif.mime_type(),
+        Some("image/avif")
+    );
+    if !Vips::supported_save_suffixes().contains(&".avif".to_string()) {
+        return;
+    }
+
+    assert_eq!(
+        Vips::find_saver("out.avif[Q=50]")
+            .unwrap()
+            .format,
+        ImageFormat::Avif
+    );
+    let avif = VipsImage::black(16, 16)
+        .unwrap()
+        .write_to_buffer(".avif")
+        .unwrap();
+    let loader = Vips::find_load_buffer(&avif).unwrap();
+    assert_eq!(
+        loader.format,
+        ImageFormat::Avif
+    );
+    assert_eq!(
+        loader.mime_type(),
+        Some("image/avif")
+    );
+}
+
+#[test]
+fn list_supported_suffixes() {
+    init();
+    let save = Vips::supported_save_suffixes();
+    assert!(save.contains(&".png".to_string()));
+    assert!(save.contains(&".jpg".to_string()));
+
+    let load = Vips::supported_load_suffixes();
+    assert!(load.contains(&".png".to_string()));
+    assert!(load.contains(&".v".to_string()));
+}
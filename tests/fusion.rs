@@ -0,0 +1,45 @@
+mod common;
+
+use common::init;
+use rs_vips::{Vips, VipsImage};
+
+/// Images the operation cache does not hold, made since `before` was counted.
+fn made_since(before: usize) -> usize {
+    Vips::cache_drop_all();
+    Vips::live_image_count() - before
+}
+
+// A single test, since it counts every image alive in the process.
+#[test]
+fn scalar_chain_runs_one_linear() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    Vips::cache_drop_all();
+    let before = Vips::live_image_count();
+
+    let single = image
+        .linear(
+            &[6.0],
+            &[-2.0],
+        )
+        .unwrap();
+    let one_linear = made_since(before);
+    drop(single);
+    assert_eq!(
+        made_since(before),
+        0
+    );
+
+    // the intermediate images are gone, and nothing but one linear of image is left
+    let fused = (&image * 3.0 + 1.0) * 2.0 - 4.0;
+    assert_eq!(
+        made_since(before),
+        one_linear
+    );
+    assert_eq!(
+        fused
+            .avg()
+            .unwrap(),
+        -2.0
+    );
+}
@@ -0,0 +1,37 @@
+use rs_vips::{Vips, VipsImage};
+
+// A single test, since shutting down libvips affects the whole process.
+#[test]
+fn guard_lifecycle() {
+    let guard = Vips::init("rs-vips-test").unwrap();
+    let image = VipsImage::black(8, 8).unwrap();
+    assert!(Vips::live_image_count() >= 1);
+
+    // shutdown is refused while an image is alive
+    drop(guard);
+    let guard = Vips::init("rs-vips-test").unwrap();
+    assert_eq!(
+        image.get_width(),
+        8
+    );
+
+    // nested guards keep libvips running
+    let inner = Vips::init("rs-vips-test").unwrap();
+    drop(inner);
+    assert_eq!(
+        image
+            .invert()
+            .unwrap()
+            .get_width(),
+        8
+    );
+
+    drop(image);
+    Vips::cache_drop_all();
+    assert_eq!(
+        Vips::live_image_count(),
+        0
+    );
+    drop(guard);
+    assert!(Vips::init("rs-vips-test").is_err());
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::init;
+use rs_vips::{ops::BandFormat, VipsImage, VipsSource};
+use std::sync::Arc;
+
+#[test]
+fn owned_memory_outlives_caller() {
+    init();
+    let pixels = vec![128u8; 8 * 8 * 3];
+    let image = VipsImage::new_from_memory_owned(
+        pixels,
+        8,
+        8,
+        3,
+        BandFormat::Uchar,
+    )
+    .unwrap();
+    let inverted = image
+        .invert()
+        .unwrap();
+    drop(image);
+
+    assert_eq!(
+        inverted
+            .avg()
+            .unwrap(),
+        127.0
+    );
+}
+
+#[test]
+fn borrowed_buffer_is_copied() {
+    init();
+    let png = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap();
+    let image = VipsImage::new_from_buffer(&png, "").unwrap();
+    drop(png);
+
+    assert_eq!(
+        image.get_width(),
+        8
+    );
+    assert_eq!(
+        image
+            .avg()
+            .unwrap(),
+        0.0
+    );
+}
+
+#[test]
+fn owned_buffer_and_source() {
+    init();
+    let png = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap();
+    let png: Arc<[u8]> = Arc::from(png.as_slice());
+
+    let image = VipsImage::new_from_buffer_owned(
+        Arc::clone(&png),
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        image.get_height(),
+        8
+    );
+
+    let source = VipsSource::new_from_memory_owned(png).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    drop(source);
+    assert_eq!(
+        image
+            .avg()
+            .unwrap(),
+        0.0
+    );
+}
+
+#[test]
+fn write_to_memory_without_copy() {
+    init();
+    let image = VipsImage::black(4, 2).unwrap();
+    let pixels = image
+        .write_to_memory()
+        .unwrap();
+    assert_eq!(
+        pixels.len(),
+        8
+    );
+    assert!(pixels
+        .iter()
+        .all(|p| *p == 0));
+
+    let pixels: Vec<u8> = pixels.into();
+    assert_eq!(
+        pixels.len(),
+        8
+    );
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn buffer_into_bytes() {
+    init();
+    let buffer = VipsImage::black(8, 8)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap();
+    let expected = buffer.to_vec();
+    let bytes = bytes::Bytes::from(buffer);
+    assert_eq!(
+        bytes,
+        expected
+    );
+}
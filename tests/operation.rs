@@ -0,0 +1,168 @@
+mod common;
+
+use common::init;
+use rs_vips::{
+    operation::{ArgumentDirection, ArgumentType, Operation, OperationInfo, Value},
+    VipsImage,
+};
+
+#[test]
+fn describe_operation() {
+    init();
+    let resize = OperationInfo::find("resize").unwrap();
+    assert_eq!(
+        resize.nickname,
+        "resize"
+    );
+    assert!(!resize
+        .description
+        .is_empty());
+
+    let input = resize
+        .argument("in")
+        .unwrap();
+    assert!(input.required);
+    assert_eq!(
+        input.direction,
+        ArgumentDirection::Input
+    );
+    assert_eq!(
+        input.value_type,
+        ArgumentType::Image
+    );
+
+    let out = resize
+        .argument("out")
+        .unwrap();
+    assert_eq!(
+        out.direction,
+        ArgumentDirection::Output
+    );
+
+    let kernel = resize
+        .argument("kernel")
+        .unwrap();
+    assert!(!kernel.required);
+    assert_eq!(
+        kernel.value_type,
+        ArgumentType::Enum
+    );
+    assert!(kernel
+        .enum_values
+        .contains(&"lanczos3".to_string()));
+    assert!(matches!(
+        kernel.default,
+        Some(Value::Enum(_))
+    ));
+
+    let scale = resize
+        .argument("scale")
+        .unwrap();
+    assert!(scale
+        .range
+        .is_some());
+}
+
+#[test]
+fn list_operations() {
+    init();
+    let operations = OperationInfo::all();
+    assert!(operations
+        .iter()
+        .any(|op| op.nickname == "invert"));
+    assert!(operations
+        .iter()
+        .any(|op| op.nickname == "jpegload_buffer"));
+    assert!(operations
+        .windows(2)
+        .all(|pair| pair[0].nickname < pair[1].nickname));
+}
+
+#[test]
+fn unknown_operation() {
+    init();
+    assert!(OperationInfo::find("no_such_operation").is_err());
+}
+
+#[test]
+fn call_operation() {
+    init();
+    let image = VipsImage::black(16, 8).unwrap();
+    let outputs = Operation::new("resize")
+        .unwrap()
+        .set(
+            "in",
+            &image,
+        )
+        .unwrap()
+        .set("scale", 2)
+        .unwrap()
+        .set(
+            "kernel",
+            "nearest",
+        )
+        .unwrap()
+        .call()
+        .unwrap();
+    let resized = outputs
+        .image("out")
+        .unwrap();
+    assert_eq!(
+        resized.get_width(),
+        32
+    );
+
+    let outputs = Operation::new("max")
+        .unwrap()
+        .set(
+            "in",
+            &resized,
+        )
+        .unwrap()
+        .call()
+        .unwrap();
+    assert_eq!(
+        outputs.double("out"),
+        Some(0.0)
+    );
+    assert!(outputs
+        .int("x")
+        .is_some());
+}
+
+#[test]
+fn call_operation_checks_arguments() {
+    init();
+    let image = VipsImage::black(16, 8).unwrap();
+    let resize = Operation::new("resize").unwrap();
+    assert!(resize
+        .clone()
+        .set(
+            "no_such_argument",
+            1
+        )
+        .is_err());
+    assert!(resize
+        .clone()
+        .set(
+            "scale",
+            &image
+        )
+        .is_err());
+    assert!(resize
+        .clone()
+        .set(
+            "out",
+            &image
+        )
+        .is_err());
+    // scale is required
+    assert!(resize
+        .set(
+            "in",
+            &image
+        )
+        .unwrap()
+        .call()
+        .is_err());
+}
@@ -0,0 +1,292 @@
+mod common;
+
+use common::init;
+use rs_vips::{
+    operator::{Atan2, Index, Pow},
+    ops::BandFormat,
+    VipsImage,
+};
+
+fn bands(n: usize) -> VipsImage {
+    let image = VipsImage::black(8, 8).unwrap();
+    if n == 1 {
+        return image;
+    }
+    image
+        .bandjoin_const(&vec![
+            0.0;
+            n - 1
+        ])
+        .unwrap()
+}
+
+#[test]
+fn checked_ops_return_errors() {
+    init();
+    let rgb = bands(3);
+    let two = bands(2);
+
+    assert!(rgb
+        .checked_add(&two)
+        .is_err());
+    assert!(rgb
+        .checked_mul(&[1.0, 2.0][..])
+        .is_err());
+    assert!(rgb
+        .checked_lt(&two)
+        .is_err());
+    assert!(rgb
+        .checked_at(3)
+        .is_err());
+    assert_eq!(
+        rgb.checked_at(2)
+            .unwrap()
+            .get_bands(),
+        1
+    );
+}
+
+#[test]
+fn checked_ops_match_overloads() {
+    init();
+    let image = bands(1);
+
+    let checked = image
+        .checked_add(10.0)
+        .unwrap()
+        .checked_sub(4.0)
+        .unwrap()
+        .checked_mul(3.0)
+        .unwrap()
+        .checked_div(2.0)
+        .unwrap();
+    let overloaded = (&image + 10.0 - 4.0) * 3.0 / 2.0;
+    assert_eq!(
+        checked
+            .avg()
+            .unwrap(),
+        9.0
+    );
+    assert_eq!(
+        overloaded
+            .avg()
+            .unwrap(),
+        9.0
+    );
+}
+
+#[test]
+fn unary_assign_and_integer_ops() {
+    init();
+    let image = bands(1) + 4i32;
+
+    assert_eq!(
+        (-&image)
+            .avg()
+            .unwrap(),
+        -4.0
+    );
+    assert_eq!(
+        (2u8 * &image - 1i32)
+            .avg()
+            .unwrap(),
+        7.0
+    );
+    assert_eq!(
+        (10i32 / &image)
+            .avg()
+            .unwrap(),
+        2.5
+    );
+
+    let mut accumulated = image.clone();
+    accumulated += &image;
+    accumulated *= 3i32;
+    accumulated -= 4.0;
+    assert_eq!(
+        accumulated
+            .avg()
+            .unwrap(),
+        20.0
+    );
+
+    let inverted = !bands(1)
+        .cast(BandFormat::Uchar)
+        .unwrap();
+    assert_eq!(
+        inverted
+            .avg()
+            .unwrap(),
+        255.0
+    );
+}
+
+#[test]
+fn pow_atan2_and_band_ranges() {
+    init();
+    let image = bands(1) + 3.0;
+
+    assert_eq!(
+        (&image)
+            .pow(2i32)
+            .avg()
+            .unwrap(),
+        9.0
+    );
+    assert_eq!(
+        2.0.pow(&image)
+            .avg()
+            .unwrap(),
+        8.0
+    );
+    assert!(
+        (&image)
+            .atan2(&image)
+            .avg()
+            .unwrap()
+            > 0.0
+    );
+
+    let rgba = VipsImage::black(8, 8)
+        .unwrap()
+        .bandjoin_const(&[1.0, 2.0, 3.0])
+        .unwrap();
+    let middle = rgba.at(1..3);
+    assert_eq!(
+        middle.get_bands(),
+        2
+    );
+    assert_eq!(
+        middle
+            .avg()
+            .unwrap(),
+        1.5
+    );
+    assert_eq!(
+        rgba.at(1..=3)
+            .get_bands(),
+        3
+    );
+    assert!(rgba
+        .checked_at(2..6)
+        .is_err());
+}
+
+#[test]
+fn scalar_lhs_division_and_atan2() {
+    init();
+    let image = bands(1) + 4.0;
+
+    assert_eq!(
+        (10.0 / &image)
+            .avg()
+            .unwrap(),
+        2.5
+    );
+    assert_eq!(
+        (10.0 / image.clone())
+            .avg()
+            .unwrap(),
+        2.5
+    );
+    let per_band = &[8.0, 16.0] / &image;
+    assert_eq!(
+        per_band.get_bands(),
+        2
+    );
+    assert_eq!(
+        per_band
+            .avg()
+            .unwrap(),
+        3.0
+    );
+    assert_eq!(
+        (&[8.0, 16.0][..] / image.clone())
+            .avg()
+            .unwrap(),
+        3.0
+    );
+
+    // libvips works in degrees
+    let angle = Atan2::atan2(
+        4.0,
+        &image,
+    )
+    .avg()
+    .unwrap();
+    assert!((angle - 45.0).abs() < 1e-6);
+}
+
+#[test]
+fn scalar_chains_are_fused() {
+    init();
+    let fused = {
+        let image = bands(1) + 2.0;
+        (&image * 3.0 + 1.0) * 2.0 - 4.0
+    };
+    assert_eq!(
+        fused
+            .avg()
+            .unwrap(),
+        10.0
+    );
+
+    let image = bands(1) + 2.0;
+    let per_band = &image * &[1.0, 2.0, 3.0] + 1.0;
+    assert_eq!(
+        per_band.get_bands(),
+        3
+    );
+    assert_eq!(
+        per_band
+            .avg()
+            .unwrap(),
+        5.0
+    );
+    assert_eq!(
+        (-(&per_band / 2.0))
+            .avg()
+            .unwrap(),
+        -2.5
+    );
+
+    let rgb = bands(3) * 2.0;
+    assert!(rgb
+        .checked_mul(&[1.0, 2.0][..])
+        .is_err());
+}
+
+#[test]
+fn fused_chains_see_changes() {
+    init();
+    let mut drawn = bands(1) + 2.0;
+    drawn
+        .draw_rect(
+            &[10.0],
+            0,
+            0,
+            8,
+            8,
+        )
+        .unwrap();
+    assert_eq!(
+        (&drawn * 2.0)
+            .avg()
+            .unwrap(),
+        20.0
+    );
+
+    let mut tagged = bands(1) + 2.0;
+    tagged
+        .set_int(
+            "answer",
+            42,
+        )
+        .unwrap();
+    assert_eq!(
+        (&tagged * 2.0)
+            .get_int("answer")
+            .unwrap(),
+        42
+    );
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::init;
+use rs_vips::{
+    ops::{ForeignKeep, Kernel},
+    options::{JpegsaveBufferOptions, MaxOptions, ResizeOptions},
+    voption::{Setter, VOption},
+    VipsImage,
+};
+
+#[test]
+fn builder_options() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let resized = image
+        .resize_with_opts(
+            0.5,
+            ResizeOptions::new()
+                .kernel(Kernel::Nearest)
+                .vscale(0.25),
+        )
+        .unwrap();
+    assert_eq!(
+        resized.get_width(),
+        8
+    );
+    assert_eq!(
+        resized.get_height(),
+        4
+    );
+
+    let jpeg = image
+        .jpegsave_buffer_with_opts(
+            JpegsaveBufferOptions::new()
+                .q(90)
+                .keep(ForeignKeep::Exif | ForeignKeep::Icc),
+        )
+        .unwrap();
+    assert!(!jpeg.is_empty());
+}
+
+#[test]
+fn unset_options_keep_defaults() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let defaults = image
+        .jpegsave_buffer_with_opts(JpegsaveBufferOptions::default())
+        .unwrap();
+    let plain = image
+        .jpegsave_buffer()
+        .unwrap();
+    assert_eq!(
+        defaults.len(),
+        plain.len()
+    );
+}
+
+#[test]
+fn builder_with_outputs() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let mut x_array: Vec<i32> = Vec::new();
+    image
+        .max_with_opts(
+            VOption::from(MaxOptions::new().size(2)).set(
+                "x_array",
+                &mut x_array,
+            ),
+        )
+        .unwrap();
+    assert_eq!(
+        x_array.len(),
+        2
+    );
+}
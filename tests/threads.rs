@@ -0,0 +1,192 @@
+mod common;
+
+use common::init;
+use rs_vips::{VipsImage, VipsSource, VipsTarget};
+use std::{sync::Arc, thread};
+
+#[test]
+fn share_image_between_threads() {
+    init();
+    let image = Arc::new(VipsImage::black(64, 64).unwrap());
+
+    let handles = (0..8)
+        .map(|i| {
+            let image = Arc::clone(&image);
+            thread::spawn(move || {
+                let resized = image
+                    .resize(1.0 / (i + 1) as f64)
+                    .unwrap();
+                resized
+                    .write_to_buffer(".png")
+                    .unwrap()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert!(!handle
+            .join()
+            .unwrap()
+            .is_empty());
+    }
+}
+
+#[test]
+fn load_process_save_concurrently() {
+    init();
+    let png = VipsImage::black(32, 32)
+        .unwrap()
+        .write_to_buffer(".png")
+        .unwrap();
+    let png = Arc::new(png);
+
+    let handles = (0..8)
+        .map(|_| {
+            let png = Arc::clone(&png);
+            thread::spawn(move || {
+                let source = VipsSource::new_from_memory(&png).unwrap();
+                let image = VipsImage::new_from_source(
+                    &source,
+                    "",
+                )
+                .unwrap();
+                let inverted = image
+                    .invert()
+                    .unwrap();
+                let target = VipsTarget::new_to_memory().unwrap();
+                inverted
+                    .write_to_target(
+                        ".jpg",
+                        &target,
+                    )
+                    .unwrap();
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle
+            .join()
+            .unwrap();
+    }
+}
+
+#[test]
+fn move_image_to_thread() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let width = thread::spawn(move || image.get_width())
+        .join()
+        .unwrap();
+    assert_eq!(width, 16);
+}
+
+#[test]
+fn parallel_failures_report_their_own_errors() {
+    init();
+    let dir = std::env::temp_dir();
+    let handles = (0..8)
+        .map(|i| {
+            let dir = dir.clone();
+            thread::spawn(move || {
+                let image = VipsImage::black(8, 8).unwrap();
+                for j in 0..50 {
+                    let path = dir.join(format!(
+                        "rs-vips-missing-{}-{}.png",
+                        i, j
+                    ));
+                    let filename = path
+                        .to_string_lossy()
+                        .to_string();
+                    let err = VipsImage::new_from_file(&path).unwrap_err();
+                    let lines = &err
+                        .info()
+                        .unwrap()
+                        .lines;
+                    assert!(!lines.is_empty());
+                    assert!(lines
+                        .iter()
+                        .all(|line| line.contains(&filename)));
+
+                    let err = image
+                        .extract_area(
+                            4, 4, 16, 16,
+                        )
+                        .unwrap_err();
+                    let lines = &err
+                        .info()
+                        .unwrap()
+                        .lines;
+                    assert!(!lines.is_empty());
+                    assert!(lines
+                        .iter()
+                        .all(|line| line.starts_with("extract_area")));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle
+            .join()
+            .unwrap();
+    }
+}
+
+#[test]
+fn parallel_buffer_load_failures_keep_their_own_lines() {
+    init();
+    let inputs: Vec<Vec<u8>> = vec![
+        b"not an image at all".to_vec(),
+        [
+            &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a][..],
+            &[0; 24],
+        ]
+        .concat(),
+        [&[0xff, 0xd8, 0xff, 0xe0][..], &[0; 24]].concat(),
+        [&b"GIF89a"[..], &[0; 24]].concat(),
+    ];
+    // what each failure reports when nothing else is running
+    let expected: Vec<Vec<String>> = inputs
+        .iter()
+        .map(|input| {
+            VipsImage::new_from_buffer(input, "")
+                .unwrap_err()
+                .info()
+                .unwrap()
+                .lines
+                .clone()
+        })
+        .collect();
+    let inputs = Arc::new(inputs);
+    let expected = Arc::new(expected);
+
+    let handles = (0..8)
+        .map(|i| {
+            let inputs = Arc::clone(&inputs);
+            let expected = Arc::clone(&expected);
+            thread::spawn(move || {
+                for j in 0..50 {
+                    let k = (i + j) % inputs.len();
+                    let err = VipsImage::new_from_buffer(
+                        &inputs[k],
+                        "",
+                    )
+                    .unwrap_err();
+                    assert_eq!(
+                        err.info()
+                            .unwrap()
+                            .lines,
+                        expected[k]
+                    );
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle
+            .join()
+            .unwrap();
+    }
+}
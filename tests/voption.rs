@@ -0,0 +1,190 @@
+mod common;
+
+use common::init;
+use rs_vips::{
+    error::Error,
+    ops::{ForeignFlags, ForeignKeep, Kernel},
+    voption::{Setter, VOption},
+    VipsImage,
+};
+
+#[test]
+fn unknown_argument() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let result = image.jpegsave_buffer_with_opts(
+        VOption::new().set(
+            "qualtiy",
+            90,
+        ),
+    );
+    match result {
+        Err(Error::ArgumentError(e)) => {
+            assert_eq!(
+                e.operation,
+                "jpegsave_buffer"
+            );
+            assert_eq!(
+                e.argument,
+                "qualtiy"
+            );
+            assert!(e
+                .valid_arguments
+                .contains(&"Q".to_string()));
+        }
+        other => panic!(
+            "expected an argument error, got {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn invalid_enum_nick() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let result = image.resize_with_opts(
+        0.5,
+        VOption::new().set(
+            "kernel",
+            "no_such_kernel",
+        ),
+    );
+    match result {
+        Err(Error::ArgumentError(e)) => {
+            assert_eq!(
+                e.argument,
+                "kernel"
+            );
+            assert!(e
+                .reason
+                .contains("no_such_kernel"));
+        }
+        other => panic!(
+            "expected an argument error, got {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn wrong_value_type() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let result = image.jpegsave_buffer_with_opts(
+        VOption::new().set(
+            "Q",
+            &image,
+        ),
+    );
+    assert!(matches!(
+        result,
+        Err(Error::ArgumentError(_))
+    ));
+}
+
+#[test]
+fn valid_arguments_still_work() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let jpeg = image
+        .jpegsave_buffer_with_opts(VOption::new().set("Q", 90))
+        .unwrap();
+    assert!(!jpeg.is_empty());
+}
+
+#[test]
+fn int_array_outputs() {
+    init();
+    let image = VipsImage::black(8, 8).unwrap();
+    let mut x_array: Vec<i32> = Vec::new();
+    let mut y_array: Vec<i32> = Vec::new();
+    image
+        .max_with_opts(
+            VOption::new()
+                .set("size", 3)
+                .set(
+                    "x_array",
+                    &mut x_array,
+                )
+                .set(
+                    "y_array",
+                    &mut y_array,
+                ),
+        )
+        .unwrap();
+    assert_eq!(
+        x_array.len(),
+        3
+    );
+    assert_eq!(
+        y_array.len(),
+        3
+    );
+}
+
+#[test]
+fn string_output() {
+    init();
+    let mut log = String::new();
+    VipsImage::system_with_opts(
+        "echo hello",
+        VOption::new().set(
+            "log",
+            &mut log,
+        ),
+    )
+    .unwrap();
+    assert!(log.contains("hello"));
+}
+
+#[test]
+fn enum_output() {
+    init();
+    let png = VipsImage::black(8, 8)
+        .unwrap()
+        .pngsave_buffer()
+        .unwrap();
+    let mut flags = ForeignFlags::All;
+    VipsImage::pngload_buffer_with_opts(
+        &png,
+        VOption::new().set(
+            "flags",
+            &mut flags,
+        ),
+    )
+    .unwrap();
+    assert_ne!(
+        flags,
+        ForeignFlags::All
+    );
+}
+
+#[test]
+fn enum_and_flags_inputs() {
+    init();
+    let image = VipsImage::black(16, 16).unwrap();
+    let resized = image
+        .resize_with_opts(
+            0.5,
+            VOption::new().set(
+                "kernel",
+                Kernel::Nearest,
+            ),
+        )
+        .unwrap();
+    assert_eq!(
+        resized.get_width(),
+        8
+    );
+
+    let jpeg = image
+        .jpegsave_buffer_with_opts(
+            VOption::new().set(
+                "keep",
+                ForeignKeep::Exif | ForeignKeep::Icc,
+            ),
+        )
+        .unwrap();
+    assert!(!jpeg.is_empty());
+}